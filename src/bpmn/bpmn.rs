@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use crate::clients::flow_node_instance::flow_node_instance::BpmnType;
+
+/// Describes the root of a BPMN 2.0 document.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Definitions {
+    pub id: String,
+    pub processes: Vec<Process>,
+    pub participants: Vec<Participant>,
+    pub messages: Vec<Message>,
+    pub signals: Vec<Signal>,
+    pub errors: Vec<ErrorDefinition>,
+}
+
+/// Describes a pool of a collaboration diagram.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Participant {
+    pub id: String,
+    pub name: Option<String>,
+    pub process_ref: Option<String>,
+}
+
+/// Describes a process, which the ProcessCube® Engine deploys as a ProcessModel.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Process {
+    pub id: String,
+    pub name: Option<String>,
+    pub is_executable: bool,
+    pub version: Option<String>,
+    pub custom_properties: BTreeMap<String, String>,
+    pub lanes: Vec<Lane>,
+    pub flow_nodes: Vec<FlowNode>,
+    pub sequence_flows: Vec<SequenceFlow>,
+}
+
+/// Describes a lane and the lanes nested in it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Lane {
+    pub id: String,
+    pub name: Option<String>,
+    pub flow_node_refs: Vec<String>,
+    pub child_lanes: Vec<Lane>,
+}
+
+/// Describes an event, gateway or activity of a process.
+///
+/// SubProcesses carry their own flow nodes and sequence flows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowNode {
+    pub id: String,
+    pub name: Option<String>,
+    pub bpmn_type: BpmnType,
+    pub documentation: Vec<String>,
+    pub custom_properties: BTreeMap<String, String>,
+    pub incoming: Vec<String>,
+    pub outgoing: Vec<String>,
+    pub event_definition: Option<EventDefinition>,
+    pub attached_to_ref: Option<String>,
+    pub flow_nodes: Vec<FlowNode>,
+    pub sequence_flows: Vec<SequenceFlow>,
+}
+
+/// Describes a connection between two flow nodes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SequenceFlow {
+    pub id: String,
+    pub name: Option<String>,
+    pub source_ref: String,
+    pub target_ref: String,
+    pub condition_expression: Option<String>,
+}
+
+/// Describes what triggers or is triggered by an event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventDefinition {
+    Message { message_ref: Option<String> },
+    Signal { signal_ref: Option<String> },
+    Error { error_ref: Option<String> },
+    Escalation { escalation_ref: Option<String> },
+    Timer { timer: Option<Timer> },
+    Link { name: Option<String> },
+    Conditional,
+    Compensate,
+    Terminate,
+}
+
+/// Describes when a timer event fires.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Timer {
+    Date(String),
+    Duration(String),
+    Cycle(String),
+}
+
+/// Describes a message that can be sent or received by events.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Message {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// Describes a signal that can be sent or received by events.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Signal {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// Describes an error that can be thrown or caught by events.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ErrorDefinition {
+    pub id: String,
+    pub name: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl Definitions {
+    /// Returns the message with the given ID.
+    pub fn message(&self, id: &str) -> Option<&Message> {
+        self.messages.iter().find(|message| message.id == id)
+    }
+
+    /// Returns the signal with the given ID.
+    pub fn signal(&self, id: &str) -> Option<&Signal> {
+        self.signals.iter().find(|signal| signal.id == id)
+    }
+
+    /// Returns the error with the given ID.
+    pub fn error(&self, id: &str) -> Option<&ErrorDefinition> {
+        self.errors.iter().find(|error| error.id == id)
+    }
+}
+
+impl Process {
+    /// Returns all flow nodes of the process, including those nested in SubProcesses.
+    pub fn all_flow_nodes(&self) -> Vec<&FlowNode> {
+        let mut flow_nodes = Vec::new();
+        collect_flow_nodes(&self.flow_nodes, &mut flow_nodes);
+        flow_nodes
+    }
+
+    /// Returns all lanes of the process, including nested lanes.
+    pub fn all_lanes(&self) -> Vec<&Lane> {
+        let mut lanes = Vec::new();
+        collect_lanes(&self.lanes, &mut lanes);
+        lanes
+    }
+}
+
+fn collect_flow_nodes<'a>(flow_nodes: &'a [FlowNode], collected: &mut Vec<&'a FlowNode>) {
+    for flow_node in flow_nodes {
+        collected.push(flow_node);
+        collect_flow_nodes(&flow_node.flow_nodes, collected);
+    }
+}
+
+fn collect_lanes<'a>(lanes: &'a [Lane], collected: &mut Vec<&'a Lane>) {
+    for lane in lanes {
+        collected.push(lane);
+        collect_lanes(&lane.child_lanes, collected);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::clients::{
+    flow_node_instance::flow_node_instance::BpmnType,
+    process_model::process_model::{
+        EndEvent, FlowNode as ProcessModelFlowNode, Lane as ProcessModelLane, LaneSet,
+        ProcessModel, StartEvent,
+    },
+};
+
+use super::{
+    bpmn::{Definitions, EventDefinition, FlowNode, Lane, Process, Timer},
+    error::BpmnError,
+    parser::parse,
+};
+
+/// Parses the given BPMN 2.0 document and returns its ProcessModels in the shape the ProcessCube® Engine returns them.
+///
+/// Only the top-level lanes are included. The shape in which the engine returns nested lanes is unknown,
+/// so `child_lane_set` is always `None`.
+///
+/// # Example
+/// ```
+/// use processcube_engine_client::bpmn::converter::process_models_from_xml;
+///
+/// let xml = r#"<bpmn:definitions xmlns:bpmn="http://www.omg.org/spec/BPMN/20100524/MODEL" id="Definitions_1">
+///   <bpmn:process id="Process_1" name="Process 1" isExecutable="true">
+///     <bpmn:startEvent id="StartEvent_1" name="Start" />
+///   </bpmn:process>
+/// </bpmn:definitions>"#;
+///
+/// let process_models = process_models_from_xml(xml).unwrap();
+/// assert_eq!(process_models[0].start_events[0].id, "StartEvent_1");
+/// ```
+pub fn process_models_from_xml(xml: &str) -> Result<Vec<ProcessModel>, BpmnError> {
+    Ok(to_process_models(&parse(xml)?))
+}
+
+/// Converts the processes of a parsed BPMN 2.0 document into ProcessModels.
+pub fn to_process_models(definitions: &Definitions) -> Vec<ProcessModel> {
+    definitions
+        .processes
+        .iter()
+        .map(|process| to_process_model(definitions, process))
+        .collect()
+}
+
+fn to_process_model(definitions: &Definitions, process: &Process) -> ProcessModel {
+    let process_model_name = process.name.clone().or_else(|| {
+        definitions
+            .participants
+            .iter()
+            .find(|participant| participant.process_ref.as_deref() == Some(process.id.as_str()))
+            .and_then(|participant| participant.name.clone())
+    });
+    let context = ProcessContext {
+        definitions,
+        process_model_id: &process.id,
+        process_model_name: process_model_name.clone().unwrap_or_default(),
+    };
+
+    ProcessModel {
+        id: process.id.clone(),
+        name: process_model_name,
+        process_definition_id: definitions.id.clone(),
+        version: process.version.clone(),
+        custom_properties: to_custom_properties(&process.custom_properties),
+        is_executable: process.is_executable,
+        lane_set: to_lane_set(&process.lanes),
+        start_events: process
+            .flow_nodes
+            .iter()
+            .filter(|flow_node| flow_node.bpmn_type == BpmnType::StartEvent)
+            .map(|flow_node| context.to_start_event(flow_node))
+            .collect(),
+        end_events: process
+            .flow_nodes
+            .iter()
+            .filter(|flow_node| flow_node.bpmn_type == BpmnType::EndEvent)
+            .map(|flow_node| context.to_end_event(flow_node))
+            .collect(),
+        flow_nodes: process
+            .all_flow_nodes()
+            .into_iter()
+            .map(|flow_node| context.to_flow_node(flow_node))
+            .collect(),
+    }
+}
+
+/// Holds what every flow node of a ProcessModel refers to.
+struct ProcessContext<'a> {
+    definitions: &'a Definitions,
+    process_model_id: &'a str,
+    process_model_name: String,
+}
+
+impl ProcessContext<'_> {
+    fn to_start_event(&self, flow_node: &FlowNode) -> StartEvent {
+        let (timer_type, timer_value) = match &flow_node.event_definition {
+            Some(EventDefinition::Timer { timer: Some(timer) }) => match timer {
+                Timer::Date(value) => (Some("date"), Some(value.clone())),
+                Timer::Duration(value) => (Some("duration"), Some(value.clone())),
+                Timer::Cycle(value) => (Some("cycle"), Some(value.clone())),
+            },
+            _ => (None, None),
+        };
+        let (message_id, message_name) = self.message(flow_node);
+        let (signal_id, signal_name) = self.signal(flow_node);
+
+        StartEvent {
+            id: flow_node.id.clone(),
+            name: flow_node.name.clone().unwrap_or_default(),
+            process_model_id: self.process_model_id.to_string(),
+            process_model_name: self.process_model_name.clone(),
+            custom_properties: to_custom_properties(&flow_node.custom_properties),
+            flow_node_type: flow_node.bpmn_type.as_str().to_string(),
+            timer_type: timer_type.map(str::to_string),
+            timer_value,
+            message_name,
+            message_id,
+            signal_name,
+            signal_id,
+            documentation: to_documentation(&flow_node.documentation),
+        }
+    }
+
+    fn to_end_event(&self, flow_node: &FlowNode) -> EndEvent {
+        let error = match &flow_node.event_definition {
+            Some(EventDefinition::Error {
+                error_ref: Some(error_ref),
+            }) => self.definitions.error(error_ref),
+            _ => None,
+        };
+        let (message_id, message_name) = self.message(flow_node);
+        let (signal_id, signal_name) = self.signal(flow_node);
+
+        EndEvent {
+            id: flow_node.id.clone(),
+            name: flow_node.name.clone().unwrap_or_default(),
+            process_model_id: self.process_model_id.to_string(),
+            process_model_name: self.process_model_name.clone(),
+            custom_properties: to_custom_properties(&flow_node.custom_properties),
+            flow_node_type: flow_node.bpmn_type.as_str().to_string(),
+            error_name: error.and_then(|error| error.name.clone()),
+            error_code: error.and_then(|error| error.error_code.clone()),
+            error_message: error.and_then(|error| error.error_message.clone()),
+            message_name,
+            message_id,
+            signal_name,
+            signal_id,
+            documentation: to_documentation(&flow_node.documentation),
+        }
+    }
+
+    fn to_flow_node(&self, flow_node: &FlowNode) -> ProcessModelFlowNode {
+        ProcessModelFlowNode {
+            id: flow_node.id.clone(),
+            name: flow_node.name.clone().unwrap_or_default(),
+            custom_properties: to_custom_properties(&flow_node.custom_properties),
+            flow_node_type: flow_node.bpmn_type.as_str().to_string(),
+            process_model_id: self.process_model_id.to_string(),
+            process_model_name: self.process_model_name.clone(),
+            documentation: to_documentation(&flow_node.documentation),
+        }
+    }
+
+    /// Returns the ID and name of the message the flow node sends or receives.
+    fn message(&self, flow_node: &FlowNode) -> (Option<String>, Option<String>) {
+        match &flow_node.event_definition {
+            Some(EventDefinition::Message {
+                message_ref: Some(message_ref),
+            }) => (
+                Some(message_ref.clone()),
+                self.definitions
+                    .message(message_ref)
+                    .and_then(|message| message.name.clone()),
+            ),
+            _ => (None, None),
+        }
+    }
+
+    /// Returns the ID and name of the signal the flow node sends or receives.
+    fn signal(&self, flow_node: &FlowNode) -> (Option<String>, Option<String>) {
+        match &flow_node.event_definition {
+            Some(EventDefinition::Signal {
+                signal_ref: Some(signal_ref),
+            }) => (
+                Some(signal_ref.clone()),
+                self.definitions
+                    .signal(signal_ref)
+                    .and_then(|signal| signal.name.clone()),
+            ),
+            _ => (None, None),
+        }
+    }
+}
+
+fn to_lane_set(lanes: &[Lane]) -> LaneSet {
+    LaneSet {
+        lanes: lanes
+            .iter()
+            .map(|lane| ProcessModelLane {
+                id: lane.id.clone(),
+                extension_elements: None,
+                flow_node_references: Some(lane.flow_node_refs.clone()),
+                name: lane.name.clone(),
+                child_lane_set: None,
+                documentation: None,
+            })
+            .collect(),
+    }
+}
+
+fn to_custom_properties(properties: &BTreeMap<String, String>) -> Value {
+    Value::Object(
+        properties
+            .iter()
+            .map(|(name, value)| (name.clone(), Value::String(value.clone())))
+            .collect(),
+    )
+}
+
+fn to_documentation(documentation: &[String]) -> Option<Vec<String>> {
+    match documentation.is_empty() {
+        true => None,
+        false => Some(documentation.to_vec()),
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+/// Represents an error found in a BPMN 2.0 document before it is sent to the ProcessCube® Engine.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BpmnError {
+    /// The document is not well-formed XML.
+    Xml { message: String, position: u64 },
+    /// The document is well-formed XML, but not a BPMN 2.0 document.
+    InvalidDocument(String),
+    /// The document is a BPMN 2.0 document the ProcessCube® Engine cannot execute.
+    Validation(Vec<ValidationIssue>),
+}
+
+/// Describes a single reason why a BPMN 2.0 document cannot be executed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    /// The document contains no process.
+    NoProcess,
+    /// Several elements share the same ID.
+    DuplicateId { id: String },
+    /// No process is marked as executable.
+    NoExecutableProcess,
+    /// An executable process has no start event.
+    MissingStartEvent { process_id: String },
+    /// An executable process has no end event.
+    MissingEndEvent { process_id: String },
+    /// A sequence flow starts at an element that does not exist.
+    DanglingSourceRef {
+        sequence_flow_id: String,
+        source_ref: String,
+    },
+    /// A sequence flow ends at an element that does not exist.
+    DanglingTargetRef {
+        sequence_flow_id: String,
+        target_ref: String,
+    },
+}
+
+impl fmt::Display for BpmnError {
+    /// Formats a BpmnError.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BpmnError::Xml { message, position } => {
+                write!(f, "Malformed XML at position {}: {}", position, message)
+            }
+            BpmnError::InvalidDocument(message) => write!(f, "Invalid BPMN document: {}", message),
+            BpmnError::Validation(issues) => {
+                write!(f, "The BPMN document cannot be executed:")?;
+                for issue in issues {
+                    write!(f, "\n - {}", issue)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    /// Formats a ValidationIssue.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationIssue::NoProcess => write!(f, "The document contains no process."),
+            ValidationIssue::DuplicateId { id } => {
+                write!(f, "The ID `{}` is used by more than one element.", id)
+            }
+            ValidationIssue::NoExecutableProcess => {
+                write!(f, "The document contains no executable process.")
+            }
+            ValidationIssue::MissingStartEvent { process_id } => {
+                write!(f, "Process `{}` has no start event.", process_id)
+            }
+            ValidationIssue::MissingEndEvent { process_id } => {
+                write!(f, "Process `{}` has no end event.", process_id)
+            }
+            ValidationIssue::DanglingSourceRef {
+                sequence_flow_id,
+                source_ref,
+            } => write!(
+                f,
+                "Sequence flow `{}` starts at unknown element `{}`.",
+                sequence_flow_id, source_ref
+            ),
+            ValidationIssue::DanglingTargetRef {
+                sequence_flow_id,
+                target_ref,
+            } => write!(
+                f,
+                "Sequence flow `{}` ends at unknown element `{}`.",
+                sequence_flow_id, target_ref
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BpmnError {}
@@ -0,0 +1,5 @@
+pub mod bpmn;
+pub mod converter;
+pub mod error;
+pub mod parser;
+pub mod validator;
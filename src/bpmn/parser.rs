@@ -0,0 +1,531 @@
+use std::collections::{BTreeMap, HashMap};
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use crate::clients::flow_node_instance::flow_node_instance::BpmnType;
+
+use super::{
+    bpmn::{
+        Definitions, ErrorDefinition, EventDefinition, FlowNode, Lane, Message, Participant,
+        Process, SequenceFlow, Signal, Timer,
+    },
+    error::BpmnError,
+};
+
+/// Parses a BPMN 2.0 document.
+///
+/// Elements are matched by their local name, so the document may use any namespace prefix.
+/// Elements without meaning for the ProcessCube® Engine, such as diagram information, are skipped.
+///
+/// # Example
+/// ```
+/// use processcube_engine_client::bpmn::parser::parse;
+///
+/// let definitions = parse(r#"
+///     <bpmn:definitions xmlns:bpmn="http://www.omg.org/spec/BPMN/20100524/MODEL" id="Definitions_1">
+///         <bpmn:process id="Sample_Process" isExecutable="true">
+///             <bpmn:startEvent id="StartEvent_1" />
+///         </bpmn:process>
+///     </bpmn:definitions>
+/// "#).unwrap();
+/// assert_eq!(definitions.processes[0].id, "Sample_Process");
+/// ```
+pub fn parse(xml: &str) -> Result<Definitions, BpmnError> {
+    BpmnParser::new(xml).parse_document()
+}
+
+struct BpmnParser<'a> {
+    reader: Reader<&'a [u8]>,
+}
+
+type Attributes = HashMap<String, String>;
+
+impl<'a> BpmnParser<'a> {
+    fn new(xml: &'a str) -> BpmnParser<'a> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+        BpmnParser { reader }
+    }
+
+    fn parse_document(&mut self) -> Result<Definitions, BpmnError> {
+        let definitions = loop {
+            match self.read_event()? {
+                Event::Start(start) => break self.parse_root(start, false)?,
+                Event::Empty(start) => break self.parse_root(start, true)?,
+                Event::Text(_) | Event::CData(_) => {
+                    return Err(self.xml_error("Non-whitespace before first tag."))
+                }
+                Event::Eof => {
+                    return Err(BpmnError::InvalidDocument(
+                        "The document is empty.".to_string(),
+                    ))
+                }
+                _ => {}
+            }
+        };
+
+        loop {
+            match self.read_event()? {
+                Event::Eof => return Ok(definitions),
+                Event::Comment(_) | Event::PI(_) => {}
+                _ => return Err(self.xml_error("Unexpected content after the root element.")),
+            }
+        }
+    }
+
+    fn parse_root(
+        &mut self,
+        start: BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<Definitions, BpmnError> {
+        let name = local_name(&start);
+        if name != "definitions" {
+            return Err(BpmnError::InvalidDocument(format!(
+                "Expected `definitions` as root element, found `{}`.",
+                name
+            )));
+        }
+
+        self.parse_definitions(start, is_empty)
+    }
+
+    fn parse_definitions(
+        &mut self,
+        start: BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<Definitions, BpmnError> {
+        let mut attributes = self.attributes(&start)?;
+        let mut definitions = Definitions {
+            id: attributes.remove("id").unwrap_or_default(),
+            ..Default::default()
+        };
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            match local_name(&child).as_str() {
+                "process" => {
+                    let process = self.parse_process(child, child_is_empty)?;
+                    definitions.processes.push(process);
+                }
+                "collaboration" => {
+                    let participants = self.parse_collaboration(child_is_empty)?;
+                    definitions.participants.extend(participants);
+                }
+                "message" => {
+                    let mut attributes = self.attributes(&child)?;
+                    self.skip(child, child_is_empty)?;
+                    definitions.messages.push(Message {
+                        id: attributes.remove("id").unwrap_or_default(),
+                        name: attributes.remove("name"),
+                    });
+                }
+                "signal" => {
+                    let mut attributes = self.attributes(&child)?;
+                    self.skip(child, child_is_empty)?;
+                    definitions.signals.push(Signal {
+                        id: attributes.remove("id").unwrap_or_default(),
+                        name: attributes.remove("name"),
+                    });
+                }
+                "error" => {
+                    let mut attributes = self.attributes(&child)?;
+                    self.skip(child, child_is_empty)?;
+                    definitions.errors.push(ErrorDefinition {
+                        id: attributes.remove("id").unwrap_or_default(),
+                        name: attributes.remove("name"),
+                        error_code: attributes.remove("errorCode"),
+                        error_message: attributes.remove("errorMessage"),
+                    });
+                }
+                _ => self.skip(child, child_is_empty)?,
+            }
+        }
+
+        Ok(definitions)
+    }
+
+    fn parse_collaboration(&mut self, is_empty: bool) -> Result<Vec<Participant>, BpmnError> {
+        let mut participants = Vec::new();
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            if local_name(&child) == "participant" {
+                let mut attributes = self.attributes(&child)?;
+                participants.push(Participant {
+                    id: attributes.remove("id").unwrap_or_default(),
+                    name: attributes.remove("name"),
+                    process_ref: attributes.remove("processRef"),
+                });
+            }
+            self.skip(child, child_is_empty)?;
+        }
+
+        Ok(participants)
+    }
+
+    fn parse_process(
+        &mut self,
+        start: BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<Process, BpmnError> {
+        let mut attributes = self.attributes(&start)?;
+        let mut process = Process {
+            id: attributes.remove("id").unwrap_or_default(),
+            name: attributes.remove("name"),
+            is_executable: attributes.remove("isExecutable").as_deref() == Some("true"),
+            version: attributes.remove("versionTag"),
+            ..Default::default()
+        };
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            let name = local_name(&child);
+            match name.as_str() {
+                "laneSet" => {
+                    let lanes = self.parse_lane_set(child_is_empty)?;
+                    process.lanes.extend(lanes);
+                }
+                "extensionElements" => {
+                    process.custom_properties = self.parse_extension_elements(child_is_empty)?;
+                }
+                _ => self.parse_flow_element(
+                    &name,
+                    child,
+                    child_is_empty,
+                    &mut process.flow_nodes,
+                    &mut process.sequence_flows,
+                )?,
+            }
+        }
+
+        Ok(process)
+    }
+
+    /// Parses a flow node or sequence flow of a process or SubProcess and skips anything else.
+    fn parse_flow_element(
+        &mut self,
+        name: &str,
+        start: BytesStart<'a>,
+        is_empty: bool,
+        flow_nodes: &mut Vec<FlowNode>,
+        sequence_flows: &mut Vec<SequenceFlow>,
+    ) -> Result<(), BpmnError> {
+        if name == "sequenceFlow" {
+            let sequence_flow = self.parse_sequence_flow(start, is_empty)?;
+            sequence_flows.push(sequence_flow);
+        } else if let Some(bpmn_type) = bpmn_type(name) {
+            let flow_node = self.parse_flow_node(bpmn_type, start, is_empty)?;
+            flow_nodes.push(flow_node);
+        } else {
+            self.skip(start, is_empty)?;
+        }
+
+        Ok(())
+    }
+
+    fn parse_lane_set(&mut self, is_empty: bool) -> Result<Vec<Lane>, BpmnError> {
+        let mut lanes = Vec::new();
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            match local_name(&child).as_str() {
+                "lane" => lanes.push(self.parse_lane(child, child_is_empty)?),
+                _ => self.skip(child, child_is_empty)?,
+            }
+        }
+
+        Ok(lanes)
+    }
+
+    fn parse_lane(&mut self, start: BytesStart<'a>, is_empty: bool) -> Result<Lane, BpmnError> {
+        let mut attributes = self.attributes(&start)?;
+        let mut lane = Lane {
+            id: attributes.remove("id").unwrap_or_default(),
+            name: attributes.remove("name"),
+            ..Default::default()
+        };
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            match local_name(&child).as_str() {
+                "flowNodeRef" => lane.flow_node_refs.push(self.read_text(child_is_empty)?),
+                "childLaneSet" => lane.child_lanes = self.parse_lane_set(child_is_empty)?,
+                _ => self.skip(child, child_is_empty)?,
+            }
+        }
+
+        Ok(lane)
+    }
+
+    fn parse_sequence_flow(
+        &mut self,
+        start: BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<SequenceFlow, BpmnError> {
+        let mut attributes = self.attributes(&start)?;
+        let mut sequence_flow = SequenceFlow {
+            id: attributes.remove("id").unwrap_or_default(),
+            name: attributes.remove("name"),
+            source_ref: attributes.remove("sourceRef").unwrap_or_default(),
+            target_ref: attributes.remove("targetRef").unwrap_or_default(),
+            condition_expression: None,
+        };
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            match local_name(&child).as_str() {
+                "conditionExpression" => {
+                    sequence_flow.condition_expression = Some(self.read_text(child_is_empty)?)
+                }
+                _ => self.skip(child, child_is_empty)?,
+            }
+        }
+
+        Ok(sequence_flow)
+    }
+
+    fn parse_flow_node(
+        &mut self,
+        bpmn_type: BpmnType,
+        start: BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<FlowNode, BpmnError> {
+        let mut attributes = self.attributes(&start)?;
+        let mut flow_node = FlowNode {
+            id: attributes.remove("id").unwrap_or_default(),
+            name: attributes.remove("name"),
+            bpmn_type,
+            documentation: Vec::new(),
+            custom_properties: BTreeMap::new(),
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+            event_definition: None,
+            attached_to_ref: attributes.remove("attachedToRef"),
+            flow_nodes: Vec::new(),
+            sequence_flows: Vec::new(),
+        };
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            let name = local_name(&child);
+            match name.as_str() {
+                "incoming" => flow_node.incoming.push(self.read_text(child_is_empty)?),
+                "outgoing" => flow_node.outgoing.push(self.read_text(child_is_empty)?),
+                "documentation" => flow_node
+                    .documentation
+                    .push(self.read_text(child_is_empty)?),
+                "extensionElements" => {
+                    flow_node.custom_properties = self.parse_extension_elements(child_is_empty)?;
+                }
+                _ if name.ends_with("EventDefinition") => {
+                    flow_node.event_definition =
+                        self.parse_event_definition(&name, child, child_is_empty)?;
+                }
+                _ => self.parse_flow_element(
+                    &name,
+                    child,
+                    child_is_empty,
+                    &mut flow_node.flow_nodes,
+                    &mut flow_node.sequence_flows,
+                )?,
+            }
+        }
+
+        Ok(flow_node)
+    }
+
+    fn parse_event_definition(
+        &mut self,
+        name: &str,
+        start: BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<Option<EventDefinition>, BpmnError> {
+        if name == "timerEventDefinition" {
+            let timer = self.parse_timer(is_empty)?;
+            return Ok(Some(EventDefinition::Timer { timer }));
+        }
+
+        let mut attributes = self.attributes(&start)?;
+        self.skip(start, is_empty)?;
+
+        let event_definition = match name {
+            "messageEventDefinition" => EventDefinition::Message {
+                message_ref: attributes.remove("messageRef"),
+            },
+            "signalEventDefinition" => EventDefinition::Signal {
+                signal_ref: attributes.remove("signalRef"),
+            },
+            "errorEventDefinition" => EventDefinition::Error {
+                error_ref: attributes.remove("errorRef"),
+            },
+            "escalationEventDefinition" => EventDefinition::Escalation {
+                escalation_ref: attributes.remove("escalationRef"),
+            },
+            "linkEventDefinition" => EventDefinition::Link {
+                name: attributes.remove("name"),
+            },
+            "conditionalEventDefinition" => EventDefinition::Conditional,
+            "compensateEventDefinition" => EventDefinition::Compensate,
+            "terminateEventDefinition" => EventDefinition::Terminate,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event_definition))
+    }
+
+    fn parse_timer(&mut self, is_empty: bool) -> Result<Option<Timer>, BpmnError> {
+        let mut timer = None;
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            match local_name(&child).as_str() {
+                "timeDate" => timer = Some(Timer::Date(self.read_text(child_is_empty)?)),
+                "timeDuration" => timer = Some(Timer::Duration(self.read_text(child_is_empty)?)),
+                "timeCycle" => timer = Some(Timer::Cycle(self.read_text(child_is_empty)?)),
+                _ => self.skip(child, child_is_empty)?,
+            }
+        }
+
+        Ok(timer)
+    }
+
+    /// Parses the `properties` extension as written by the 5Minds Studio.
+    fn parse_extension_elements(
+        &mut self,
+        is_empty: bool,
+    ) -> Result<BTreeMap<String, String>, BpmnError> {
+        let mut properties = BTreeMap::new();
+
+        while let Some((child, child_is_empty)) = self.next_child(is_empty)? {
+            if local_name(&child) != "properties" {
+                self.skip(child, child_is_empty)?;
+                continue;
+            }
+
+            while let Some((property, property_is_empty)) = self.next_child(child_is_empty)? {
+                if local_name(&property) == "property" {
+                    let mut attributes = self.attributes(&property)?;
+                    if let Some(name) = attributes.remove("name") {
+                        properties.insert(name, attributes.remove("value").unwrap_or_default());
+                    }
+                }
+                self.skip(property, property_is_empty)?;
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Returns the next child element of the current element, or `None` once the current element ends.
+    fn next_child(&mut self, is_empty: bool) -> Result<Option<(BytesStart<'a>, bool)>, BpmnError> {
+        if is_empty {
+            return Ok(None);
+        }
+
+        loop {
+            match self.read_event()? {
+                Event::Start(start) => return Ok(Some((start, false))),
+                Event::Empty(start) => return Ok(Some((start, true))),
+                Event::End(_) => return Ok(None),
+                Event::Eof => return Err(self.xml_error("Unexpected end of document.")),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads the text content of the current element, ignoring nested elements.
+    fn read_text(&mut self, is_empty: bool) -> Result<String, BpmnError> {
+        let mut text = String::new();
+        if is_empty {
+            return Ok(text);
+        }
+
+        loop {
+            match self.read_event()? {
+                Event::Text(content) => {
+                    let content = content
+                        .unescape()
+                        .map_err(|err| self.xml_error(&err.to_string()))?;
+                    text.push_str(&content);
+                }
+                Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content)),
+                Event::Start(start) => self.skip(start, false)?,
+                Event::End(_) => return Ok(text.trim().to_string()),
+                Event::Eof => return Err(self.xml_error("Unexpected end of document.")),
+                _ => {}
+            }
+        }
+    }
+
+    fn skip(&mut self, start: BytesStart<'a>, is_empty: bool) -> Result<(), BpmnError> {
+        if !is_empty {
+            self.reader
+                .read_to_end(start.name())
+                .map_err(|err| self.xml_error(&err.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    fn attributes(&self, start: &BytesStart<'a>) -> Result<Attributes, BpmnError> {
+        let mut attributes = Attributes::new();
+
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|err| self.xml_error(&err.to_string()))?;
+            if attribute.key.as_namespace_binding().is_some() {
+                continue;
+            }
+
+            let value = attribute
+                .unescape_value()
+                .map_err(|err| self.xml_error(&err.to_string()))?;
+            attributes.insert(
+                String::from_utf8_lossy(attribute.key.local_name().as_ref()).into_owned(),
+                value.into_owned(),
+            );
+        }
+
+        Ok(attributes)
+    }
+
+    fn read_event(&mut self) -> Result<Event<'a>, BpmnError> {
+        self.reader
+            .read_event()
+            .map_err(|err| self.xml_error(&err.to_string()))
+    }
+
+    fn xml_error(&self, message: &str) -> BpmnError {
+        BpmnError::Xml {
+            message: message.to_string(),
+            position: self.reader.buffer_position(),
+        }
+    }
+}
+
+fn local_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.local_name().as_ref()).into_owned()
+}
+
+/// Maps the local name of a BPMN element to the type of flow node it describes.
+fn bpmn_type(name: &str) -> Option<BpmnType> {
+    let bpmn_type = match name {
+        "boundaryEvent" => BpmnType::BoundaryEvent,
+        "businessRuleTask" => BpmnType::BusinessRuleTask,
+        "callActivity" => BpmnType::CallActivity,
+        "complexGateway" => BpmnType::ComplexGateway,
+        "endEvent" => BpmnType::EndEvent,
+        "eventBasedGateway" => BpmnType::EventBasedGateway,
+        "exclusiveGateway" => BpmnType::ExclusiveGateway,
+        "inclusiveGateway" => BpmnType::InclusiveGateway,
+        "intermediateCatchEvent" => BpmnType::IntermediateCatchEvent,
+        "intermediateThrowEvent" => BpmnType::IntermediateThrowEvent,
+        "manualTask" => BpmnType::ManualTask,
+        "parallelGateway" => BpmnType::ParallelGateway,
+        "receiveTask" => BpmnType::ReceiveTask,
+        "scriptTask" => BpmnType::ScriptTask,
+        "sendTask" => BpmnType::SendTask,
+        "serviceTask" => BpmnType::ServiceTask,
+        "startEvent" => BpmnType::StartEvent,
+        "subProcess" => BpmnType::SubProcess,
+        "task" => BpmnType::EmptyActivity,
+        "userTask" => BpmnType::UserTask,
+        _ => return None,
+    };
+
+    Some(bpmn_type)
+}
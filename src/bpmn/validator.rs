@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use crate::clients::flow_node_instance::flow_node_instance::BpmnType;
+
+use super::{
+    bpmn::{Definitions, FlowNode, Process, SequenceFlow},
+    error::{BpmnError, ValidationIssue},
+    parser::parse,
+};
+
+/// Checks whether the ProcessCube® Engine can execute the given BPMN 2.0 document.
+///
+/// All issues found are reported at once.
+pub fn validate(definitions: &Definitions) -> Result<(), BpmnError> {
+    let issues = find_issues(definitions);
+
+    match issues.is_empty() {
+        true => Ok(()),
+        false => Err(BpmnError::Validation(issues)),
+    }
+}
+
+/// Parses the given BPMN 2.0 document and checks whether the ProcessCube® Engine can execute it.
+///
+/// # Example
+/// ```
+/// use processcube_engine_client::bpmn::{error::BpmnError, validator::parse_and_validate};
+///
+/// let result = parse_and_validate("foo");
+/// assert!(matches!(result, Err(BpmnError::Xml { .. })));
+/// ```
+pub fn parse_and_validate(xml: &str) -> Result<Definitions, BpmnError> {
+    let definitions = parse(xml)?;
+    validate(&definitions)?;
+    Ok(definitions)
+}
+
+/// Returns all issues preventing the execution of the given BPMN 2.0 document.
+pub fn find_issues(definitions: &Definitions) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    if definitions.processes.is_empty() {
+        issues.push(ValidationIssue::NoProcess);
+    } else if !definitions
+        .processes
+        .iter()
+        .any(|process| process.is_executable)
+    {
+        issues.push(ValidationIssue::NoExecutableProcess);
+    }
+
+    for id in duplicate_ids(definitions) {
+        issues.push(ValidationIssue::DuplicateId { id });
+    }
+
+    for process in &definitions.processes {
+        find_process_issues(process, &mut issues);
+    }
+
+    issues
+}
+
+/// Collects the issues of a single process.
+///
+/// Processes that are not executable, e.g. the pool of an external participant in a collaboration,
+/// are only checked for dangling sequence flows, since the ProcessCube® Engine never starts them.
+fn find_process_issues(process: &Process, issues: &mut Vec<ValidationIssue>) {
+    if process.is_executable {
+        if !has_flow_node_of_type(&process.flow_nodes, &BpmnType::StartEvent) {
+            issues.push(ValidationIssue::MissingStartEvent {
+                process_id: process.id.clone(),
+            });
+        }
+        if !has_flow_node_of_type(&process.flow_nodes, &BpmnType::EndEvent) {
+            issues.push(ValidationIssue::MissingEndEvent {
+                process_id: process.id.clone(),
+            });
+        }
+    }
+
+    let flow_node_ids: HashSet<&str> = process
+        .all_flow_nodes()
+        .iter()
+        .map(|flow_node| flow_node.id.as_str())
+        .collect();
+
+    for sequence_flow in all_sequence_flows(process) {
+        if !flow_node_ids.contains(sequence_flow.source_ref.as_str()) {
+            issues.push(ValidationIssue::DanglingSourceRef {
+                sequence_flow_id: sequence_flow.id.clone(),
+                source_ref: sequence_flow.source_ref.clone(),
+            });
+        }
+        if !flow_node_ids.contains(sequence_flow.target_ref.as_str()) {
+            issues.push(ValidationIssue::DanglingTargetRef {
+                sequence_flow_id: sequence_flow.id.clone(),
+                target_ref: sequence_flow.target_ref.clone(),
+            });
+        }
+    }
+}
+
+fn has_flow_node_of_type(flow_nodes: &[FlowNode], bpmn_type: &BpmnType) -> bool {
+    flow_nodes
+        .iter()
+        .any(|flow_node| &flow_node.bpmn_type == bpmn_type)
+}
+
+fn all_sequence_flows(process: &Process) -> Vec<&SequenceFlow> {
+    let nested = process
+        .all_flow_nodes()
+        .into_iter()
+        .flat_map(|flow_node| flow_node.sequence_flows.iter());
+
+    process.sequence_flows.iter().chain(nested).collect()
+}
+
+/// Returns each ID used by more than one element, in the order of their first reuse.
+///
+/// Only the elements the ProcessCube® Engine looks up by ID are considered,
+/// since diagrams commonly share the ID of a process with its participant.
+fn duplicate_ids(definitions: &Definitions) -> Vec<String> {
+    let mut ids: Vec<&str> = Vec::new();
+
+    for process in &definitions.processes {
+        ids.push(process.id.as_str());
+        ids.extend(process.all_lanes().iter().map(|lane| lane.id.as_str()));
+        ids.extend(process.all_flow_nodes().iter().map(|node| node.id.as_str()));
+        ids.extend(
+            all_sequence_flows(process)
+                .iter()
+                .map(|flow| flow.id.as_str()),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    let mut duplicates: Vec<String> = Vec::new();
+    for id in ids.into_iter().filter(|id| !id.is_empty()) {
+        if !seen.insert(id) && !duplicates.iter().any(|duplicate| duplicate == id) {
+            duplicates.push(id.to_string());
+        }
+    }
+
+    duplicates
+}
@@ -33,17 +33,36 @@ pub enum Client {
         #[clap(subcommand)]
         cmd: subcommands::correlation::CorrelationCommands,
     },
+    /// Deploy a directory of BPMN files, uploading only new or changed Process Definitions.
+    Deploy {
+        #[command(flatten)]
+        cmd: subcommands::deploy::DeployCommand,
+    },
     /// Trigger Events.
-    Event,
-    /// Get all Flow Node Instances.
-    FlowNodeInstance,
+    Event {
+        #[clap(subcommand)]
+        cmd: subcommands::event::EventCommands,
+    },
+    /// Get Flow Node Instances.
+    FlowNodeInstance {
+        #[clap(subcommand)]
+        cmd: subcommands::flow_node_instance::FlowNodeInstanceCommands,
+    },
     /// Handle Process Definitions.
     ProcessDefinition {
         #[clap(subcommand)]
         cmd: subcommands::process_definition::ProcessDefinitionCommands,
     },
     /// Handle Process Models.
-    ProcessModel,
+    ProcessModel {
+        #[clap(subcommand)]
+        cmd: subcommands::process_model::ProcessModelCommands,
+    },
+    /// Make the engine match a manifest of Process Definitions and Process Model states.
+    Sync {
+        #[command(flatten)]
+        cmd: subcommands::sync::SyncCommand,
+    },
 }
 
 pub async fn register_commands(cli: Cli) {
@@ -56,17 +75,17 @@ pub async fn register_commands(cli: Cli) {
         Client::Correlation { cmd } => {
             subcommands::correlation::register_commands(client_factory, cmd).await
         }
-        Client::Event => {
-            println!("Event");
-        }
-        Client::FlowNodeInstance => {
-            println!("FlowNodeInstance");
+        Client::Deploy { cmd } => subcommands::deploy::register_commands(client_factory, cmd).await,
+        Client::Event { cmd } => subcommands::event::register_commands(client_factory, cmd).await,
+        Client::FlowNodeInstance { cmd } => {
+            subcommands::flow_node_instance::register_commands(client_factory, cmd).await
         }
         Client::ProcessDefinition { cmd } => {
             subcommands::process_definition::register_commands(client_factory, cmd).await
         }
-        Client::ProcessModel => {
-            println!("ProcessModel");
+        Client::ProcessModel { cmd } => {
+            subcommands::process_model::register_commands(client_factory, cmd).await
         }
+        Client::Sync { cmd } => subcommands::sync::register_commands(client_factory, cmd).await,
     }
 }
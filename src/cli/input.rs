@@ -0,0 +1,26 @@
+use std::io::Read;
+
+/// Reads a JSON document from the file at the given path, or from stdin if the path is `-`.
+pub fn read_json_file(path: &str) -> Result<serde_json::Value, String> {
+    let content = match path {
+        "-" => {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .map_err(|e| format!("Error reading stdin: {}", e))?;
+            content
+        }
+        _ => std::fs::read_to_string(path)
+            .map_err(|e| format!("Error reading file `{}`: {}", path, e))?,
+    };
+
+    serde_json::from_str(&content).map_err(|e| format!("Error parsing JSON: {}", e))
+}
+
+/// Parses a JSON argument given either inline or as `@path` to a file containing it.
+pub fn parse_json_argument(argument: &str) -> Result<serde_json::Value, String> {
+    match argument.strip_prefix('@') {
+        Some(path) => read_json_file(path),
+        None => serde_json::from_str(argument).map_err(|e| format!("Error parsing JSON: {}", e)),
+    }
+}
@@ -0,0 +1,44 @@
+use clap::Args;
+use serde::Deserialize;
+
+use crate::{
+    clients::client_factory::ClientFactory,
+    deployment::{deployer::Deployer, error::DeploymentError},
+};
+
+#[derive(Clone, Debug, Deserialize, Args)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeployCommand {
+    /// The directory containing the BPMN files to deploy.
+    directory: String,
+    /// Print which Process Definitions would be created or updated without uploading them.
+    #[clap(long)]
+    #[serde(default)]
+    dry_run: bool,
+}
+
+pub async fn register_commands(client_factory: ClientFactory, cmd: DeployCommand) {
+    let deployer = Deployer::new(client_factory.create_process_definition_client());
+
+    let plan = match deployer.plan(&cmd.directory).await {
+        Ok(plan) => plan,
+        Err(e) => {
+            eprintln!("Error planning deployment: {}", e);
+            return;
+        }
+    };
+    println!("{}", plan);
+
+    if cmd.dry_run {
+        return;
+    }
+
+    match deployer.deploy(&plan).await {
+        Ok(summary) => println!("{}", summary),
+        Err(DeploymentError::PartialDeployment { summary, source }) => {
+            println!("{}", summary);
+            eprintln!("Error deploying process definitions: {}", source);
+        }
+        Err(e) => eprintln!("Error deploying process definitions: {}", e),
+    }
+}
@@ -0,0 +1,78 @@
+use clap::Subcommand;
+use serde::Deserialize;
+
+use crate::{cli::input::parse_json_argument, clients::client_factory::ClientFactory};
+
+#[derive(Clone, Debug, Deserialize, Subcommand)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventCommands {
+    /// Triggers a Message.
+    Message {
+        /// The name of the Message to trigger.
+        name: String,
+        /// The ID of the Process Instance to send the Message to. Sends it to all Process Instances if omitted.
+        #[clap(long)]
+        process_instance_id: Option<String>,
+        /// The payload to send with the Message, as inline JSON or `@file.json`.
+        #[clap(long)]
+        payload: Option<String>,
+    },
+    /// Triggers a Signal.
+    Signal {
+        /// The name of the Signal to trigger.
+        name: String,
+        /// The ID of the Process Instance to send the Signal to. Sends it to all Process Instances if omitted.
+        #[clap(long)]
+        process_instance_id: Option<String>,
+        /// The payload to send with the Signal, as inline JSON or `@file.json`.
+        #[clap(long)]
+        payload: Option<String>,
+    },
+}
+
+pub async fn register_commands(client_factory: ClientFactory, cmd: EventCommands) {
+    let client = client_factory.create_event_client();
+
+    match cmd {
+        EventCommands::Message {
+            name,
+            process_instance_id,
+            payload,
+        } => {
+            let payload = match payload.as_deref().map(parse_json_argument).transpose() {
+                Ok(payload) => payload,
+                Err(e) => {
+                    eprintln!("Error reading payload: {}", e);
+                    return;
+                }
+            };
+            match client
+                .trigger_message(&name, process_instance_id.as_deref(), payload)
+                .await
+            {
+                Ok(_) => println!("Message triggered"),
+                Err(e) => eprintln!("Error triggering message: {:#?}", e),
+            }
+        }
+        EventCommands::Signal {
+            name,
+            process_instance_id,
+            payload,
+        } => {
+            let payload = match payload.as_deref().map(parse_json_argument).transpose() {
+                Ok(payload) => payload,
+                Err(e) => {
+                    eprintln!("Error reading payload: {}", e);
+                    return;
+                }
+            };
+            match client
+                .trigger_signal(&name, process_instance_id.as_deref(), payload)
+                .await
+            {
+                Ok(_) => println!("Signal triggered"),
+                Err(e) => eprintln!("Error triggering signal: {:#?}", e),
+            }
+        }
+    }
+}
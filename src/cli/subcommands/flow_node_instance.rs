@@ -0,0 +1,116 @@
+use clap::{Args, Subcommand};
+use serde::Deserialize;
+
+use crate::clients::{
+    client_factory::ClientFactory, flow_node_instance::flow_node_instance::FlowNodeInstancesQuery,
+};
+
+#[derive(Clone, Debug, Deserialize, Subcommand)]
+#[serde(rename_all = "kebab-case")]
+pub enum FlowNodeInstanceCommands {
+    /// Gets all Flow Node Instances matching the given filters.
+    GetAll {
+        #[clap(flatten)]
+        query: Box<FlowNodeInstancesQueryArgs>,
+        /// The number of Flow Node Instances to skip.
+        #[clap(long)]
+        offset: Option<u32>,
+        /// The maximum number of Flow Node Instances to return.
+        #[clap(long)]
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Args, Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FlowNodeInstancesQueryArgs {
+    /// Filter by Correlation ID.
+    #[clap(long)]
+    correlation_id: Option<String>,
+    /// Filter by creation date.
+    #[clap(long)]
+    created_at: Option<String>,
+    /// Filter by event type, e.g. `messageEvent`.
+    #[clap(long)]
+    event_type: Option<String>,
+    /// Filter by Flow Node ID.
+    #[clap(long)]
+    flow_node_id: Option<String>,
+    /// Filter by Flow Node Instance ID.
+    #[clap(long)]
+    flow_node_instance_id: Option<String>,
+    /// Filter by Flow Node lane.
+    #[clap(long)]
+    flow_node_lane: Option<String>,
+    /// Filter by Flow Node name.
+    #[clap(long)]
+    flow_node_name: Option<String>,
+    /// Filter by Flow Node type, e.g. `bpmn:UserTask`.
+    #[clap(long)]
+    flow_node_type: Option<String>,
+    /// Filter by owner ID.
+    #[clap(long)]
+    owner_id: Option<String>,
+    /// Filter by parent Process Instance ID.
+    #[clap(long)]
+    parent_process_instance_id: Option<String>,
+    /// Filter by previous Flow Node Instance ID.
+    #[clap(long)]
+    previous_flow_node_instance_id: Option<String>,
+    /// Filter by Process Definition ID.
+    #[clap(long)]
+    process_definition_id: Option<String>,
+    /// Filter by Process Instance ID.
+    #[clap(long)]
+    process_instance_id: Option<String>,
+    /// Filter by Process Model ID.
+    #[clap(long)]
+    process_model_id: Option<String>,
+    /// Filter by state, e.g. `running` or `suspended`.
+    #[clap(long)]
+    state: Option<String>,
+    /// Filter by date of the last update.
+    #[clap(long)]
+    updated_at: Option<String>,
+}
+
+impl From<FlowNodeInstancesQueryArgs> for FlowNodeInstancesQuery {
+    fn from(args: FlowNodeInstancesQueryArgs) -> Self {
+        FlowNodeInstancesQuery {
+            correlation_id: args.correlation_id,
+            created_at: args.created_at,
+            event_type: args.event_type,
+            flow_node_id: args.flow_node_id,
+            flow_node_instance_id: args.flow_node_instance_id,
+            flow_node_lane: args.flow_node_lane,
+            flow_node_name: args.flow_node_name,
+            flow_node_type: args.flow_node_type,
+            owner_id: args.owner_id,
+            parent_process_instance_id: args.parent_process_instance_id,
+            previous_flow_node_instance_id: args.previous_flow_node_instance_id,
+            process_definition_id: args.process_definition_id,
+            process_instance_id: args.process_instance_id,
+            process_model_id: args.process_model_id,
+            state: args.state,
+            updated_at: args.updated_at,
+        }
+    }
+}
+
+pub async fn register_commands(client_factory: ClientFactory, cmd: FlowNodeInstanceCommands) {
+    let client = client_factory.create_flow_node_instance_client();
+
+    match cmd {
+        FlowNodeInstanceCommands::GetAll {
+            query,
+            offset,
+            limit,
+        } => match client
+            .get_flow_node_instances(offset, limit, Some((*query).into()))
+            .await
+        {
+            Ok(flow_node_instances) => println!("{:#?}", flow_node_instances),
+            Err(e) => eprintln!("Error getting flow node instances: {:#?}", e),
+        },
+    }
+}
@@ -1,3 +1,8 @@
 pub mod application_info;
 pub mod correlation;
+pub mod deploy;
+pub mod event;
+pub mod flow_node_instance;
 pub mod process_definition;
+pub mod process_model;
+pub mod sync;
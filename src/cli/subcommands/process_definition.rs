@@ -22,6 +22,10 @@ pub enum ProcessDefinitionCommands {
         xml: String,
         /// Whether to overwrite an existing Process Definition with the same ID.
         overwrite_existing: Option<bool>,
+        /// Upload the Process Definition without checking it for errors first.
+        #[clap(long)]
+        #[serde(default)]
+        skip_validation: bool,
     },
     /// Deletes a Process Definition.
     Delete {
@@ -51,14 +55,18 @@ pub async fn register_commands(client_factory: ClientFactory, cmd: ProcessDefini
         ProcessDefinitionCommands::Post {
             xml,
             overwrite_existing,
+            skip_validation,
         } => {
             let request = PersistProcessDefinitionPayload {
                 xml,
-                overwrite_existing: match overwrite_existing {
-                    Some(overwrite_existing) => overwrite_existing,
-                    None => false,
-                },
+                overwrite_existing: overwrite_existing.unwrap_or_default(),
             };
+            if !skip_validation {
+                if let Err(e) = request.validate() {
+                    eprintln!("Invalid process definition: {}", e);
+                    return;
+                }
+            }
             match client.upload_process_definition(request).await {
                 Ok(_) => println!("Process definition uploaded"),
                 Err(e) => eprintln!("Error uploading process definition: {:#?}", e),
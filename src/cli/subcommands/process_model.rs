@@ -0,0 +1,152 @@
+use clap::{Subcommand, ValueEnum};
+use serde::Deserialize;
+
+use crate::{
+    cli::input::read_json_file,
+    clients::{
+        client_factory::ClientFactory,
+        process_model::process_model::{ProcessStartRequest, ReturnOn},
+    },
+};
+
+#[derive(Clone, Debug, Deserialize, Subcommand)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProcessModelCommands {
+    /// Gets all Process Models.
+    GetAll,
+    /// Gets a single Process Model by ID.
+    GetById {
+        /// The ID of the Process Model to retrieve.
+        id: String,
+    },
+    /// Gets the Process Definition of a Process Model.
+    GetDefinition {
+        /// The ID of the Process Model whose Process Definition to retrieve.
+        id: String,
+    },
+    /// Starts a new Process Instance of a Process Model.
+    Start {
+        /// The ID of the Process Model to start.
+        id: String,
+        /// The ID of the Start Event to start the Process Instance at.
+        #[clap(long)]
+        start_event_id: Option<String>,
+        /// The Correlation ID to assign to the Process Instance.
+        #[clap(long)]
+        correlation_id: Option<String>,
+        /// Path to a JSON file containing the initial token. Use `-` to read it from stdin.
+        #[clap(long)]
+        initial_token: Option<String>,
+        /// When the engine should respond to the start request.
+        #[clap(long, value_enum, default_value_t = ReturnOnArg::OnProcessInstanceStarted)]
+        return_on: ReturnOnArg,
+        /// The ID of the End Event to wait for when using `--return-on on-end-event-reached`.
+        #[clap(long)]
+        end_event_id: Option<String>,
+    },
+    /// Enables a Process Model.
+    Enable {
+        /// The ID of the Process Model to enable.
+        id: String,
+    },
+    /// Disables a Process Model.
+    Disable {
+        /// The ID of the Process Model to disable.
+        id: String,
+    },
+    /// Deletes a Process Model.
+    Delete {
+        /// The ID of the Process Model to delete.
+        id: String,
+    },
+}
+
+/// The values of `--return-on`, mapped to the ReturnOn of a ProcessStartRequest.
+#[derive(Clone, Copy, Debug, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReturnOnArg {
+    /// Respond as soon as the Process Instance was started.
+    OnProcessInstanceStarted,
+    /// Respond once the given End Event was reached.
+    OnEndEventReached,
+    /// Respond once the Process Instance is finished.
+    OnProcessInstanceFinished,
+}
+
+impl From<ReturnOnArg> for ReturnOn {
+    fn from(return_on: ReturnOnArg) -> Self {
+        match return_on {
+            ReturnOnArg::OnProcessInstanceStarted => ReturnOn::OnProcessInstanceStarted,
+            ReturnOnArg::OnEndEventReached => ReturnOn::OnEndEventReached,
+            ReturnOnArg::OnProcessInstanceFinished => ReturnOn::OnProcessInstanceFinished,
+        }
+    }
+}
+
+pub async fn register_commands(client_factory: ClientFactory, cmd: ProcessModelCommands) {
+    let client = client_factory.create_process_model_client();
+
+    match cmd {
+        ProcessModelCommands::GetAll => match client.get_process_models(None, None).await {
+            Ok(process_models) => println!("{:#?}", process_models),
+            Err(e) => eprintln!("Error getting process models: {:#?}", e),
+        },
+        ProcessModelCommands::GetById { id } => match client.get_process_model_by_id(&id).await {
+            Ok(process_model) => println!("{:#?}", process_model),
+            Err(e) => eprintln!("Error getting process model: {:#?}", e),
+        },
+        ProcessModelCommands::GetDefinition { id } => {
+            match client.get_process_definition_by_process_model_id(&id).await {
+                Ok(process_definition) => println!("{:#?}", process_definition),
+                Err(e) => eprintln!("Error getting process definition: {:#?}", e),
+            }
+        }
+        ProcessModelCommands::Start {
+            id,
+            start_event_id,
+            correlation_id,
+            initial_token,
+            return_on,
+            end_event_id,
+        } => {
+            let initial_token = match initial_token {
+                Some(path) => match read_json_file(&path) {
+                    Ok(initial_token) => Some(initial_token),
+                    Err(e) => {
+                        eprintln!("Error reading initial token: {}", e);
+                        return;
+                    }
+                },
+                None => None,
+            };
+            let request = ProcessStartRequest {
+                start_event_id,
+                correlation_id,
+                initial_token,
+                return_on: return_on.into(),
+                end_event_id,
+            };
+            match client
+                .start_process_instance_by_process_model_id(&id, request)
+                .await
+            {
+                Ok(response) => println!("{:#?}", response),
+                Err(e) => eprintln!("Error starting process instance: {:#?}", e),
+            }
+        }
+        ProcessModelCommands::Enable { id } => match client.enable_process_model_by_id(&id).await {
+            Ok(_) => println!("Process model enabled"),
+            Err(e) => eprintln!("Error enabling process model: {:#?}", e),
+        },
+        ProcessModelCommands::Disable { id } => {
+            match client.disable_process_model_by_id(&id).await {
+                Ok(_) => println!("Process model disabled"),
+                Err(e) => eprintln!("Error disabling process model: {:#?}", e),
+            }
+        }
+        ProcessModelCommands::Delete { id } => match client.delete_process_model_by_id(&id).await {
+            Ok(_) => println!("Process model deleted"),
+            Err(e) => eprintln!("Error deleting process model: {:#?}", e),
+        },
+    }
+}
@@ -0,0 +1,75 @@
+use std::io::Write;
+
+use clap::Args;
+use serde::Deserialize;
+
+use crate::{
+    clients::client_factory::ClientFactory,
+    deployment::{error::DeploymentError, manifest::SyncManifest, synchronizer::Synchronizer},
+};
+
+#[derive(Clone, Debug, Deserialize, Args)]
+#[serde(rename_all = "kebab-case")]
+pub struct SyncCommand {
+    /// Path to the JSON manifest describing the desired engine state.
+    manifest: String,
+    /// Apply the changes without asking for confirmation.
+    #[clap(short, long)]
+    #[serde(default)]
+    yes: bool,
+    /// Print the changes without applying them.
+    #[clap(long)]
+    #[serde(default)]
+    dry_run: bool,
+}
+
+pub async fn register_commands(client_factory: ClientFactory, cmd: SyncCommand) {
+    let synchronizer = Synchronizer::new(
+        client_factory.create_process_definition_client(),
+        client_factory.create_process_model_client(),
+    );
+
+    let plan = match SyncManifest::from_file(&cmd.manifest) {
+        Ok(manifest) => synchronizer.plan(&manifest).await,
+        Err(e) => Err(e),
+    };
+    let plan = match plan {
+        Ok(plan) => plan,
+        Err(e) => {
+            eprintln!("Error planning sync: {}", e);
+            return;
+        }
+    };
+    println!("{}", plan);
+
+    if cmd.dry_run || !plan.has_changes() {
+        return;
+    }
+    if !cmd.yes && !confirm("Apply these changes?") {
+        println!("Aborted");
+        return;
+    }
+
+    match synchronizer.apply(&plan).await {
+        Ok(summary) => println!("{}", summary),
+        Err(DeploymentError::PartialSync { summary, source }) => {
+            println!("{}", summary);
+            eprintln!("Error syncing engine: {}", source);
+        }
+        Err(e) => eprintln!("Error syncing engine: {}", e),
+    }
+}
+
+/// Asks the user a yes/no question on stdin. Anything but `y` or `yes` counts as no.
+fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    if std::io::stdout().flush().is_err() {
+        return false;
+    }
+
+    let mut answer = String::new();
+    match std::io::stdin().read_line(&mut answer) {
+        Ok(_) => matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"),
+        Err(_) => false,
+    }
+}
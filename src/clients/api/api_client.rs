@@ -1,17 +1,30 @@
-use reqwest::Client;
+use std::sync::Arc;
 
-use crate::clients::error::EngineError;
+use tokio::time::sleep;
 
-const ENGINE_API_ENDPOINT: &str = "/atlas_engine/api/v1";
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+use crate::clients::error::{EngineError, EngineErrorDetails, RawResponse};
+
+use super::{
+    api_client_builder::ApiClientBuilder,
+    auth_provider::{AuthProvider, StaticTokenProvider},
+    retry_policy::RetryPolicy,
+    transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse},
+};
+
+pub(crate) const ENGINE_API_ENDPOINT: &str = "/atlas_engine/api/v1";
 
 /// A client for communicating with the ProcessCube® Engine API.
 ///
-/// It is used as a base for all other clients and uses the reqwest Client for HTTP communication.
+/// It is used as a base for all other clients. Its requests are sent by a Transport, which is a
+/// ReqwestTransport unless another one is set with `with_transport` or the ApiClientBuilder.
 #[derive(Clone)]
 pub struct ApiClient {
-    pub http_client: Client,
+    transport: Arc<dyn Transport>,
     engine_url: String,
-    auth_token: String,
+    auth_provider: Arc<dyn AuthProvider>,
+    retry_policy: RetryPolicy,
 }
 
 impl ApiClient {
@@ -36,19 +49,136 @@ impl ApiClient {
     ///     // Read the Engine API endpoint
     ///     println!("Engine API endpoint: {}", api_client.get_engine_api_endpoint());
     ///     // Read the authentication token
-    ///     println!("Auth token: {}", api_client.get_auth_token());
+    ///     println!("Auth token: {}", api_client.get_auth_token().await?);
     ///     Ok(())
     /// }
     /// ```
     pub fn new(engine_url: &str, auth_token: &str) -> ApiClient {
-        let http_client = Client::new();
+        ApiClient::with_auth_provider(engine_url, StaticTokenProvider::new(auth_token))
+    }
+
+    /// Creates a new instance of the ApiClient that asks the given AuthProvider for a token before each request.
+    ///
+    /// # Arguments
+    /// * `engine_url` - The URL of the ProcessCube® Engine.
+    /// * `auth_provider` - The AuthProvider supplying the authentication token.
+    ///
+    /// # Example
+    /// ```
+    /// use processcube_engine_client::clients::{api::{api_client::ApiClient, auth_provider::ClientCredentialsProvider}, error::EngineError};
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// const AUTHORITY_URL: &str = "http://localhost:11560";
+    ///
+    /// let auth_provider = ClientCredentialsProvider::new(AUTHORITY_URL, "my_client", "my_secret", "engine_read engine_write");
+    /// let api_client = ApiClient::with_auth_provider(ENGINE_URL, auth_provider);
+    /// ```
+    pub fn with_auth_provider<P>(engine_url: &str, auth_provider: P) -> ApiClient
+    where
+        P: AuthProvider + 'static,
+    {
+        ApiClient::from_parts(
+            Arc::new(ReqwestTransport::default()),
+            engine_url,
+            Arc::new(auth_provider),
+            RetryPolicy::no_retries(),
+        )
+    }
+
+    /// Returns an ApiClientBuilder for configuring timeouts, TLS, proxies and default headers.
+    ///
+    /// # Arguments
+    /// * `engine_url` - The URL of the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use processcube_engine_client::clients::{api::api_client::ApiClient, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    ///
+    /// fn main() -> Result<(), EngineError> {
+    ///     let api_client = ApiClient::builder(ENGINE_URL)
+    ///         .auth_token(DUMMY_TOKEN)
+    ///         .connect_timeout(Duration::from_secs(5))
+    ///         .timeout(Duration::from_secs(30))
+    ///         .user_agent("my_application/1.0")
+    ///         .default_header("X-Tenant-Id", "my_tenant")
+    ///         .build()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder(engine_url: &str) -> ApiClientBuilder {
+        ApiClientBuilder::new(engine_url)
+    }
+
+    pub(crate) fn from_parts(
+        transport: Arc<dyn Transport>,
+        engine_url: &str,
+        auth_provider: Arc<dyn AuthProvider>,
+        retry_policy: RetryPolicy,
+    ) -> ApiClient {
         ApiClient {
-            http_client,
+            transport,
             engine_url: engine_url.to_string(),
-            auth_token: auth_token.to_string(),
+            auth_provider,
+            retry_policy,
         }
     }
 
+    /// Returns the ApiClient with the given RetryPolicy applied to all of its requests.
+    ///
+    /// By default, every request is sent exactly once.
+    ///
+    /// # Arguments
+    /// * `retry_policy` - The RetryPolicy deciding when a failed request is repeated.
+    ///
+    /// # Example
+    /// ```
+    /// use processcube_engine_client::clients::api::{api_client::ApiClient, retry_policy::RetryPolicy};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    ///
+    /// let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN).with_retry_policy(RetryPolicy {
+    ///     max_attempts: 5,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> ApiClient {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the ApiClient with all of its requests sent by the given Transport.
+    ///
+    /// This allows to answer requests in memory in unit tests, or to send them with an HTTP client
+    /// configured elsewhere.
+    ///
+    /// # Arguments
+    /// * `transport` - The Transport sending the requests.
+    ///
+    /// # Example
+    /// ```
+    /// use processcube_engine_client::clients::api::{api_client::ApiClient, transport::ReqwestTransport};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    ///
+    /// let http_client = reqwest::Client::builder().pool_max_idle_per_host(4).build().unwrap();
+    /// let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN)
+    ///     .with_transport(ReqwestTransport::new(http_client));
+    /// ```
+    pub fn with_transport<T>(mut self, transport: T) -> ApiClient
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Returns the RetryPolicy applied to all requests.
+    pub fn get_retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
     /// Returns The URL of the ProcessCube® Engine.
     pub fn get_engine_url(&self) -> &str {
         &self.engine_url
@@ -59,9 +189,112 @@ impl ApiClient {
         ENGINE_API_ENDPOINT
     }
 
-    /// Returns the authentication token used by the ApiClient.
-    pub fn get_auth_token(&self) -> &str {
-        &self.auth_token
+    /// Returns the authentication token the ApiClient currently sends.
+    pub async fn get_auth_token(&self) -> Result<String, EngineError> {
+        self.auth_provider.get_auth_token().await
+    }
+
+    /// Sends a request, in a span of its own if the `tracing` feature is enabled.
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<TransportResponse, EngineError> {
+        #[cfg(feature = "tracing")]
+        return super::instrumentation::instrument(
+            &method,
+            url,
+            body,
+            self.send_with_retries(method.clone(), url, body),
+        )
+        .await;
+
+        #[cfg(not(feature = "tracing"))]
+        self.send_with_retries(method, url, body).await
+    }
+
+    /// Sends a request with the current authentication token, repeating it as the RetryPolicy allows.
+    ///
+    /// If the ProcessCube® Engine rejects the token and the AuthProvider can supply a fresh one,
+    /// the request is sent once more without counting as an attempt.
+    async fn send_with_retries(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<TransportResponse, EngineError> {
+        let mut attempt = 1;
+        let mut token_refreshed = false;
+
+        loop {
+            let auth_token = self.get_auth_token().await?;
+            let result = self.send_once(method.clone(), url, body, &auth_token).await;
+            let can_retry = attempt < self.retry_policy.max_attempts;
+
+            let delay = match result {
+                Ok(response)
+                    if response.status == StatusCode::UNAUTHORIZED
+                        && !token_refreshed
+                        && self.auth_provider.invalidate().await =>
+                {
+                    token_refreshed = true;
+                    continue;
+                }
+                Ok(response)
+                    if can_retry
+                        && self
+                            .retry_policy
+                            .should_retry_status(&method, response.status) =>
+                {
+                    match self.retry_policy.retry_delay(attempt, &response) {
+                        Some(delay) => delay,
+                        None => return Ok(response),
+                    }
+                }
+                Err(error)
+                    if can_retry && self.retry_policy.should_retry_error(&method, &error) =>
+                {
+                    self.retry_policy.backoff(attempt)
+                }
+                result => return result,
+            };
+
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn send_once(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+        auth_token: &str,
+    ) -> Result<TransportResponse, EngineError> {
+        let mut headers = HeaderMap::new();
+        // An empty token means the Transport takes care of authentication.
+        if !auth_token.is_empty() {
+            headers.insert(
+                "Authorization",
+                auth_token.parse().map_err(|_| {
+                    EngineError::Configuration(
+                        "The auth token is not a valid header value.".to_string(),
+                    )
+                })?,
+            );
+        }
+        #[cfg(feature = "tracing")]
+        super::instrumentation::inject_trace_context(&mut headers);
+
+        let request = TransportRequest {
+            method,
+            url: url.to_string(),
+            headers,
+            body: body.map(serde_json::to_vec).transpose()?,
+        };
+
+        self.transport.send(request).await
     }
 
     /// Sends a GET request to the given URL and returns the response as a deserialized object.
@@ -72,16 +305,11 @@ impl ApiClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let response = self
-            .http_client
-            .get(url)
-            .header("Authorization", self.get_auth_token())
-            .send()
-            .await?;
-
-        match response.status() {
-            reqwest::StatusCode::OK => Ok(response.json::<T>().await?),
-            _ => Err(response.json::<EngineError>().await?),
+        let response = self.send(Method::GET, url, None).await?;
+
+        match response.status {
+            StatusCode::OK => Ok(read_json::<T>(response)?),
+            _ => Err(read_error(response)),
         }
     }
 
@@ -98,30 +326,38 @@ impl ApiClient {
     where
         T: serde::de::DeserializeOwned + Default,
     {
-        let response = match body {
-            Some(body) => {
-                self.http_client
-                    .post(url)
-                    .header("Authorization", self.get_auth_token())
-                    .json(body)
-                    .send()
-                    .await?
-            }
-            None => {
-                self.http_client
-                    .post(url)
-                    .header("Authorization", self.get_auth_token())
-                    .send()
-                    .await?
-            }
-        };
+        let response = self.send(Method::POST, url, body).await?;
 
-        match response.status().as_u16() {
-            200..=299 => match response.content_length().unwrap_or_default() {
-                0 => Ok(serde_json::from_str("{}").unwrap_or_default()),
-                _ => Ok(response.json::<T>().await?),
+        match response.status.as_u16() {
+            200..=299 => match response.body.is_empty() {
+                true => Ok(serde_json::from_str("{}").unwrap_or_default()),
+                false => Ok(read_json::<T>(response)?),
             },
-            _ => Err(response.json::<EngineError>().await?),
+            _ => Err(read_error(response)),
+        }
+    }
+
+    /// Sends a PUT request to the given URL and returns the response as a deserialized object.
+    ///
+    /// # Arguments
+    /// * `url` - The URL to send the PUT request to.
+    /// * `body` - The body of the PUT request as a JSON object. If no body is required, this can be set to `None`.
+    pub async fn put<T>(
+        &self,
+        url: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<T, EngineError>
+    where
+        T: serde::de::DeserializeOwned + Default,
+    {
+        let response = self.send(Method::PUT, url, body).await?;
+
+        match response.status.as_u16() {
+            200..=299 => match response.body.is_empty() {
+                true => Ok(serde_json::from_str("{}").unwrap_or_default()),
+                false => Ok(read_json::<T>(response)?),
+            },
+            _ => Err(read_error(response)),
         }
     }
 
@@ -133,19 +369,51 @@ impl ApiClient {
     where
         T: serde::de::DeserializeOwned + Default,
     {
-        let response = self
-            .http_client
-            .delete(url)
-            .header("Authorization", self.get_auth_token())
-            .send()
-            .await?;
-
-        match response.status().as_u16() {
-            200..=299 => match response.content_length().unwrap_or_default() {
-                0 => Ok(serde_json::from_str("{}").unwrap_or_default()),
-                _ => Ok(response.json::<T>().await?),
+        let response = self.send(Method::DELETE, url, None).await?;
+
+        match response.status.as_u16() {
+            200..=299 => match response.body.is_empty() {
+                true => Ok(serde_json::from_str("{}").unwrap_or_default()),
+                false => Ok(read_json::<T>(response)?),
             },
-            _ => Err(response.json::<EngineError>().await?),
+            _ => Err(read_error(response)),
         }
     }
 }
+
+/// Deserializes the body of a successful response.
+fn read_json<T>(response: TransportResponse) -> Result<T, EngineError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    Ok(serde_json::from_slice::<T>(&response.body)?)
+}
+
+/// Converts an unsuccessful response into the EngineError matching its status code.
+///
+/// Bodies that are not an engine error document, e.g. the HTML page of a reverse proxy,
+/// are kept as they are in the raw response of the error.
+pub(crate) fn read_error(response: TransportResponse) -> EngineError {
+    let status = response.status;
+    let body = String::from_utf8_lossy(&response.body).into_owned();
+
+    let details = serde_json::from_str::<EngineErrorDetails>(&body).unwrap_or_else(|_| {
+        EngineErrorDetails::new(
+            "UnexpectedResponseError",
+            &format!("Unexpected response with status {}", status),
+            status.as_u16(),
+        )
+    });
+
+    EngineError::from_status(
+        status.as_u16(),
+        EngineErrorDetails {
+            raw_response: Some(Box::new(RawResponse {
+                status: status.as_u16(),
+                headers: response.headers,
+                body,
+            })),
+            ..details
+        },
+    )
+}
@@ -0,0 +1,288 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Certificate, Client, Identity, Proxy,
+};
+
+use crate::clients::error::EngineError;
+
+use super::{
+    api_client::ApiClient,
+    auth_provider::{AuthProvider, ClientCredentialsProvider, StaticTokenProvider},
+    retry_policy::RetryPolicy,
+    transport::{ReqwestTransport, Transport},
+};
+
+/// A builder for an ApiClient whose HTTP connection needs more than the default settings.
+///
+/// All settings are validated when calling `build`.
+pub struct ApiClientBuilder {
+    engine_url: String,
+    auth_provider: Option<Arc<dyn AuthProvider>>,
+    client_credentials: Option<ClientCredentialsProvider>,
+    retry_policy: RetryPolicy,
+    transport: Option<Arc<dyn Transport>>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    root_certificates: Vec<Vec<u8>>,
+    identity: Option<(Vec<u8>, Vec<u8>)>,
+    proxy: Option<String>,
+    no_proxy: bool,
+    user_agent: Option<String>,
+    default_headers: Vec<(String, String)>,
+}
+
+impl ApiClientBuilder {
+    /// Creates a new instance of the ApiClientBuilder.
+    ///
+    /// # Arguments
+    /// * `engine_url` - The URL of the ProcessCube® Engine.
+    pub fn new(engine_url: &str) -> ApiClientBuilder {
+        ApiClientBuilder {
+            engine_url: engine_url.to_string(),
+            auth_provider: None,
+            client_credentials: None,
+            retry_policy: RetryPolicy::no_retries(),
+            transport: None,
+            connect_timeout: None,
+            timeout: None,
+            root_certificates: Vec::new(),
+            identity: None,
+            proxy: None,
+            no_proxy: false,
+            user_agent: None,
+            default_headers: Vec::new(),
+        }
+    }
+
+    /// Sets the authentication token to send with each request.
+    pub fn auth_token(self, auth_token: &str) -> ApiClientBuilder {
+        self.auth_provider(StaticTokenProvider::new(auth_token))
+    }
+
+    /// Sets the AuthProvider to ask for a token before each request.
+    pub fn auth_provider<P>(mut self, auth_provider: P) -> ApiClientBuilder
+    where
+        P: AuthProvider + 'static,
+    {
+        self.auth_provider = Some(Arc::new(auth_provider));
+        self.client_credentials = None;
+        self
+    }
+
+    /// Sets the ClientCredentialsProvider to request tokens with.
+    ///
+    /// Unlike with `auth_provider`, the tokens are requested with the HTTP settings of this builder,
+    /// e.g. its root certificates, client certificate, proxy and timeouts.
+    pub fn client_credentials(
+        mut self,
+        auth_provider: ClientCredentialsProvider,
+    ) -> ApiClientBuilder {
+        self.client_credentials = Some(auth_provider);
+        self.auth_provider = None;
+        self
+    }
+
+    /// Sets the RetryPolicy deciding when a failed request is repeated.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> ApiClientBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the Transport to send all requests with instead of the default ReqwestTransport.
+    ///
+    /// The HTTP settings of this builder, e.g. timeouts or proxies, only apply to the default
+    /// ReqwestTransport and can't be combined with another Transport.
+    pub fn transport<T>(mut self, transport: T) -> ApiClientBuilder
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets the maximum time to wait for a connection to the ProcessCube® Engine.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> ApiClientBuilder {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the maximum time a request may take, from connecting until the response body is read.
+    ///
+    /// Should be longer than the long polling timeout of any ExternalTaskWorker using the ApiClient.
+    pub fn timeout(mut self, timeout: Duration) -> ApiClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a PEM encoded certificate to trust in addition to the system's root certificates.
+    pub fn add_root_certificate_pem(mut self, certificate_pem: &[u8]) -> ApiClientBuilder {
+        self.root_certificates.push(certificate_pem.to_vec());
+        self
+    }
+
+    /// Sets the client certificate to authenticate with via mutual TLS.
+    ///
+    /// # Arguments
+    /// * `certificate_pem` - The PEM encoded certificate chain of the client.
+    /// * `private_key_pem` - The PEM encoded PKCS #8 private key of the client.
+    pub fn identity_pem(
+        mut self,
+        certificate_pem: &[u8],
+        private_key_pem: &[u8],
+    ) -> ApiClientBuilder {
+        self.identity = Some((certificate_pem.to_vec(), private_key_pem.to_vec()));
+        self
+    }
+
+    /// Sets the proxy to send all requests through, e.g. `http://proxy.example.com:8080`.
+    ///
+    /// Without a proxy, the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are respected.
+    pub fn proxy(mut self, proxy_url: &str) -> ApiClientBuilder {
+        self.proxy = Some(proxy_url.to_string());
+        self
+    }
+
+    /// Disables all proxies, including those from the environment.
+    pub fn no_proxy(mut self) -> ApiClientBuilder {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Sets the `User-Agent` header to send with each request.
+    pub fn user_agent(mut self, user_agent: &str) -> ApiClientBuilder {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Adds a header to send with each request, e.g. a tenant ID.
+    pub fn default_header(mut self, name: &str, value: &str) -> ApiClientBuilder {
+        self.default_headers
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Creates the ApiClient.
+    ///
+    /// Fails if no authentication token or AuthProvider is set, or if any setting is invalid.
+    /// With a custom Transport, the token may be omitted, e.g. if its service stack sets it with an
+    /// AuthLayer. Requests are then sent without an `Authorization` header.
+    pub fn build(mut self) -> Result<ApiClient, EngineError> {
+        let (transport, http_client): (Arc<dyn Transport>, _) = match &self.transport {
+            Some(_) if self.has_http_settings() => {
+                return Err(configuration_error(
+                    "HTTP settings can't be combined with a custom transport.".to_string(),
+                ))
+            }
+            Some(transport) => (transport.clone(), None),
+            None => {
+                let http_client = self.build_http_client()?;
+                (
+                    Arc::new(ReqwestTransport::new(http_client.clone())),
+                    Some(http_client),
+                )
+            }
+        };
+
+        let auth_provider = match self.client_credentials.take() {
+            Some(client_credentials) => match http_client {
+                Some(http_client) => Arc::new(client_credentials.with_http_client(http_client)),
+                None => Arc::new(client_credentials),
+            },
+            None => match (self.auth_provider.clone(), &self.transport) {
+                (Some(auth_provider), _) => auth_provider,
+                (None, Some(_)) => Arc::new(NoAuthProvider),
+                (None, None) => {
+                    return Err(configuration_error(
+                        "Either an auth token or an AuthProvider must be set.".to_string(),
+                    ))
+                }
+            },
+        };
+
+        Ok(ApiClient::from_parts(
+            transport,
+            &self.engine_url,
+            auth_provider,
+            self.retry_policy,
+        ))
+    }
+
+    /// Returns whether any setting of the default ReqwestTransport is set.
+    fn has_http_settings(&self) -> bool {
+        self.connect_timeout.is_some()
+            || self.timeout.is_some()
+            || !self.root_certificates.is_empty()
+            || self.identity.is_some()
+            || self.proxy.is_some()
+            || self.no_proxy
+            || self.user_agent.is_some()
+            || !self.default_headers.is_empty()
+    }
+
+    fn build_http_client(&self) -> Result<Client, EngineError> {
+        let mut http_client_builder = Client::builder();
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            http_client_builder = http_client_builder.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            http_client_builder = http_client_builder.timeout(timeout);
+        }
+        for certificate_pem in &self.root_certificates {
+            let certificate = Certificate::from_pem(certificate_pem)
+                .map_err(|err| configuration_error(format!("Invalid root certificate: {}", err)))?;
+            http_client_builder = http_client_builder.add_root_certificate(certificate);
+        }
+        if let Some((certificate_pem, private_key_pem)) = &self.identity {
+            let identity =
+                Identity::from_pkcs8_pem(certificate_pem, private_key_pem).map_err(|err| {
+                    configuration_error(format!("Invalid client certificate: {}", err))
+                })?;
+            http_client_builder = http_client_builder.identity(identity);
+        }
+        if let Some(proxy_url) = &self.proxy {
+            let proxy = Proxy::all(proxy_url).map_err(|err| {
+                configuration_error(format!("Invalid proxy `{}`: {}", proxy_url, err))
+            })?;
+            http_client_builder = http_client_builder.proxy(proxy);
+        }
+        if self.no_proxy {
+            http_client_builder = http_client_builder.no_proxy();
+        }
+        if let Some(user_agent) = &self.user_agent {
+            http_client_builder = http_client_builder.user_agent(user_agent);
+        }
+
+        let mut default_headers = HeaderMap::new();
+        for (name, value) in &self.default_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| configuration_error(format!("Invalid header name `{}`.", name)))?;
+            let header_value = HeaderValue::from_str(value).map_err(|_| {
+                configuration_error(format!("Invalid value for header `{}`.", name))
+            })?;
+            default_headers.append(header_name, header_value);
+        }
+        http_client_builder = http_client_builder.default_headers(default_headers);
+
+        http_client_builder
+            .build()
+            .map_err(|err| configuration_error(format!("Invalid HTTP client settings: {}", err)))
+    }
+}
+
+/// The AuthProvider of an ApiClient whose Transport takes care of authentication.
+struct NoAuthProvider;
+
+#[async_trait]
+impl AuthProvider for NoAuthProvider {
+    async fn get_auth_token(&self) -> Result<String, EngineError> {
+        Ok(String::new())
+    }
+}
+
+fn configuration_error(message: String) -> EngineError {
+    EngineError::Configuration(message)
+}
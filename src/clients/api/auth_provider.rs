@@ -0,0 +1,279 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::{Mutex, OnceCell};
+
+use crate::clients::{
+    application_info::application_info_client::ApplicationInfoClient,
+    error::{EngineError, EngineErrorDetails},
+};
+
+/// The time before its actual expiry at which a cached token is considered expired.
+/// Short-lived tokens use at most half of their lifetime as margin instead.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Provides the value of the `Authorization` header the ApiClient sends with each request.
+///
+/// Besides the providers in this module, any async closure returning a header value can be used as a provider.
+#[async_trait]
+pub trait AuthProvider: Send + Sync {
+    /// Returns the value of the `Authorization` header, e.g. `Bearer <token>`.
+    async fn get_auth_token(&self) -> Result<String, EngineError>;
+
+    /// Discards any cached token after the ProcessCube® Engine rejected it.
+    ///
+    /// Returns whether a retry may succeed with a fresh token.
+    async fn invalidate(&self) -> bool {
+        false
+    }
+}
+
+/// An AuthProvider sending the same token with every request.
+#[derive(Clone, Debug)]
+pub struct StaticTokenProvider {
+    auth_token: String,
+}
+
+impl StaticTokenProvider {
+    /// Creates a new instance of the StaticTokenProvider.
+    ///
+    /// # Arguments
+    /// * `auth_token` - The value of the `Authorization` header, e.g. `Bearer <token>`.
+    pub fn new(auth_token: &str) -> StaticTokenProvider {
+        StaticTokenProvider {
+            auth_token: auth_token.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl AuthProvider for StaticTokenProvider {
+    async fn get_auth_token(&self) -> Result<String, EngineError> {
+        Ok(self.auth_token.clone())
+    }
+}
+
+#[async_trait]
+impl<F, Fut> AuthProvider for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, EngineError>> + Send,
+{
+    async fn get_auth_token(&self) -> Result<String, EngineError> {
+        self().await
+    }
+
+    async fn invalidate(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct OpenIdConfiguration {
+    token_endpoint: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct TokenResponse {
+    access_token: String,
+    token_type: Option<String>,
+    expires_in: Option<u64>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    auth_token: String,
+    /// The time from which the token is considered expired and requested anew.
+    refresh_at: Option<Instant>,
+}
+
+impl CachedToken {
+    fn is_valid(&self) -> bool {
+        match self.refresh_at {
+            Some(refresh_at) => Instant::now() < refresh_at,
+            None => true,
+        }
+    }
+}
+
+/// An AuthProvider requesting tokens from an OAuth2 authority using the client credentials grant.
+///
+/// Tokens are cached until shortly before they expire and requested anew
+/// when the ProcessCube® Engine rejects them. The token endpoint is discovered once
+/// and reused for all later token requests.
+#[derive(Debug)]
+pub struct ClientCredentialsProvider {
+    http_client: reqwest::Client,
+    authority_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: String,
+    token_endpoint: OnceCell<String>,
+    cached_token: Mutex<Option<CachedToken>>,
+}
+
+impl ClientCredentialsProvider {
+    /// Creates a new instance of the ClientCredentialsProvider.
+    ///
+    /// # Arguments
+    /// * `authority_url` - The URL of the authority issuing the tokens.
+    /// * `client_id` - The ID of the client to request tokens for.
+    /// * `client_secret` - The secret of the client to request tokens for.
+    /// * `scope` - The space-separated scopes to request, e.g. `engine_read engine_write`.
+    pub fn new(
+        authority_url: &str,
+        client_id: &str,
+        client_secret: &str,
+        scope: &str,
+    ) -> ClientCredentialsProvider {
+        ClientCredentialsProvider {
+            http_client: reqwest::Client::new(),
+            authority_url: authority_url.trim_end_matches('/').to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            scope: scope.to_string(),
+            token_endpoint: OnceCell::new(),
+            cached_token: Mutex::new(None),
+        }
+    }
+
+    /// Creates a new instance of the ClientCredentialsProvider using the authority the ProcessCube® Engine trusts.
+    ///
+    /// # Arguments
+    /// * `application_info_client` - The client used to look up the authority of the ProcessCube® Engine.
+    /// * `client_id` - The ID of the client to request tokens for.
+    /// * `client_secret` - The secret of the client to request tokens for.
+    /// * `scope` - The space-separated scopes to request, e.g. `engine_read engine_write`.
+    pub async fn from_engine(
+        application_info_client: &ApplicationInfoClient,
+        client_id: &str,
+        client_secret: &str,
+        scope: &str,
+    ) -> Result<ClientCredentialsProvider, EngineError> {
+        let authority_url = application_info_client.get_authority_info().await?;
+
+        Ok(ClientCredentialsProvider::new(
+            &authority_url,
+            client_id,
+            client_secret,
+            scope,
+        ))
+    }
+
+    /// Returns the ClientCredentialsProvider sending its requests to the authority with the given reqwest Client.
+    ///
+    /// Use it to apply the same root certificates, client certificate, proxy and timeouts as for the
+    /// ProcessCube® Engine. The ApiClientBuilder does so for a provider set with `client_credentials`.
+    ///
+    /// # Arguments
+    /// * `http_client` - The reqwest Client to request tokens with.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> ClientCredentialsProvider {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Returns the URL of the authority issuing the tokens.
+    pub fn get_authority_url(&self) -> &str {
+        &self.authority_url
+    }
+
+    async fn discover_token_endpoint(&self) -> Result<String, EngineError> {
+        let discovery_url = format!("{}/.well-known/openid-configuration", self.authority_url);
+        let discovery_response = self.http_client.get(&discovery_url).send().await?;
+        if !discovery_response.status().is_success() {
+            return Err(authentication_error(
+                "Error discovering the token endpoint of the authority",
+                discovery_response,
+            )
+            .await);
+        }
+        let configuration = discovery_response.json::<OpenIdConfiguration>().await?;
+
+        Ok(configuration.token_endpoint)
+    }
+
+    async fn request_token(&self) -> Result<CachedToken, EngineError> {
+        // A failed discovery leaves the cell empty, so the next token request discovers again.
+        let token_endpoint = self
+            .token_endpoint
+            .get_or_try_init(|| self.discover_token_endpoint())
+            .await?;
+
+        let token_response = self
+            .http_client
+            .post(token_endpoint)
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.as_str()),
+                ("scope", self.scope.as_str()),
+            ])
+            .send()
+            .await?;
+        if !token_response.status().is_success() {
+            return Err(authentication_error(
+                "Error requesting a token from the authority",
+                token_response,
+            )
+            .await);
+        }
+        let token = token_response.json::<TokenResponse>().await?;
+
+        Ok(CachedToken {
+            auth_token: format!(
+                "{} {}",
+                token.token_type.as_deref().unwrap_or("Bearer"),
+                token.access_token
+            ),
+            refresh_at: token.expires_in.map(|expires_in| {
+                let expires_in = Duration::from_secs(expires_in);
+                Instant::now() + expires_in - TOKEN_EXPIRY_MARGIN.min(expires_in / 2)
+            }),
+        })
+    }
+}
+
+#[async_trait]
+impl AuthProvider for ClientCredentialsProvider {
+    async fn get_auth_token(&self) -> Result<String, EngineError> {
+        let mut cached_token = self.cached_token.lock().await;
+
+        if let Some(token) = cached_token.as_ref().filter(|token| token.is_valid()) {
+            return Ok(token.auth_token.clone());
+        }
+
+        let token = self.request_token().await?;
+        let auth_token = token.auth_token.clone();
+        *cached_token = Some(token);
+
+        Ok(auth_token)
+    }
+
+    async fn invalidate(&self) -> bool {
+        *self.cached_token.lock().await = None;
+        true
+    }
+}
+
+/// Returns the error for an unsuccessful response of the authority.
+///
+/// Rejected client credentials are reported as `400 Bad Request` by OAuth2 authorities, so both
+/// 400 and 401 are returned as Unauthorized. Other statuses, e.g. of an unavailable authority, are
+/// returned as they are, so they can be retried.
+async fn authentication_error(message: &str, response: reqwest::Response) -> EngineError {
+    let code = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    let details = EngineErrorDetails {
+        additional_information: Some(serde_json::Value::String(body)),
+        ..EngineErrorDetails::new("AuthenticationError", message, code)
+    };
+
+    match code {
+        400 | 401 => EngineError::Unauthorized(details),
+        _ => EngineError::from_status(code, details),
+    }
+}
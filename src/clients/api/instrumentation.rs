@@ -0,0 +1,206 @@
+use std::{future::Future, time::Instant};
+
+use opentelemetry::trace::TraceContextExt;
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Method, Url,
+};
+use serde_json::Value;
+use tracing::{field::Empty, Instrument, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::clients::error::EngineError;
+
+use super::{api_client::ENGINE_API_ENDPOINT, transport::TransportResponse};
+
+/// The endpoints of the ProcessCube® Engine API, relative to its base path.
+///
+/// Routes with a fixed segment come before those with a parameter at the same position.
+const ENDPOINT_TEMPLATES: &[&str] = &[
+    "/info",
+    "/authority",
+    "/correlations",
+    "/correlations/{correlation_id}",
+    "/messages/{message_name}/trigger",
+    "/signals/{signal_name}/trigger",
+    "/external_tasks/fetch_and_lock",
+    "/external_tasks/{external_task_id}/extend_lock",
+    "/external_tasks/{external_task_id}/finish",
+    "/external_tasks/{external_task_id}/error",
+    "/flow_node_instances",
+    "/manual_tasks",
+    "/manual_tasks/{flow_node_instance_id}/finish",
+    "/process_definitions",
+    "/process_definitions/{process_definition_id}",
+    "/process_instances/query",
+    "/process_instances/owner",
+    "/process_instances/{process_instance_id}/terminate",
+    "/process_instances/{process_instance_id}/retry",
+    "/process_models",
+    "/process_models/{process_model_id}",
+    "/process_models/{process_model_id}/process_definition",
+    "/process_models/{process_model_id}/start",
+    "/process_models/{process_model_id}/enable",
+    "/process_models/{process_model_id}/disable",
+    "/untyped_tasks",
+    "/untyped_tasks/{flow_node_instance_id}/finish",
+    "/user_tasks",
+    "/user_tasks/{flow_node_instance_id}/reserve",
+    "/user_tasks/{flow_node_instance_id}/cancel-reservation",
+    "/user_tasks/{flow_node_instance_id}/finish",
+];
+
+/// The endpoint template of requests to paths not in `ENDPOINT_TEMPLATES`.
+const UNKNOWN_ENDPOINT: &str = "unknown";
+
+/// Runs a request to the ProcessCube® Engine, including its retries, in a span of its own.
+///
+/// The span is named after the method and endpoint template, e.g. `POST /process_models/{process_model_id}/start`,
+/// and records the ProcessModel and Correlation the request refers to, if known from its URL or body.
+/// Requests to unknown endpoints use the template `unknown` and record their path in `url.path` instead,
+/// so IDs in the path don't end up in the span name.
+pub(crate) async fn instrument<F>(
+    method: &Method,
+    url: &str,
+    body: Option<&Value>,
+    request: F,
+) -> Result<TransportResponse, EngineError>
+where
+    F: Future<Output = Result<TransportResponse, EngineError>>,
+{
+    let span = request_span(method, url, body);
+    let started = Instant::now();
+    let result = request.instrument(span.clone()).await;
+
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    let status = match &result {
+        Ok(response) => Some(response.status.as_u16()),
+        Err(error) => {
+            span.record("error", tracing::field::display(error));
+            error.status()
+        }
+    };
+    if let Some(status) = status {
+        span.record("http.response.status_code", status);
+    }
+    if result.is_err() || status.is_some_and(|status| status >= 400) {
+        span.record("otel.status_code", "ERROR");
+    }
+
+    result
+}
+
+/// Adds the W3C `traceparent` and `tracestate` headers of the current span to the given headers.
+///
+/// Nothing is added unless the span is exported to OpenTelemetry, e.g. with the layer of `tracing-opentelemetry`.
+pub(crate) fn inject_trace_context(headers: &mut HeaderMap) {
+    let context = Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return;
+    }
+
+    let traceparent = format!(
+        "00-{}-{}-{:02x}",
+        span_context.trace_id(),
+        span_context.span_id(),
+        span_context.trace_flags().to_u8()
+    );
+    if let Ok(value) = HeaderValue::from_str(&traceparent) {
+        headers.insert("traceparent", value);
+    }
+    let tracestate = span_context.trace_state().header();
+    if tracestate.is_empty() {
+        return;
+    }
+    if let Ok(value) = HeaderValue::from_str(&tracestate) {
+        headers.insert("tracestate", value);
+    }
+}
+
+fn request_span(method: &Method, url: &str, body: Option<&Value>) -> Span {
+    let url = Url::parse(url).ok();
+    let path = url.as_ref().map(|url| url.path()).unwrap_or_default();
+    let path = path.strip_prefix(ENGINE_API_ENDPOINT).unwrap_or(path);
+    let endpoint = match_endpoint(path);
+    let is_known_endpoint = endpoint.is_some();
+    let (template, parameters) = endpoint.unwrap_or((UNKNOWN_ENDPOINT, Vec::new()));
+
+    let parameter = |name: &str, query_name: &str| {
+        parameters
+            .iter()
+            .find(|(parameter_name, _)| *parameter_name == name)
+            .map(|(_, value)| value.to_string())
+            .or_else(|| {
+                url.as_ref()?
+                    .query_pairs()
+                    .find(|(key, _)| key == query_name)
+                    .map(|(_, value)| value.into_owned())
+            })
+            .or_else(|| body?.get(query_name)?.as_str().map(str::to_string))
+    };
+    let process_model_id = parameter("process_model_id", "processModelId");
+    let correlation_id = parameter("correlation_id", "correlationId");
+
+    let span = tracing::info_span!(
+        "engine_request",
+        otel.name = %format!("{} {}", method, template),
+        otel.kind = "client",
+        otel.status_code = Empty,
+        http.request.method = %method,
+        url.template = %template,
+        url.path = Empty,
+        process_model_id = Empty,
+        correlation_id = Empty,
+        http.response.status_code = Empty,
+        latency_ms = Empty,
+        error = Empty,
+    );
+    if !is_known_endpoint {
+        span.record("url.path", path);
+    }
+    if let Some(process_model_id) = process_model_id {
+        span.record("process_model_id", process_model_id);
+    }
+    if let Some(correlation_id) = correlation_id {
+        span.record("correlation_id", correlation_id);
+    }
+
+    span
+}
+
+/// Returns the endpoint template matching the given path and the values of its parameters,
+/// or `None` if the path belongs to no known endpoint.
+fn match_endpoint(path: &str) -> Option<(&'static str, Vec<(&'static str, &str)>)> {
+    let segments: Vec<&str> = path.split('/').skip(1).collect();
+
+    for template in ENDPOINT_TEMPLATES {
+        let template_segments: Vec<&str> = template.split('/').skip(1).collect();
+        if template_segments.len() != segments.len() {
+            continue;
+        }
+
+        let mut parameters = Vec::new();
+        let matches = template_segments
+            .iter()
+            .zip(&segments)
+            .all(|(template_segment, segment)| {
+                match template_segment
+                    .strip_prefix('{')
+                    .and_then(|name| name.strip_suffix('}'))
+                {
+                    Some(name) => {
+                        parameters.push((name, *segment));
+                        true
+                    }
+                    None => template_segment == segment,
+                }
+            });
+        if matches {
+            return Some((template, parameters));
+        }
+    }
+
+    None
+}
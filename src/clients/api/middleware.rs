@@ -0,0 +1,301 @@
+use std::{
+    mem,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use http::{header::AUTHORIZATION, HeaderValue, Request, Response};
+use tower::{
+    load_shed::error::Overloaded, timeout::error::Elapsed, BoxError, Layer, Service, ServiceExt,
+};
+
+use crate::clients::error::{EngineError, NetworkErrorKind};
+
+use super::{
+    api_client::read_error,
+    auth_provider::AuthProvider,
+    transport::{Transport, TransportRequest, TransportResponse},
+};
+
+/// The body of the requests and responses passed through a tower service stack.
+pub type Body = bytes::Bytes;
+
+/// A Transport sending the requests of an ApiClient through a tower service stack.
+///
+/// This allows to reuse the layers of a service, e.g. for timeouts, concurrency limits or load
+/// shedding. Timeouts of the timeout layer and rejections of the load shedding layer are returned as
+/// `EngineError::Network`, so the ApiClient may repeat the request. Other errors of the stack are
+/// returned as `EngineError::Service`, unless they already are an EngineError, e.g. one returned by
+/// the EngineErrorLayer.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use http::Response;
+/// use processcube_engine_client::clients::{
+///     api::{
+///         auth_provider::StaticTokenProvider,
+///         middleware::{AuthLayer, Body, TowerTransport},
+///     },
+///     client_factory::ClientFactory,
+///     error::EngineError,
+/// };
+/// use serde_json::json;
+/// use tower::{BoxError, ServiceBuilder};
+/// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+/// const ENGINE_URL: &str = "http://localhost:10560";
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), EngineError> {
+///     // The innermost service answers every request itself. In practice, it sends them, e.g. with hyper.
+///     let service = ServiceBuilder::new()
+///         .layer(AuthLayer::new(StaticTokenProvider::new(DUMMY_TOKEN)))
+///         .concurrency_limit(10)
+///         .timeout(Duration::from_secs(30))
+///         .service_fn(|_request| async {
+///             let body = json!({ "correlations": [], "totalCount": 0 }).to_string();
+///             Ok::<_, BoxError>(Response::new(Body::from(body)))
+///         });
+///     // The AuthLayer sets the token, so none is set on the ClientFactory.
+///     let client_factory = ClientFactory::builder(ENGINE_URL)
+///         .transport(TowerTransport::new(service))
+///         .build()?;
+///     let correlations = client_factory.create_correlation_client().get_correlations().await?;
+///     assert_eq!(correlations.total_count, 0);
+///     Ok(())
+/// }
+/// ```
+pub struct TowerTransport<S> {
+    service: Mutex<S>,
+}
+
+impl<S> TowerTransport<S> {
+    /// Creates a new instance of the TowerTransport.
+    ///
+    /// # Arguments
+    /// * `service` - The service stack actually sending the requests. It is cloned for each request.
+    pub fn new(service: S) -> TowerTransport<S> {
+        TowerTransport {
+            service: Mutex::new(service),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> Transport for TowerTransport<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, EngineError> {
+        let request = to_http_request(request)?;
+        let service = self
+            .service
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        let response = service
+            .oneshot(request)
+            .await
+            .map_err(|err| to_engine_error(err.into()))?;
+
+        to_transport_response(response)
+    }
+}
+
+/// A layer setting the `Authorization` header of each request to a token of an AuthProvider.
+///
+/// If a response has the status `401 Unauthorized`, the token is invalidated, so the next
+/// request is sent with a fresh one.
+#[derive(Clone)]
+pub struct AuthLayer {
+    auth_provider: Arc<dyn AuthProvider>,
+}
+
+impl AuthLayer {
+    /// Creates a new instance of the AuthLayer.
+    ///
+    /// # Arguments
+    /// * `auth_provider` - The AuthProvider supplying the authentication token.
+    pub fn new<P>(auth_provider: P) -> AuthLayer
+    where
+        P: AuthProvider + 'static,
+    {
+        AuthLayer {
+            auth_provider: Arc::new(auth_provider),
+        }
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthService<S>;
+
+    fn layer(&self, inner: S) -> AuthService<S> {
+        AuthService {
+            inner,
+            auth_provider: self.auth_provider.clone(),
+        }
+    }
+}
+
+/// The service created by the AuthLayer.
+#[derive(Clone)]
+pub struct AuthService<S> {
+    inner: S,
+    auth_provider: Arc<dyn AuthProvider>,
+}
+
+impl<S, B> Service<Request<Body>> for AuthService<S>
+where
+    S: Service<Request<Body>, Response = Response<B>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    B: Send + 'static,
+{
+    type Response = Response<B>;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Response<B>, BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        // The clone may not be ready, so the ready service is taken and the clone left behind.
+        let clone = self.inner.clone();
+        let mut inner = mem::replace(&mut self.inner, clone);
+        let auth_provider = self.auth_provider.clone();
+
+        Box::pin(async move {
+            let auth_token = auth_provider.get_auth_token().await?;
+            let header_value = HeaderValue::from_str(&auth_token).map_err(|_| {
+                EngineError::Configuration(
+                    "The auth token is not a valid header value.".to_string(),
+                )
+            })?;
+            request.headers_mut().insert(AUTHORIZATION, header_value);
+
+            let response = inner.call(request).await.map_err(Into::into)?;
+            if response.status() == http::StatusCode::UNAUTHORIZED {
+                auth_provider.invalidate().await;
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// A layer converting responses with an unsuccessful status into the matching EngineError.
+///
+/// This lets layers above it, e.g. a retry or circuit breaker layer, see the errors of the
+/// ProcessCube® Engine. When used in the stack of an ApiClient, the errors are returned as they
+/// are, so the ApiClient neither refreshes rejected tokens nor repeats requests by their status.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EngineErrorLayer;
+
+impl EngineErrorLayer {
+    /// Creates a new instance of the EngineErrorLayer.
+    pub fn new() -> EngineErrorLayer {
+        EngineErrorLayer
+    }
+}
+
+impl<S> Layer<S> for EngineErrorLayer {
+    type Service = EngineErrorService<S>;
+
+    fn layer(&self, inner: S) -> EngineErrorService<S> {
+        EngineErrorService { inner }
+    }
+}
+
+/// The service created by the EngineErrorLayer.
+#[derive(Clone, Debug)]
+pub struct EngineErrorService<S> {
+    inner: S,
+}
+
+impl<S> Service<Request<Body>> for EngineErrorService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Response<Body>, BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await.map_err(Into::into)?;
+            match response.status().is_success() {
+                true => Ok(response),
+                false => Err(read_error(to_transport_response(response)?).into()),
+            }
+        })
+    }
+}
+
+/// Returns the EngineError of a service stack, or wraps any other error into one.
+fn to_engine_error(err: BoxError) -> EngineError {
+    let err = match err.downcast::<EngineError>() {
+        Ok(engine_error) => return *engine_error,
+        Err(err) => err,
+    };
+
+    if err.is::<Elapsed>() {
+        EngineError::network(NetworkErrorKind::Timeout, err)
+    } else if err.is::<Overloaded>() {
+        // The load shedding layer rejects requests before they are sent.
+        EngineError::network(NetworkErrorKind::Connect, err)
+    } else {
+        EngineError::Service(err)
+    }
+}
+
+fn to_http_request(request: TransportRequest) -> Result<Request<Body>, EngineError> {
+    let mut builder = Request::builder()
+        .method(request.method.as_str())
+        .uri(&request.url);
+    for (name, value) in &request.headers {
+        builder = builder.header(name.as_str(), value.as_bytes());
+    }
+    if request.body.is_some() {
+        builder = builder.header(http::header::CONTENT_TYPE, "application/json");
+    }
+
+    builder
+        .body(Body::from(request.body.unwrap_or_default()))
+        .map_err(|err| EngineError::Configuration(format!("Invalid request: {}", err)))
+}
+
+fn to_transport_response(response: Response<Body>) -> Result<TransportResponse, EngineError> {
+    let invalid_response =
+        |message: String| EngineError::Configuration(format!("Invalid response: {}", message));
+
+    let status = reqwest::StatusCode::from_u16(response.status().as_u16())
+        .map_err(|err| invalid_response(err.to_string()))?;
+    let mut headers = reqwest::header::HeaderMap::new();
+    for (name, value) in response.headers() {
+        let header_name = reqwest::header::HeaderName::from_bytes(name.as_str().as_bytes())
+            .map_err(|err| invalid_response(err.to_string()))?;
+        let header_value = reqwest::header::HeaderValue::from_bytes(value.as_bytes())
+            .map_err(|err| invalid_response(err.to_string()))?;
+        headers.append(header_name, header_value);
+    }
+
+    Ok(TransportResponse {
+        status,
+        headers,
+        body: response.into_body().to_vec(),
+    })
+}
@@ -1 +1,9 @@
 pub mod api_client;
+pub mod api_client_builder;
+pub mod auth_provider;
+#[cfg(feature = "tracing")]
+mod instrumentation;
+#[cfg(feature = "tower")]
+pub mod middleware;
+pub mod retry_policy;
+pub mod transport;
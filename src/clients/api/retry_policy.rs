@@ -0,0 +1,134 @@
+use std::time::{Duration, SystemTime};
+
+use reqwest::{header::RETRY_AFTER, Method, StatusCode};
+
+use crate::clients::error::{EngineError, NetworkErrorKind, RETRYABLE_STATUSES};
+
+use super::transport::TransportResponse;
+
+/// Describes when and how often the ApiClient repeats a failed request.
+///
+/// Requests are repeated when the ProcessCube® Engine answers with one of the `retryable_statuses`
+/// or the connection fails. Non-idempotent requests, e.g. starting a ProcessInstance, are only
+/// repeated if they never reached the engine, unless `retry_non_idempotent` is set. Only GET and
+/// DELETE requests count as idempotent, since the PUT endpoints of the engine perform actions
+/// like finishing a task or retrying a ProcessInstance.
+///
+/// If a `429 Too Many Requests` or `503 Service Unavailable` response has a `Retry-After` header,
+/// the request is repeated after the delay it asks for instead of the backoff. If that delay
+/// exceeds `max_backoff`, the response is returned without repeating the request.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts per request, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The upper bound for the delay between two attempts.
+    pub max_backoff: Duration,
+    /// The factor by which the delay grows with each attempt.
+    pub backoff_multiplier: f64,
+    /// Whether to randomize each delay between half and all of its nominal length.
+    pub jitter: bool,
+    /// The HTTP status codes that cause a request to be repeated.
+    pub retryable_statuses: Vec<u16>,
+    /// Whether non-idempotent requests may be repeated after they reached the engine.
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            retryable_statuses: RETRYABLE_STATUSES.to_vec(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns a RetryPolicy that sends every request exactly once.
+    pub fn no_retries() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the delay before the given retry, where `1` is the first retry.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = self.backoff_multiplier.powi(exponent).max(1.0);
+        let backoff = Duration::from_secs_f64(
+            (self.initial_backoff.as_secs_f64() * factor).min(self.max_backoff.as_secs_f64()),
+        );
+
+        match self.jitter {
+            true => backoff.mul_f64(0.5 + fastrand::f64() / 2.0),
+            false => backoff,
+        }
+    }
+
+    /// Returns the delay before repeating a request answered with the given response, where `1` is
+    /// the first retry, or None if the engine asks to wait longer than `max_backoff`.
+    pub(crate) fn retry_delay(&self, retry: u32, response: &TransportResponse) -> Option<Duration> {
+        if !matches!(
+            response.status,
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        ) {
+            return Some(self.backoff(retry));
+        }
+
+        match retry_after(response) {
+            Some(delay) if delay > self.max_backoff => None,
+            Some(delay) => Some(delay),
+            None => Some(self.backoff(retry)),
+        }
+    }
+
+    /// Returns whether a request answered with the given status should be repeated.
+    pub(crate) fn should_retry_status(&self, method: &Method, status: StatusCode) -> bool {
+        (is_idempotent(method) || self.retry_non_idempotent)
+            && self.retryable_statuses.contains(&status.as_u16())
+    }
+
+    /// Returns whether a request that failed with the given error should be repeated.
+    pub(crate) fn should_retry_error(&self, method: &Method, error: &EngineError) -> bool {
+        let (is_connect, is_send_failure) = match error {
+            EngineError::Transport(error) | EngineError::Timeout(error) => {
+                (error.is_connect(), error.is_timeout() || error.is_request())
+            }
+            EngineError::Network { kind, .. } => (*kind == NetworkErrorKind::Connect, true),
+            _ => return false,
+        };
+
+        // A failed connection attempt means the request never reached the engine.
+        if is_connect {
+            return true;
+        }
+
+        (is_idempotent(method) || self.retry_non_idempotent) && is_send_failure
+    }
+}
+
+/// Returns whether sending a request with the given method to the engine twice has the same effect as sending it once.
+fn is_idempotent(method: &Method) -> bool {
+    method.is_safe() || *method == Method::DELETE
+}
+
+/// Returns the delay the `Retry-After` header of the response asks for, given in seconds or as HTTP date.
+fn retry_after(response: &TransportResponse) -> Option<Duration> {
+    let value = response.headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
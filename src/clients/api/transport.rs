@@ -0,0 +1,179 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    Client, Method, StatusCode,
+};
+
+use crate::clients::error::EngineError;
+
+/// An HTTP request the ApiClient sends to the ProcessCube® Engine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    /// The JSON encoded body, if the request has one.
+    pub body: Option<Vec<u8>>,
+}
+
+/// An HTTP response as received from the ProcessCube® Engine or a proxy in front of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    /// Creates a response with the given status and JSON body.
+    pub fn json(status: StatusCode, body: &serde_json::Value) -> TransportResponse {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        TransportResponse {
+            status,
+            headers,
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    /// Creates a response with the given status and without a body.
+    pub fn empty(status: StatusCode) -> TransportResponse {
+        TransportResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+}
+
+/// Sends the HTTP requests of an ApiClient.
+///
+/// Implement it to send the requests with another HTTP client, or to answer them without one.
+/// The ApiClient adds the `Authorization` header, retries and error handling on top of the Transport.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Sends the request and returns the response, whatever its status code.
+    ///
+    /// Fails only if no response was received. Return `EngineError::network` in that case, so the
+    /// ApiClient knows whether the request may be repeated.
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, EngineError>;
+}
+
+/// A Transport sending requests with a reqwest Client. This is the default Transport of the ApiClient.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    http_client: Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a new instance of the ReqwestTransport.
+    ///
+    /// # Arguments
+    /// * `http_client` - The reqwest Client to send the requests with.
+    pub fn new(http_client: Client) -> ReqwestTransport {
+        ReqwestTransport { http_client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, EngineError> {
+        let mut request_builder = self
+            .http_client
+            .request(request.method, &request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            request_builder = request_builder
+                .header("Content-Type", "application/json")
+                .body(body);
+        }
+
+        let response = request_builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+
+        Ok(TransportResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+type Handler = dyn Fn(&TransportRequest) -> TransportResponse + Send + Sync;
+
+/// A Transport answering requests with a function instead of sending them, e.g. in unit tests.
+///
+/// Clones share the requests received, so a clone can be passed to the ApiClient and the
+/// requests inspected afterwards.
+///
+/// # Example
+/// ```
+/// use processcube_engine_client::clients::{
+///     api::transport::{InMemoryTransport, TransportResponse},
+///     client_factory::ClientFactory,
+///     error::EngineError,
+/// };
+/// use reqwest::StatusCode;
+/// use serde_json::json;
+/// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), EngineError> {
+///     let transport = InMemoryTransport::new(|_request| {
+///         TransportResponse::json(StatusCode::OK, &json!({ "correlations": [], "totalCount": 0 }))
+///     });
+///     let client_factory = ClientFactory::new("http://localhost:10560", DUMMY_TOKEN)
+///         .with_transport(transport.clone());
+///     let correlations = client_factory.create_correlation_client().get_correlations().await?;
+///     assert_eq!(correlations.total_count, 0);
+///     assert_eq!(transport.requests().len(), 1);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct InMemoryTransport {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<TransportRequest>>>,
+}
+
+impl InMemoryTransport {
+    /// Creates a new instance of the InMemoryTransport.
+    ///
+    /// # Arguments
+    /// * `handler` - The function returning the response to each request.
+    pub fn new<F>(handler: F) -> InMemoryTransport
+    where
+        F: Fn(&TransportRequest) -> TransportResponse + Send + Sync + 'static,
+    {
+        InMemoryTransport {
+            handler: Arc::new(handler),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns the requests received so far, in the order they were received.
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[async_trait]
+impl Transport for InMemoryTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, EngineError> {
+        let response = (self.handler)(&request);
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(request);
+
+        Ok(response)
+    }
+}
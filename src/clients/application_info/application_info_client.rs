@@ -20,7 +20,7 @@ impl ApplicationInfoClient {
     /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use processcube_engine_client::clients::{api::api_client::ApiClient, application_info::application_info_client::ApplicationInfoClient, error::EngineError};
     /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
     /// const ENGINE_URL: &str = "http://localhost:10560";
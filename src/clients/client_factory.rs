@@ -1,8 +1,20 @@
 use super::{
-    api::api_client::ApiClient, application_info::application_info_client::ApplicationInfoClient,
-    correlation::correlation_client::CorrelationClient, event::event_client::EventClient,
+    api::{
+        api_client::ApiClient, auth_provider::AuthProvider, retry_policy::RetryPolicy,
+        transport::Transport,
+    },
+    application_info::application_info_client::ApplicationInfoClient,
+    client_factory_builder::ClientFactoryBuilder,
+    correlation::correlation_client::CorrelationClient,
+    event::event_client::EventClient,
+    external_task::external_task_client::ExternalTaskClient,
+    flow_node_instance::flow_node_instance_client::FlowNodeInstanceClient,
+    manual_task::manual_task_client::ManualTaskClient,
     process_definition::process_definition_client::ProcessDefinitionClient,
+    process_instance::process_instance_client::ProcessInstanceClient,
     process_model::process_model_client::ProcessModelClient,
+    untyped_task::untyped_task_client::UntypedTaskClient,
+    user_task::user_task_client::UserTaskClient,
 };
 
 /// A factory for creating clients for the ProcessCube® Engine.
@@ -34,8 +46,20 @@ impl ClientFactory {
     ///     let process_definition_client = client_factory.create_process_definition_client();
     ///     // Create a new ProcessModelClient
     ///     let process_model_client = client_factory.create_process_model_client();
+    ///     // Create a new FlowNodeInstanceClient
+    ///     let flow_node_instance_client = client_factory.create_flow_node_instance_client();
+    ///     // Create a new ProcessInstanceClient
+    ///     let process_instance_client = client_factory.create_process_instance_client();
     ///     // Create a new EventClient
     ///     let event_client = client_factory.create_event_client();
+    ///     // Create a new ExternalTaskClient
+    ///     let external_task_client = client_factory.create_external_task_client();
+    ///     // Create a new UserTaskClient
+    ///     let user_task_client = client_factory.create_user_task_client();
+    ///     // Create a new ManualTaskClient
+    ///     let manual_task_client = client_factory.create_manual_task_client();
+    ///     // Create a new UntypedTaskClient
+    ///     let untyped_task_client = client_factory.create_untyped_task_client();
     ///     Ok(())
     /// }
     /// ```
@@ -44,6 +68,77 @@ impl ClientFactory {
         ClientFactory { api_client }
     }
 
+    /// Creates a new instance of the ClientFactory whose clients ask the given AuthProvider for a token before each request.
+    ///
+    /// # Arguments
+    /// * `engine_url` - The URL of the ProcessCube® Engine.
+    /// * `auth_provider` - The AuthProvider supplying the authentication token.
+    pub fn with_auth_provider<P>(engine_url: &str, auth_provider: P) -> ClientFactory
+    where
+        P: AuthProvider + 'static,
+    {
+        let api_client = ApiClient::with_auth_provider(engine_url, auth_provider);
+        ClientFactory { api_client }
+    }
+
+    /// Returns a ClientFactoryBuilder for configuring timeouts, TLS, proxies and default headers.
+    ///
+    /// # Arguments
+    /// * `engine_url` - The URL of the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use processcube_engine_client::clients::{client_factory::ClientFactory, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    ///
+    /// fn main() -> Result<(), EngineError> {
+    ///     let client_factory = ClientFactory::builder(ENGINE_URL)
+    ///         .auth_token(DUMMY_TOKEN)
+    ///         .timeout(Duration::from_secs(30))
+    ///         .default_header("X-Tenant-Id", "my_tenant")
+    ///         .build()?;
+    ///     let application_info_client = client_factory.create_application_info_client();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder(engine_url: &str) -> ClientFactoryBuilder {
+        ClientFactoryBuilder::new(engine_url)
+    }
+
+    /// Returns the ClientFactory with the given RetryPolicy applied to all clients it creates.
+    ///
+    /// # Arguments
+    /// * `retry_policy` - The RetryPolicy deciding when a failed request is repeated.
+    ///
+    /// # Example
+    /// ```
+    /// use processcube_engine_client::clients::{api::retry_policy::RetryPolicy, client_factory::ClientFactory};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    ///
+    /// let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_retry_policy(RetryPolicy::default());
+    /// ```
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> ClientFactory {
+        ClientFactory {
+            api_client: self.api_client.with_retry_policy(retry_policy),
+        }
+    }
+
+    /// Returns the ClientFactory with all requests of its clients sent by the given Transport.
+    ///
+    /// # Arguments
+    /// * `transport` - The Transport sending the requests.
+    pub fn with_transport<T>(self, transport: T) -> ClientFactory
+    where
+        T: Transport + 'static,
+    {
+        ClientFactory {
+            api_client: self.api_client.with_transport(transport),
+        }
+    }
+
     /// Creates a new instance of the ApplicationInfoClient.
     pub fn create_application_info_client(&self) -> ApplicationInfoClient {
         ApplicationInfoClient::new(self.api_client.clone())
@@ -64,8 +159,38 @@ impl ClientFactory {
         ProcessModelClient::new(self.api_client.clone())
     }
 
+    /// Creates a new instance of the FlowNodeInstanceClient.
+    pub fn create_flow_node_instance_client(&self) -> FlowNodeInstanceClient {
+        FlowNodeInstanceClient::new(self.api_client.clone())
+    }
+
+    /// Creates a new instance of the ProcessInstanceClient.
+    pub fn create_process_instance_client(&self) -> ProcessInstanceClient {
+        ProcessInstanceClient::new(self.api_client.clone())
+    }
+
     /// Creates a new instance of the EventClient.
     pub fn create_event_client(&self) -> EventClient {
         EventClient::new(self.api_client.clone())
     }
+
+    /// Creates a new instance of the ExternalTaskClient.
+    pub fn create_external_task_client(&self) -> ExternalTaskClient {
+        ExternalTaskClient::new(self.api_client.clone())
+    }
+
+    /// Creates a new instance of the UserTaskClient.
+    pub fn create_user_task_client(&self) -> UserTaskClient {
+        UserTaskClient::new(self.api_client.clone())
+    }
+
+    /// Creates a new instance of the ManualTaskClient.
+    pub fn create_manual_task_client(&self) -> ManualTaskClient {
+        ManualTaskClient::new(self.api_client.clone())
+    }
+
+    /// Creates a new instance of the UntypedTaskClient.
+    pub fn create_untyped_task_client(&self) -> UntypedTaskClient {
+        UntypedTaskClient::new(self.api_client.clone())
+    }
 }
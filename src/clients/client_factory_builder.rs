@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use super::{
+    api::{
+        api_client::ApiClient,
+        api_client_builder::ApiClientBuilder,
+        auth_provider::{AuthProvider, ClientCredentialsProvider},
+        retry_policy::RetryPolicy,
+        transport::Transport,
+    },
+    client_factory::ClientFactory,
+    error::EngineError,
+};
+
+/// A builder for a ClientFactory whose clients need more than the default HTTP settings.
+///
+/// Each setting is passed on to the underlying ApiClientBuilder.
+pub struct ClientFactoryBuilder {
+    api_client_builder: ApiClientBuilder,
+}
+
+impl ClientFactoryBuilder {
+    /// Creates a new instance of the ClientFactoryBuilder.
+    ///
+    /// # Arguments
+    /// * `engine_url` - The URL of the ProcessCube® Engine.
+    pub fn new(engine_url: &str) -> ClientFactoryBuilder {
+        ClientFactoryBuilder {
+            api_client_builder: ApiClient::builder(engine_url),
+        }
+    }
+
+    /// Sets the authentication token to send with each request.
+    pub fn auth_token(self, auth_token: &str) -> ClientFactoryBuilder {
+        self.map(|builder| builder.auth_token(auth_token))
+    }
+
+    /// Sets the AuthProvider to ask for a token before each request.
+    pub fn auth_provider<P>(self, auth_provider: P) -> ClientFactoryBuilder
+    where
+        P: AuthProvider + 'static,
+    {
+        self.map(|builder| builder.auth_provider(auth_provider))
+    }
+
+    /// Sets the ClientCredentialsProvider to request tokens with, using the HTTP settings of this builder.
+    pub fn client_credentials(
+        self,
+        auth_provider: ClientCredentialsProvider,
+    ) -> ClientFactoryBuilder {
+        self.map(|builder| builder.client_credentials(auth_provider))
+    }
+
+    /// Sets the RetryPolicy deciding when a failed request is repeated.
+    pub fn retry_policy(self, retry_policy: RetryPolicy) -> ClientFactoryBuilder {
+        self.map(|builder| builder.retry_policy(retry_policy))
+    }
+
+    /// Sets the Transport to send all requests with instead of the default ReqwestTransport.
+    pub fn transport<T>(self, transport: T) -> ClientFactoryBuilder
+    where
+        T: Transport + 'static,
+    {
+        self.map(|builder| builder.transport(transport))
+    }
+
+    /// Sets the maximum time to wait for a connection to the ProcessCube® Engine.
+    pub fn connect_timeout(self, connect_timeout: Duration) -> ClientFactoryBuilder {
+        self.map(|builder| builder.connect_timeout(connect_timeout))
+    }
+
+    /// Sets the maximum time a request may take, from connecting until the response body is read.
+    pub fn timeout(self, timeout: Duration) -> ClientFactoryBuilder {
+        self.map(|builder| builder.timeout(timeout))
+    }
+
+    /// Adds a PEM encoded certificate to trust in addition to the system's root certificates.
+    pub fn add_root_certificate_pem(self, certificate_pem: &[u8]) -> ClientFactoryBuilder {
+        self.map(|builder| builder.add_root_certificate_pem(certificate_pem))
+    }
+
+    /// Sets the client certificate and PKCS #8 private key to authenticate with via mutual TLS.
+    pub fn identity_pem(
+        self,
+        certificate_pem: &[u8],
+        private_key_pem: &[u8],
+    ) -> ClientFactoryBuilder {
+        self.map(|builder| builder.identity_pem(certificate_pem, private_key_pem))
+    }
+
+    /// Sets the proxy to send all requests through.
+    pub fn proxy(self, proxy_url: &str) -> ClientFactoryBuilder {
+        self.map(|builder| builder.proxy(proxy_url))
+    }
+
+    /// Disables all proxies, including those from the environment.
+    pub fn no_proxy(self) -> ClientFactoryBuilder {
+        self.map(|builder| builder.no_proxy())
+    }
+
+    /// Sets the `User-Agent` header to send with each request.
+    pub fn user_agent(self, user_agent: &str) -> ClientFactoryBuilder {
+        self.map(|builder| builder.user_agent(user_agent))
+    }
+
+    /// Adds a header to send with each request, e.g. a tenant ID.
+    pub fn default_header(self, name: &str, value: &str) -> ClientFactoryBuilder {
+        self.map(|builder| builder.default_header(name, value))
+    }
+
+    /// Creates the ClientFactory.
+    ///
+    /// Fails if no authentication token or AuthProvider is set, or if any setting is invalid.
+    /// With a custom Transport, the token may be omitted, e.g. if its service stack sets it with an AuthLayer.
+    pub fn build(self) -> Result<ClientFactory, EngineError> {
+        let api_client = self.api_client_builder.build()?;
+        Ok(ClientFactory { api_client })
+    }
+
+    fn map(self, f: impl FnOnce(ApiClientBuilder) -> ApiClientBuilder) -> ClientFactoryBuilder {
+        ClientFactoryBuilder {
+            api_client_builder: f(self.api_client_builder),
+        }
+    }
+}
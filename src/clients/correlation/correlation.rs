@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::clients::process_instance::process_instance::ProcessInstance;
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CorrelationList {
@@ -13,5 +15,5 @@ pub struct Correlation {
     #[serde(rename = "correlationId")]
     pub id: String,
     pub metadata: serde_json::Value,
-    // TODO: pub process_instances: Vec<ProcessInstance>,
+    pub process_instances: Option<Vec<ProcessInstance>>,
 }
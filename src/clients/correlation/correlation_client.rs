@@ -18,7 +18,7 @@ impl CorrelationClient {
     /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use processcube_engine_client::clients::{api::api_client::ApiClient, correlation::correlation_client::CorrelationClient, error::EngineError};
     /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
     /// const ENGINE_URL: &str = "http://localhost:10560";
@@ -1,34 +1,185 @@
-use std::fmt;
+use std::{fmt, time::Duration};
 
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 
-/// Represents an error returned by the ProcessCube® Engine.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+use super::process_instance::process_instance::ProcessInstanceState;
+
+/// The status codes for which repeating a request may succeed.
+pub(crate) const RETRYABLE_STATUSES: [u16; 4] = [429, 502, 503, 504];
+
+/// Represents an error that occurred while communicating with the ProcessCube® Engine.
+#[derive(Debug)]
+pub enum EngineError {
+    /// The request could not be sent or its response could not be received.
+    Transport(reqwest::Error),
+    /// The request did not complete in time.
+    Timeout(reqwest::Error),
+    /// A Transport other than the ReqwestTransport could not send the request or receive its response.
+    Network {
+        kind: NetworkErrorKind,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The response did not match the expected format, or a payload could not be serialized to JSON.
+    Deserialization(serde_json::Error),
+    /// The client is configured incorrectly, e.g. with an invalid certificate.
+    Configuration(String),
+    /// A layer of the tower service stack sending the requests failed, e.g. a rate limiting layer.
+    Service(Box<dyn std::error::Error + Send + Sync>),
+    /// The ProcessCube® Engine or the authority rejected the authentication token.
+    Unauthorized(EngineErrorDetails),
+    /// The authenticated user lacks the claims required for the request.
+    Forbidden(EngineErrorDetails),
+    /// The requested resource does not exist.
+    NotFound(EngineErrorDetails),
+    /// The request conflicts with the current state of the resource.
+    Conflict(EngineErrorDetails),
+    /// Any other error reported by the ProcessCube® Engine.
+    Engine(EngineErrorDetails),
+    /// A ProcessInstance that was waited for ended with an error or was terminated.
+    ProcessInstanceFailed {
+        process_instance_id: String,
+        state: ProcessInstanceState,
+        error: Option<serde_json::Value>,
+    },
+    /// A ProcessInstance that was waited for did not finish in time.
+    WaitTimeout {
+        process_instance_id: String,
+        timeout: Duration,
+    },
+    /// A ProcessInstance that was waited for finished at another End Event than the expected one.
+    EndEventNotReached {
+        process_instance_id: String,
+        end_event_id: String,
+    },
+}
+
+/// Describes how a request sent by a Transport failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkErrorKind {
+    /// The request never reached the ProcessCube® Engine, e.g. because no connection could be established.
+    Connect,
+    /// The request did not complete in time.
+    Timeout,
+    /// The request failed after it may have reached the ProcessCube® Engine, e.g. because the connection was reset.
+    Other,
+}
+
+/// The details of an error as reported by the ProcessCube® Engine.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct EngineError {
-    #[serde(rename = "errorClassName")]
-    pub error_type: String,
+pub struct EngineErrorDetails {
+    pub error_class_name: String,
     pub message: String,
-    #[serde(rename = "callStack")]
-    pub stack: String,
+    #[serde(default)]
+    pub call_stack: Option<String>,
     pub code: u16,
-    pub fatal: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_information: Option<serde_json::Value>,
+    /// The HTTP response the error was read from.
+    #[serde(skip)]
+    pub raw_response: Option<Box<RawResponse>>,
+}
+
+/// An HTTP response as received from the ProcessCube® Engine or a proxy in front of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl EngineErrorDetails {
+    pub fn new(error_class_name: &str, message: &str, code: u16) -> EngineErrorDetails {
+        EngineErrorDetails {
+            error_class_name: error_class_name.to_string(),
+            message: message.to_string(),
+            call_stack: None,
+            code,
+            additional_information: None,
+            raw_response: None,
+        }
+    }
+
+    /// Returns the HTTP status of the response the details were read from, or their `code`.
+    fn status(&self) -> u16 {
+        self.raw_response
+            .as_ref()
+            .map_or(self.code, |raw_response| raw_response.status)
+    }
 }
 
 impl EngineError {
-    pub fn new(
-        error_type: String,
-        message: String,
-        stack: String,
-        code: u16,
-        fatal: bool,
+    /// Creates the EngineError matching the given HTTP status code.
+    pub fn from_status(status: u16, details: EngineErrorDetails) -> EngineError {
+        match status {
+            401 => EngineError::Unauthorized(details),
+            403 => EngineError::Forbidden(details),
+            404 => EngineError::NotFound(details),
+            409 => EngineError::Conflict(details),
+            _ => EngineError::Engine(details),
+        }
+    }
+
+    /// Creates the error a Transport returns if it could not send the request or receive its response.
+    ///
+    /// # Arguments
+    /// * `kind` - How the request failed. It decides whether the request is repeated.
+    /// * `source` - The error of the underlying HTTP client.
+    pub fn network(
+        kind: NetworkErrorKind,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
     ) -> EngineError {
-        EngineError {
-            error_type,
-            message,
-            stack,
-            code,
-            fatal,
+        EngineError::Network {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    /// Creates a NotFound error with the given message.
+    pub(crate) fn not_found(message: String) -> EngineError {
+        EngineError::NotFound(EngineErrorDetails::new("NotFoundError", &message, 404))
+    }
+
+    /// Returns the HTTP status code of the error, if there was a response.
+    ///
+    /// This is the status of the received response, which may differ from the `code` of an error
+    /// document passed on by a proxy. Errors created without a response fall back to their `code`.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            EngineError::Transport(err) | EngineError::Timeout(err) => {
+                err.status().map(|status| status.as_u16())
+            }
+            EngineError::Network { .. }
+            | EngineError::Deserialization(_)
+            | EngineError::Configuration(_)
+            | EngineError::Service(_)
+            | EngineError::ProcessInstanceFailed { .. }
+            | EngineError::WaitTimeout { .. }
+            | EngineError::EndEventNotReached { .. } => None,
+            _ => self.details().map(EngineErrorDetails::status),
+        }
+    }
+
+    /// Returns the details reported by the ProcessCube® Engine, if any.
+    pub fn details(&self) -> Option<&EngineErrorDetails> {
+        match self {
+            EngineError::Unauthorized(details)
+            | EngineError::Forbidden(details)
+            | EngineError::NotFound(details)
+            | EngineError::Conflict(details)
+            | EngineError::Engine(details) => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Returns whether repeating the request may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            EngineError::Transport(err) => err.is_connect() || err.is_request(),
+            EngineError::Timeout(_) | EngineError::Network { .. } => true,
+            EngineError::Engine(details) => RETRYABLE_STATUSES.contains(&details.status()),
+            _ => false,
         }
     }
 }
@@ -36,34 +187,93 @@ impl EngineError {
 impl From<reqwest::Error> for EngineError {
     /// Converts a reqwest::Error into an EngineError.
     fn from(err: reqwest::Error) -> Self {
-        match err.status() {
-            Some(status) => EngineError::new(
-                "RequestError".to_string(),
-                "Error processing request".to_string(),
-                err.to_string(),
-                status.as_u16(),
-                false,
-            ),
-            None => EngineError::new(
-                "InternalError".to_string(),
-                "Error processing request".to_string(),
-                err.to_string(),
-                500,
-                false,
-            ),
+        match err.is_timeout() {
+            true => EngineError::Timeout(err),
+            false => EngineError::Transport(err),
         }
     }
 }
 
+impl From<serde_json::Error> for EngineError {
+    /// Converts a serde_json::Error into an EngineError.
+    fn from(err: serde_json::Error) -> Self {
+        EngineError::Deserialization(err)
+    }
+}
+
 impl fmt::Display for EngineError {
     /// Formats an EngineError.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "EngineError: error_type: {}, message: {}, stack: {}, code: {}, fatal: {}",
-            self.error_type, self.message, self.stack, self.code, self.fatal
-        )
+        match self {
+            EngineError::Transport(err) => write!(f, "Error sending request: {}", err),
+            EngineError::Timeout(err) => write!(f, "Request timed out: {}", err),
+            EngineError::Network {
+                kind: NetworkErrorKind::Timeout,
+                source,
+            } => write!(f, "Request timed out: {}", source),
+            EngineError::Network { source, .. } => write!(f, "Error sending request: {}", source),
+            EngineError::Deserialization(err) => write!(f, "Error converting JSON: {}", err),
+            EngineError::Configuration(message) => write!(f, "Invalid configuration: {}", message),
+            EngineError::Service(err) => write!(f, "Error in service stack: {}", err),
+            EngineError::ProcessInstanceFailed {
+                process_instance_id,
+                state,
+                error: Some(error),
+            } => write!(
+                f,
+                "Process instance `{}` ended with state `{}`: {}",
+                process_instance_id,
+                state.as_str(),
+                error
+            ),
+            EngineError::ProcessInstanceFailed {
+                process_instance_id,
+                state,
+                error: None,
+            } => write!(
+                f,
+                "Process instance `{}` ended with state `{}`",
+                process_instance_id,
+                state.as_str()
+            ),
+            EngineError::WaitTimeout {
+                process_instance_id,
+                timeout,
+            } => write!(
+                f,
+                "Process instance `{}` did not finish within {:?}",
+                process_instance_id, timeout
+            ),
+            EngineError::EndEventNotReached {
+                process_instance_id,
+                end_event_id,
+            } => write!(
+                f,
+                "Process instance `{}` finished without reaching end event `{}`",
+                process_instance_id, end_event_id
+            ),
+            EngineError::Unauthorized(details)
+            | EngineError::Forbidden(details)
+            | EngineError::NotFound(details)
+            | EngineError::Conflict(details)
+            | EngineError::Engine(details) => write!(
+                f,
+                "{} ({}): {}",
+                details.error_class_name, details.code, details.message
+            ),
+        }
     }
 }
 
-impl std::error::Error for EngineError {}
+impl std::error::Error for EngineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EngineError::Transport(err) | EngineError::Timeout(err) => Some(err),
+            EngineError::Deserialization(err) => Some(err),
+            EngineError::Network { source, .. } | EngineError::Service(source) => {
+                Some(source.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::clients::{api::api_client::ApiClient, error::EngineError};
 
 const MESSAGES_ENDPOINT: &str = "/messages";
@@ -70,7 +72,7 @@ impl EventClient {
         event_type: &EventType,
         event_name: &str,
         process_instance_id: Option<&str>,
-        payload: Option<&str>,
+        payload: Option<serde_json::Value>,
     ) -> Result<(), EngineError> {
         let url_base = match event_type {
             EventType::Message => &self.messages_url,
@@ -80,7 +82,7 @@ impl EventClient {
         let url = match process_instance_id {
             Some(process_instance_id) => format!(
                 "{}/{}/trigger?processInstanceId={}",
-                self.messages_url, event_name, process_instance_id
+                url_base, event_name, process_instance_id
             ),
             None => format!("{}/{}/trigger", url_base, event_name),
         };
@@ -98,13 +100,13 @@ impl EventClient {
     /// # Arguments
     /// * `message_name` - The name of the message to trigger.
     /// * `process_instance_id` - The ID of the ProcessInstance to send the message to.
-    /// If this is set to `None`, the message will be sent to all ProcessInstances.
-    /// * `payload` - An optional payload to send with the message.
+    ///   If this is set to `None`, the message will be sent to all ProcessInstances.
+    /// * `payload` - An optional JSON payload to send with the message.
     pub async fn trigger_message(
         &self,
         message_name: &str,
         process_instance_id: Option<&str>,
-        payload: Option<&str>,
+        payload: Option<serde_json::Value>,
     ) -> Result<(), EngineError> {
         self.trigger_event(
             &EventType::Message,
@@ -115,18 +117,40 @@ impl EventClient {
         .await
     }
 
+    /// Triggers a message with the given name and a payload serialized from the given value.
+    ///
+    /// # Arguments
+    /// * `message_name` - The name of the message to trigger.
+    /// * `process_instance_id` - The ID of the ProcessInstance to send the message to.
+    ///   If this is set to `None`, the message will be sent to all ProcessInstances.
+    /// * `payload` - The payload to send with the message.
+    pub async fn trigger_message_with_payload<T>(
+        &self,
+        message_name: &str,
+        process_instance_id: Option<&str>,
+        payload: &T,
+    ) -> Result<(), EngineError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let payload_json = serde_json::to_value(payload)?;
+
+        self.trigger_message(message_name, process_instance_id, Some(payload_json))
+            .await
+    }
+
     /// Triggers a signal with the given name.
     ///
     /// # Arguments
     /// * `signal_name` - The name of the signal to trigger.
     /// * `process_instance_id` - The ID of the ProcessInstance to send the signal to.
-    /// If this is set to `None`, the signal will be sent to all ProcessInstances.
-    /// * `payload` - An optional payload to send with the signal.
+    ///   If this is set to `None`, the signal will be sent to all ProcessInstances.
+    /// * `payload` - An optional JSON payload to send with the signal.
     pub async fn trigger_signal(
         &self,
         signal_name: &str,
         process_instance_id: Option<&str>,
-        payload: Option<&str>,
+        payload: Option<serde_json::Value>,
     ) -> Result<(), EngineError> {
         self.trigger_event(
             &EventType::Signal,
@@ -136,4 +160,26 @@ impl EventClient {
         )
         .await
     }
+
+    /// Triggers a signal with the given name and a payload serialized from the given value.
+    ///
+    /// # Arguments
+    /// * `signal_name` - The name of the signal to trigger.
+    /// * `process_instance_id` - The ID of the ProcessInstance to send the signal to.
+    ///   If this is set to `None`, the signal will be sent to all ProcessInstances.
+    /// * `payload` - The payload to send with the signal.
+    pub async fn trigger_signal_with_payload<T>(
+        &self,
+        signal_name: &str,
+        process_instance_id: Option<&str>,
+        payload: &T,
+    ) -> Result<(), EngineError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let payload_json = serde_json::to_value(payload)?;
+
+        self.trigger_signal(signal_name, process_instance_id, Some(payload_json))
+            .await
+    }
 }
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes an ExternalTask that was fetched and locked by a worker.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalTask {
+    pub id: String,
+    pub worker_id: String,
+    pub topic: String,
+    pub flow_node_instance_id: String,
+    pub correlation_id: String,
+    pub process_definition_id: String,
+    pub process_instance_id: String,
+    pub payload: serde_json::Value,
+    pub state: ExternalTaskState,
+
+    pub owner_id: Option<String>,
+    pub lock_expiration_time: Option<String>,
+    pub created_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+}
+
+/// The state of an ExternalTask.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ExternalTaskState {
+    Pending,
+    Finished,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchAndLockRequest {
+    pub worker_id: String,
+    pub topic_name: String,
+    pub max_tasks: u32,
+    /// The time in milliseconds the engine waits for new tasks before answering with an empty list.
+    pub long_polling_timeout: u64,
+    /// The time in milliseconds the fetched tasks stay locked for the worker.
+    pub lock_duration: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendLockRequest {
+    pub worker_id: String,
+    /// The time in milliseconds to extend the lock by.
+    pub additional_duration: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FinishExternalTaskRequest {
+    pub worker_id: String,
+    pub result: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HandleExternalTaskErrorRequest {
+    pub worker_id: String,
+    pub error: ExternalTaskErrorPayload,
+}
+
+/// Describes an error reported for an ExternalTask.
+///
+/// Errors carrying an `error_code` are treated as BPMN errors and can be caught by error boundary events.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalTaskErrorPayload {
+    pub error_message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_details: Option<serde_json::Value>,
+}
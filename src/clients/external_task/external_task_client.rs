@@ -0,0 +1,176 @@
+use crate::clients::{api::api_client::ApiClient, error::EngineError};
+
+use super::external_task::{
+    ExtendLockRequest, ExternalTask, ExternalTaskErrorPayload, FetchAndLockRequest,
+    FinishExternalTaskRequest, HandleExternalTaskErrorRequest,
+};
+
+const EXTERNAL_TASKS_ENDPOINT: &str = "/external_tasks";
+
+/// A client for communicating with the ProcessCube® Engine's ExternalTask API.
+#[derive(Clone)]
+pub struct ExternalTaskClient {
+    api_client: ApiClient,
+    pub external_tasks_url: String,
+}
+
+impl ExternalTaskClient {
+    /// Creates a new instance of the ExternalTaskClient.
+    ///
+    /// # Arguments
+    /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::clients::{api::api_client::ApiClient, external_task::{external_task::FetchAndLockRequest, external_task_client::ExternalTaskClient}, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let external_task_client = ExternalTaskClient::new(api_client);
+    ///     // Fetch and lock up to 10 ExternalTasks of the topic "sample_topic"
+    ///     let external_tasks = external_task_client
+    ///         .fetch_and_lock(FetchAndLockRequest {
+    ///             worker_id: "sample_worker".to_string(),
+    ///             topic_name: "sample_topic".to_string(),
+    ///             max_tasks: 10,
+    ///             long_polling_timeout: 1000,
+    ///             lock_duration: 30000,
+    ///         })
+    ///         .await?;
+    ///     println!("ExternalTasks: {:#?}", external_tasks);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(api_client: ApiClient) -> ExternalTaskClient {
+        let external_tasks_url = format!(
+            "{}{}{}",
+            api_client.get_engine_url(),
+            api_client.get_engine_api_endpoint(),
+            EXTERNAL_TASKS_ENDPOINT
+        );
+        ExternalTaskClient {
+            api_client,
+            external_tasks_url,
+        }
+    }
+
+    /// Fetches and locks ExternalTasks of the given topic.
+    ///
+    /// The engine holds the request open for up to `long_polling_timeout` milliseconds
+    /// if no ExternalTasks are available.
+    pub async fn fetch_and_lock(
+        &self,
+        request: FetchAndLockRequest,
+    ) -> Result<Vec<ExternalTask>, EngineError> {
+        let url = format!("{}/fetch_and_lock", self.external_tasks_url);
+        let request_json = serde_json::to_value(request).expect("Failed to serialize request");
+
+        self.api_client
+            .post::<Vec<ExternalTask>>(&url, Some(&request_json))
+            .await
+    }
+
+    /// Extends the lock of the ExternalTask with the given ID.
+    ///
+    /// # Arguments
+    /// * `external_task_id` - The ID of the ExternalTask.
+    /// * `worker_id` - The ID of the worker holding the lock.
+    /// * `additional_duration` - The time in milliseconds to extend the lock by.
+    pub async fn extend_lock(
+        &self,
+        external_task_id: &str,
+        worker_id: &str,
+        additional_duration: u64,
+    ) -> Result<(), EngineError> {
+        let url = format!(
+            "{}/{}/extend_lock",
+            self.external_tasks_url, external_task_id
+        );
+        let request = ExtendLockRequest {
+            worker_id: worker_id.to_string(),
+            additional_duration,
+        };
+        let request_json = serde_json::to_value(request).expect("Failed to serialize request");
+
+        self.api_client.put::<()>(&url, Some(&request_json)).await
+    }
+
+    /// Finishes the ExternalTask with the given ID.
+    pub async fn finish_external_task(
+        &self,
+        external_task_id: &str,
+        worker_id: &str,
+        result: serde_json::Value,
+    ) -> Result<(), EngineError> {
+        let url = format!("{}/{}/finish", self.external_tasks_url, external_task_id);
+        let request = FinishExternalTaskRequest {
+            worker_id: worker_id.to_string(),
+            result,
+        };
+        let request_json = serde_json::to_value(request).expect("Failed to serialize request");
+
+        self.api_client.put::<()>(&url, Some(&request_json)).await
+    }
+
+    /// Reports a BPMN error for the ExternalTask with the given ID.
+    ///
+    /// The error can be caught by an error boundary event with a matching error code.
+    pub async fn handle_bpmn_error(
+        &self,
+        external_task_id: &str,
+        worker_id: &str,
+        error_code: &str,
+        error_message: &str,
+    ) -> Result<(), EngineError> {
+        self.handle_error(
+            external_task_id,
+            worker_id,
+            ExternalTaskErrorPayload {
+                error_message: error_message.to_string(),
+                error_code: Some(error_code.to_string()),
+                error_details: None,
+            },
+        )
+        .await
+    }
+
+    /// Reports a technical error for the ExternalTask with the given ID.
+    pub async fn handle_service_error(
+        &self,
+        external_task_id: &str,
+        worker_id: &str,
+        error_message: &str,
+        error_details: Option<serde_json::Value>,
+    ) -> Result<(), EngineError> {
+        self.handle_error(
+            external_task_id,
+            worker_id,
+            ExternalTaskErrorPayload {
+                error_message: error_message.to_string(),
+                error_code: None,
+                error_details,
+            },
+        )
+        .await
+    }
+
+    async fn handle_error(
+        &self,
+        external_task_id: &str,
+        worker_id: &str,
+        error: ExternalTaskErrorPayload,
+    ) -> Result<(), EngineError> {
+        let url = format!("{}/{}/error", self.external_tasks_url, external_task_id);
+        let request = HandleExternalTaskErrorRequest {
+            worker_id: worker_id.to_string(),
+            error,
+        };
+        let request_json = serde_json::to_value(request).expect("Failed to serialize request");
+
+        self.api_client.put::<()>(&url, Some(&request_json)).await
+    }
+}
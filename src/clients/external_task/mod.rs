@@ -0,0 +1,2 @@
+pub mod external_task;
+pub mod external_task_client;
@@ -5,37 +5,37 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct FlowNodeInstanceList {
     /// The FlowNodeInstances.
-    flow_node_instances: Vec<FlowNodeInstance>,
+    pub flow_node_instances: Vec<FlowNodeInstance>,
     /// The total number of FlowNodeInstances.
-    total_count: u32,
+    pub total_count: u32,
 }
 
 /// Describes a FlowNodeInstance.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowNodeInstance {
-    correlation_id: String,
-    flow_node_id: String,
-    flow_node_instance_id: String,
-    flow_node_type: BpmnType,
-    owner_id: String,
-    process_definition_id: String,
-    process_instance_id: String,
-    process_model_id: String,
-    start_token: std::collections::HashMap<String, serde_json::Value>,
-    state: FlowNodeInstanceState,
-    tokens: Vec<ProcessToken>,
-
-    end_token: Option<std::collections::HashMap<String, serde_json::Value>>,
-    error: Option<serde_json::Value>,
-    event_type: Option<EventType>,
-    finished_at: Option<String>,
-    flow_node_lane: Option<String>,
-    flow_node_name: Option<String>,
-    parent_process_instance_id: Option<String>,
-    previous_flow_node_instance_id: Option<String>,
-    started_at: Option<String>,
-    triggered_by_flow_node_instance: Option<Box<FlowNodeInstance>>,
+    pub correlation_id: String,
+    pub flow_node_id: String,
+    pub flow_node_instance_id: String,
+    pub flow_node_type: BpmnType,
+    pub owner_id: String,
+    pub process_definition_id: String,
+    pub process_instance_id: String,
+    pub process_model_id: String,
+    pub start_token: std::collections::HashMap<String, serde_json::Value>,
+    pub state: FlowNodeInstanceState,
+    pub tokens: Vec<ProcessToken>,
+
+    pub end_token: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+    pub event_type: Option<EventType>,
+    pub finished_at: Option<String>,
+    pub flow_node_lane: Option<String>,
+    pub flow_node_name: Option<String>,
+    pub parent_process_instance_id: Option<String>,
+    pub previous_flow_node_instance_id: Option<String>,
+    pub started_at: Option<String>,
+    pub triggered_by_flow_node_instance: Option<Box<FlowNodeInstance>>,
 }
 
 /// The state of a FlowNodeInstance.
@@ -66,12 +66,12 @@ impl FlowNodeInstanceState {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessToken {
-    created_at: String,
-    flow_node_instance_id: String,
-    payload: serde_json::Value,
+    pub created_at: String,
+    pub flow_node_instance_id: String,
+    pub payload: serde_json::Value,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowNodeInstancesQuery {
     pub correlation_id: Option<String>,
@@ -141,27 +141,49 @@ impl FlowNodeInstancesQuery {
 }
 
 /// The type of a BPMN element.
-#[derive(Serialize, Deserialize, Debug)]
+///
+/// Deserializes both the bare names, e.g. `EndEvent`, and the prefixed XML tags, e.g. `bpmn:EndEvent`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum BpmnType {
+    #[serde(alias = "bpmn:BoundaryEvent")]
     BoundaryEvent,
+    #[serde(alias = "bpmn:BusinessRuleTask")]
     BusinessRuleTask,
+    #[serde(alias = "bpmn:CallActivity")]
     CallActivity,
+    #[serde(alias = "bpmn:ComplexGateway")]
     ComplexGateway,
+    #[serde(alias = "bpmn:EmptyActivity")]
     EmptyActivity,
+    #[serde(alias = "bpmn:EndEvent")]
     EndEvent,
+    #[serde(alias = "bpmn:EventBasedGateway")]
     EventBasedGateway,
+    #[serde(alias = "bpmn:ExclusiveGateway")]
     ExclusiveGateway,
+    #[serde(alias = "bpmn:InclusiveGateway")]
     InclusiveGateway,
+    #[serde(alias = "bpmn:IntermediateCatchEvent")]
     IntermediateCatchEvent,
+    #[serde(alias = "bpmn:IntermediateThrowEvent")]
     IntermediateThrowEvent,
+    #[serde(alias = "bpmn:ManualTask")]
     ManualTask,
+    #[serde(alias = "bpmn:ParallelGateway")]
     ParallelGateway,
+    #[serde(alias = "bpmn:ReceiveTask")]
     ReceiveTask,
+    #[serde(alias = "bpmn:ScriptTask")]
     ScriptTask,
+    #[serde(alias = "bpmn:SendTask")]
     SendTask,
+    #[serde(alias = "bpmn:ServiceTask")]
     ServiceTask,
+    #[serde(alias = "bpmn:StartEvent")]
     StartEvent,
+    #[serde(alias = "bpmn:SubProcess")]
     SubProcess,
+    #[serde(alias = "bpmn:UserTask")]
     UserTask,
 }
 
@@ -1,6 +1,8 @@
-use crate::clients::{api::api_client::ApiClient, error::EngineError};
+use futures::Stream;
 
-use super::flow_node_instance::{FlowNodeInstanceList, FlowNodeInstancesQuery};
+use crate::clients::{api::api_client::ApiClient, error::EngineError, pagination::paginate};
+
+use super::flow_node_instance::{FlowNodeInstance, FlowNodeInstanceList, FlowNodeInstancesQuery};
 
 const FLOW_NODE_INSTANCES_ENDPOINT: &str = "/flow_node_instances";
 
@@ -18,7 +20,7 @@ impl FlowNodeInstanceClient {
     /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use processcube_engine_client::clients::{api::api_client::ApiClient, flow_node_instance::flow_node_instance_client::FlowNodeInstanceClient, error::EngineError};
     /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
     /// const ENGINE_URL: &str = "http://localhost:10560";
@@ -74,4 +76,28 @@ impl FlowNodeInstanceClient {
 
         self.api_client.get::<FlowNodeInstanceList>(&url).await
     }
+
+    /// Returns a stream of all FlowNodeInstances matching the given query, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `query` - The query the FlowNodeInstances have to match.
+    /// * `page_size` - The number of FlowNodeInstances to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_flow_node_instances_stream(
+        &self,
+        query: Option<FlowNodeInstancesQuery>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<FlowNodeInstance, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            let query = query.clone();
+            async move {
+                let list = client
+                    .get_flow_node_instances(Some(offset), Some(limit), query)
+                    .await?;
+                Ok((list.flow_node_instances, list.total_count))
+            }
+        })
+    }
 }
@@ -0,0 +1,111 @@
+use futures::Stream;
+
+use crate::clients::{api::api_client::ApiClient, error::EngineError, pagination::paginate};
+
+use super::manual_task::{ManualTask, ManualTaskList, ManualTasksQuery};
+
+const MANUAL_TASKS_ENDPOINT: &str = "/manual_tasks";
+
+/// A client for communicating with the ProcessCube® Engine's ManualTask API.
+#[derive(Clone)]
+pub struct ManualTaskClient {
+    api_client: ApiClient,
+    pub manual_tasks_url: String,
+}
+
+impl ManualTaskClient {
+    /// Creates a new instance of the ManualTaskClient.
+    ///
+    /// # Arguments
+    /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::clients::{api::api_client::ApiClient, manual_task::manual_task_client::ManualTaskClient, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let manual_task_client = ManualTaskClient::new(api_client);
+    ///     // Get all ManualTasks
+    ///     let manual_tasks = manual_task_client
+    ///         .get_manual_tasks(None, None, None)
+    ///         .await?;
+    ///     println!("ManualTasks: {:#?}", manual_tasks);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(api_client: ApiClient) -> ManualTaskClient {
+        let manual_tasks_url = format!(
+            "{}{}{}",
+            api_client.get_engine_url(),
+            api_client.get_engine_api_endpoint(),
+            MANUAL_TASKS_ENDPOINT
+        );
+        ManualTaskClient {
+            api_client,
+            manual_tasks_url,
+        }
+    }
+
+    /// Returns all ManualTasks matching the given query.
+    pub async fn get_manual_tasks(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        query: Option<ManualTasksQuery>,
+    ) -> Result<ManualTaskList, EngineError> {
+        let mut query_params = match query {
+            Some(query) => query.to_query_params(),
+            None => Vec::new(),
+        };
+
+        if let Some(offset) = offset {
+            query_params.push(format!("offset={}", offset));
+        }
+        if let Some(limit) = limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        let url = match query_params.is_empty() {
+            true => self.manual_tasks_url.clone(),
+            false => format!("{}?{}", self.manual_tasks_url, query_params.join("&")),
+        };
+
+        self.api_client.get::<ManualTaskList>(&url).await
+    }
+
+    /// Returns a stream of all ManualTasks matching the given query, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `query` - The query the ManualTasks have to match.
+    /// * `page_size` - The number of ManualTasks to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_manual_tasks_stream(
+        &self,
+        query: Option<ManualTasksQuery>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<ManualTask, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            let query = query.clone();
+            async move {
+                let list = client
+                    .get_manual_tasks(Some(offset), Some(limit), query)
+                    .await?;
+                Ok((list.manual_tasks, list.total_count))
+            }
+        })
+    }
+
+    /// Finishes the ManualTask with the given FlowNodeInstance ID.
+    pub async fn finish_manual_task(&self, flow_node_instance_id: &str) -> Result<(), EngineError> {
+        let url = format!("{}/{}/finish", self.manual_tasks_url, flow_node_instance_id);
+
+        self.api_client.put::<()>(&url, None).await
+    }
+}
@@ -0,0 +1,2 @@
+pub mod manual_task;
+pub mod manual_task_client;
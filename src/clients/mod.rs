@@ -1,9 +1,16 @@
 pub mod api;
 pub mod application_info;
 pub mod client_factory;
+pub mod client_factory_builder;
 pub mod correlation;
 pub mod error;
 pub mod event;
+pub mod external_task;
 pub mod flow_node_instance;
+pub mod manual_task;
+pub mod pagination;
 pub mod process_definition;
+pub mod process_instance;
 pub mod process_model;
+pub mod untyped_task;
+pub mod user_task;
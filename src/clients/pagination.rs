@@ -0,0 +1,79 @@
+use std::future::Future;
+
+use futures::{stream, Stream, TryStreamExt};
+
+use super::error::EngineError;
+
+/// The number of items requested per page if no page size is given.
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Returns a stream of all items of a list endpoint, fetching one page at a time.
+///
+/// The next page is only requested once all items of the current page are consumed.
+/// The stream ends once `total_count` items are fetched, the engine returns an empty page
+/// or a request fails. A page size of zero is rejected with `EngineError::Configuration`,
+/// as no page could ever make progress.
+///
+/// # Arguments
+/// * `page_size` - The number of items to request per page. Defaults to `DEFAULT_PAGE_SIZE`.
+/// * `fetch_page` - Fetches the items at the given offset and limit along with the total count.
+pub(crate) fn paginate<T, F, Fut>(
+    page_size: Option<u32>,
+    fetch_page: F,
+) -> impl Stream<Item = Result<T, EngineError>>
+where
+    F: FnMut(u32, u32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, u32), EngineError>>,
+{
+    let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+
+    stream::try_unfold(
+        (fetch_page, 0, None),
+        move |(mut fetch_page, offset, total_count): (F, u32, Option<u32>)| async move {
+            if page_size == 0 {
+                return Err(EngineError::Configuration(
+                    "The page size must not be zero.".to_string(),
+                ));
+            }
+            if total_count.is_some_and(|total_count| offset >= total_count) {
+                return Ok::<_, EngineError>(None);
+            }
+
+            let (items, total_count) = fetch_page(offset, page_size).await?;
+            if items.is_empty() {
+                return Ok(None);
+            }
+
+            let next_offset = offset + items.len() as u32;
+            Ok(Some((items, (fetch_page, next_offset, Some(total_count)))))
+        },
+    )
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Collects all items of a stream returned by one of the `*_stream` methods.
+///
+/// # Example
+/// ```no_run
+/// use processcube_engine_client::clients::{client_factory::ClientFactory, error::EngineError, pagination::collect_all};
+/// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+/// const ENGINE_URL: &str = "http://localhost:10560";
+/// // Be sure to have a running ProcessCube® Engine at the given URL
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), EngineError> {
+///     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+///     let process_model_client = client_factory.create_process_model_client();
+///     // Get all ProcessModels, 50 at a time
+///     let process_models = collect_all(process_model_client.get_process_models_stream(Some(50))).await?;
+///     println!("ProcessModels: {:#?}", process_models);
+///     Ok(())
+/// }
+/// ```
+pub async fn collect_all<T, S>(stream: S) -> Result<Vec<T>, EngineError>
+where
+    S: Stream<Item = Result<T, EngineError>>,
+{
+    stream.try_collect().await
+}
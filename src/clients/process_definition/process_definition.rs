@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::clients::process_model::process_model::ProcessModel;
+use crate::{
+    bpmn::{error::BpmnError, validator::parse_and_validate},
+    clients::process_model::process_model::ProcessModel,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -27,3 +30,21 @@ pub struct PersistProcessDefinitionPayload {
     pub xml: String,
     pub overwrite_existing: bool,
 }
+
+impl PersistProcessDefinitionPayload {
+    /// Checks the XML for issues the ProcessCube® Engine would reject it for, without contacting the engine.
+    ///
+    /// # Example
+    /// ```
+    /// use processcube_engine_client::clients::process_definition::process_definition::PersistProcessDefinitionPayload;
+    ///
+    /// let payload = PersistProcessDefinitionPayload {
+    ///     xml: "foo".to_string(),
+    ///     overwrite_existing: false,
+    /// };
+    /// assert!(payload.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), BpmnError> {
+        parse_and_validate(&self.xml).map(|_| ())
+    }
+}
@@ -1,4 +1,6 @@
-use crate::clients::{api::api_client::ApiClient, error::EngineError};
+use futures::Stream;
+
+use crate::clients::{api::api_client::ApiClient, error::EngineError, pagination::paginate};
 
 use super::process_definition::{
     PersistProcessDefinitionPayload, ProcessDefinition, ProcessDefinitionList,
@@ -20,7 +22,7 @@ impl ProcessDefinitionClient {
     /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use processcube_engine_client::clients::{api::api_client::ApiClient, process_definition::process_definition_client::ProcessDefinitionClient, error::EngineError};
     /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
     /// const ENGINE_URL: &str = "http://localhost:10560";
@@ -76,6 +78,27 @@ impl ProcessDefinitionClient {
         self.api_client.get::<ProcessDefinitionList>(&url).await
     }
 
+    /// Returns a stream of all ProcessDefinitions, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `page_size` - The number of ProcessDefinitions to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_process_definitions_stream(
+        &self,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<ProcessDefinition, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            async move {
+                let list = client
+                    .get_process_definitions(Some(offset), Some(limit))
+                    .await?;
+                Ok((list.process_definitions, list.total_count))
+            }
+        })
+    }
+
     /// Returns the ProcessDefinition with the given ID.
     pub async fn get_process_definition_by_id(
         &self,
@@ -0,0 +1,2 @@
+pub mod process_instance;
+pub mod process_instance_client;
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a list of ProcessInstances.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInstanceList {
+    /// The ProcessInstances.
+    pub process_instances: Vec<ProcessInstance>,
+    /// The total number of ProcessInstances.
+    pub total_count: u32,
+}
+
+/// Describes a ProcessInstance.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInstance {
+    pub process_instance_id: String,
+    pub process_definition_id: String,
+    pub process_model_id: String,
+    pub correlation_id: String,
+    pub owner_id: String,
+    pub state: ProcessInstanceState,
+
+    pub process_model_name: Option<String>,
+    pub hash: Option<String>,
+    pub parent_process_instance_id: Option<String>,
+    pub start_token: Option<serde_json::Value>,
+    pub end_token: Option<serde_json::Value>,
+    pub error: Option<serde_json::Value>,
+    pub metadata: Option<serde_json::Value>,
+    pub terminated_by_user_id: Option<String>,
+    pub created_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+/// The state of a ProcessInstance.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProcessInstanceState {
+    Error,
+    Finished,
+    Running,
+    Suspended,
+    Terminated,
+}
+
+impl ProcessInstanceState {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ProcessInstanceState::Error => "error",
+            ProcessInstanceState::Finished => "finished",
+            ProcessInstanceState::Running => "running",
+            ProcessInstanceState::Suspended => "suspended",
+            ProcessInstanceState::Terminated => "terminated",
+        }
+    }
+}
+
+/// The identity that owns a ProcessInstance.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInstanceOwner {
+    pub user_id: String,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeProcessInstanceOwnerPayload {
+    pub process_instance_id: String,
+    pub new_owner: ProcessInstanceOwner,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInstancesQuery {
+    pub correlation_id: Option<String>,
+    pub created_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub hash: Option<String>,
+    pub owner_id: Option<String>,
+    pub parent_process_instance_id: Option<String>,
+    pub process_definition_id: Option<String>,
+    pub process_instance_id: Option<String>,
+    pub process_model_id: Option<String>,
+    pub process_model_name: Option<String>,
+    pub state: Option<String>,
+    pub terminated_by_user_id: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl ProcessInstancesQuery {
+    pub fn to_query_params(&self) -> Vec<String> {
+        let mut parts = Vec::new();
+
+        fn append_param(parts: &mut Vec<String>, key: &str, value: &Option<String>) {
+            if let Some(ref val) = value {
+                parts.push(format!("{}={}", key, val));
+            }
+        }
+
+        append_param(&mut parts, "processInstanceId", &self.process_instance_id);
+        append_param(
+            &mut parts,
+            "processDefinitionId",
+            &self.process_definition_id,
+        );
+        append_param(&mut parts, "processModelId", &self.process_model_id);
+        append_param(&mut parts, "processModelName", &self.process_model_name);
+        append_param(&mut parts, "hash", &self.hash);
+        append_param(&mut parts, "correlationId", &self.correlation_id);
+        append_param(&mut parts, "ownerId", &self.owner_id);
+        append_param(&mut parts, "state", &self.state);
+        append_param(
+            &mut parts,
+            "parentProcessInstanceId",
+            &self.parent_process_instance_id,
+        );
+        append_param(
+            &mut parts,
+            "terminatedByUserId",
+            &self.terminated_by_user_id,
+        );
+
+        append_param(&mut parts, "createdAt", &self.created_at);
+        append_param(&mut parts, "updatedAt", &self.updated_at);
+        append_param(&mut parts, "finishedAt", &self.finished_at);
+
+        parts
+    }
+}
@@ -0,0 +1,193 @@
+use futures::Stream;
+
+use crate::clients::{api::api_client::ApiClient, error::EngineError, pagination::paginate};
+
+use super::process_instance::{
+    ChangeProcessInstanceOwnerPayload, ProcessInstance, ProcessInstanceList, ProcessInstanceOwner,
+    ProcessInstancesQuery,
+};
+
+const PROCESS_INSTANCES_ENDPOINT: &str = "/process_instances";
+
+/// A client for communicating with the ProcessCube® Engine's ProcessInstance API.
+#[derive(Clone)]
+pub struct ProcessInstanceClient {
+    api_client: ApiClient,
+    pub process_instances_url: String,
+}
+
+impl ProcessInstanceClient {
+    /// Creates a new instance of the ProcessInstanceClient.
+    ///
+    /// # Arguments
+    /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::clients::{api::api_client::ApiClient, process_instance::process_instance_client::ProcessInstanceClient, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let process_instance_client = ProcessInstanceClient::new(api_client);
+    ///     // Get all ProcessInstances
+    ///     let process_instances = process_instance_client
+    ///         .get_process_instances(None, None, None)
+    ///         .await?;
+    ///     println!("ProcessInstances: {:#?}", process_instances);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(api_client: ApiClient) -> ProcessInstanceClient {
+        let process_instances_url = format!(
+            "{}{}{}",
+            api_client.get_engine_url(),
+            api_client.get_engine_api_endpoint(),
+            PROCESS_INSTANCES_ENDPOINT
+        );
+        ProcessInstanceClient {
+            api_client,
+            process_instances_url,
+        }
+    }
+
+    /// Returns all ProcessInstances matching the given query.
+    pub async fn get_process_instances(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        query: Option<ProcessInstancesQuery>,
+    ) -> Result<ProcessInstanceList, EngineError> {
+        let mut query_params = match query {
+            Some(query) => query.to_query_params(),
+            None => Vec::new(),
+        };
+
+        if let Some(offset) = offset {
+            query_params.push(format!("offset={}", offset));
+        }
+        if let Some(limit) = limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        let url = match query_params.is_empty() {
+            true => format!("{}/query", self.process_instances_url),
+            false => format!(
+                "{}/query?{}",
+                self.process_instances_url,
+                query_params.join("&")
+            ),
+        };
+
+        self.api_client.get::<ProcessInstanceList>(&url).await
+    }
+
+    /// Returns a stream of all ProcessInstances matching the given query, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `query` - The query the ProcessInstances have to match.
+    /// * `page_size` - The number of ProcessInstances to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_process_instances_stream(
+        &self,
+        query: Option<ProcessInstancesQuery>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<ProcessInstance, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            let query = query.clone();
+            async move {
+                let list = client
+                    .get_process_instances(Some(offset), Some(limit), query)
+                    .await?;
+                Ok((list.process_instances, list.total_count))
+            }
+        })
+    }
+
+    /// Returns the ProcessInstance with the given ID.
+    pub async fn get_process_instance_by_id(
+        &self,
+        process_instance_id: &str,
+    ) -> Result<ProcessInstance, EngineError> {
+        let query = ProcessInstancesQuery {
+            process_instance_id: Some(process_instance_id.to_string()),
+            ..Default::default()
+        };
+
+        let process_instance_list = self
+            .get_process_instances(None, Some(1), Some(query))
+            .await?;
+
+        match process_instance_list.process_instances.into_iter().next() {
+            Some(process_instance) => Ok(process_instance),
+            None => Err(EngineError::not_found(format!(
+                "Process instance with ID `{}` not found.",
+                process_instance_id
+            ))),
+        }
+    }
+
+    /// Terminates the ProcessInstance with the given ID.
+    pub async fn terminate_process_instance_by_id(
+        &self,
+        process_instance_id: &str,
+    ) -> Result<(), EngineError> {
+        let url = format!(
+            "{}/{}/terminate",
+            self.process_instances_url, process_instance_id
+        );
+
+        self.api_client.put::<()>(&url, None).await
+    }
+
+    /// Retries the failed or terminated ProcessInstance with the given ID.
+    ///
+    /// # Arguments
+    /// * `process_instance_id` - The ID of the ProcessInstance to retry.
+    /// * `flow_node_instance_id` - The ID of the FlowNodeInstance to resume from.
+    ///   If this is set to `None`, the ProcessInstance is resumed at the FlowNodeInstance that failed.
+    /// * `new_start_token` - An optional token to replace the start token of the resumed FlowNodeInstance.
+    pub async fn retry_process_instance_by_id(
+        &self,
+        process_instance_id: &str,
+        flow_node_instance_id: Option<&str>,
+        new_start_token: Option<serde_json::Value>,
+    ) -> Result<(), EngineError> {
+        let url = match flow_node_instance_id {
+            Some(flow_node_instance_id) => format!(
+                "{}/{}/retry?flowNodeInstanceId={}",
+                self.process_instances_url, process_instance_id, flow_node_instance_id
+            ),
+            None => format!(
+                "{}/{}/retry",
+                self.process_instances_url, process_instance_id
+            ),
+        };
+
+        let request_json = new_start_token
+            .map(|new_start_token| serde_json::json!({ "newStartToken": new_start_token }));
+
+        self.api_client.put::<()>(&url, request_json.as_ref()).await
+    }
+
+    /// Changes the owner of the ProcessInstance with the given ID.
+    pub async fn change_process_instance_owner(
+        &self,
+        process_instance_id: &str,
+        new_owner: ProcessInstanceOwner,
+    ) -> Result<(), EngineError> {
+        let url = format!("{}/owner", self.process_instances_url);
+        let request = ChangeProcessInstanceOwnerPayload {
+            process_instance_id: process_instance_id.to_string(),
+            new_owner,
+        };
+        let request_json = serde_json::to_value(request).expect("Failed to serialize request");
+
+        self.api_client.post::<()>(&url, Some(&request_json)).await
+    }
+}
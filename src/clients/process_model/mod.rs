@@ -1,2 +1,3 @@
 pub mod process_model;
 pub mod process_model_client;
+pub mod process_start_request_builder;
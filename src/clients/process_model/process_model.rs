@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use super::process_start_request_builder::ProcessStartRequestBuilder;
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessModel {
@@ -24,14 +28,36 @@ pub struct ProcessModelList {
     pub total_count: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Describes how to start a ProcessInstance. Fields left empty are chosen by the ProcessCube® Engine.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessStartRequest {
-    pub start_event_id: String,
-    pub correlation_id: String,
-    pub initial_token: serde_json::Value,
-    pub return_on: String,
-    pub end_event_id: String,
+    /// The Start Event to start at. Required if the ProcessModel has more than one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_event_id: Option<String>,
+    /// The Correlation to add the ProcessInstance to. The engine creates a new one if omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_token: Option<serde_json::Value>,
+    #[serde(default)]
+    pub return_on: ReturnOn,
+    /// The End Event to wait for when returning on `ReturnOn::OnEndEventReached`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_event_id: Option<String>,
+}
+
+/// When the ProcessCube® Engine responds to a ProcessStartRequest.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ReturnOn {
+    /// Respond as soon as the Process Instance was started.
+    #[default]
+    OnProcessInstanceStarted,
+    /// Respond once the given End Event was reached.
+    OnEndEventReached,
+    /// Respond once the Process Instance is finished.
+    OnProcessInstanceFinished,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -39,10 +65,32 @@ pub struct ProcessStartRequest {
 pub struct ProcessStartResponse {
     pub process_instance_id: String,
     pub correlation_id: String,
+    #[serde(default)]
     pub end_event_id: String,
+    #[serde(default)]
     pub token_payload: serde_json::Value,
 }
 
+/// Describes how `start_and_wait` polls for the result of a ProcessInstance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitOptions {
+    /// The time between two polls.
+    pub poll_interval: Duration,
+    /// The time after which to stop polling.
+    pub timeout: Duration,
+}
+
+/// The outcome of a ProcessInstance started with `start_and_wait`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessInstanceResult<T> {
+    pub process_instance_id: String,
+    pub correlation_id: String,
+    /// The End Event the ProcessInstance finished at, if the engine reported it.
+    pub end_event_id: Option<String>,
+    /// The token at the End Event.
+    pub token: T,
+}
+
 impl Default for ProcessStartResponse {
     fn default() -> Self {
         ProcessStartResponse {
@@ -54,89 +102,115 @@ impl Default for ProcessStartResponse {
     }
 }
 
+impl ProcessStartRequest {
+    /// Returns a builder for a ProcessStartRequest.
+    pub fn builder() -> ProcessStartRequestBuilder {
+        ProcessStartRequestBuilder::new()
+    }
+}
+
+impl ReturnOn {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ReturnOn::OnProcessInstanceStarted => "onProcessInstanceStarted",
+            ReturnOn::OnEndEventReached => "onEndEventReached",
+            ReturnOn::OnProcessInstanceFinished => "onProcessInstanceFinished",
+        }
+    }
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        WaitOptions {
+            poll_interval: Duration::from_millis(500),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct LaneSet {
-    lanes: Vec<Lane>,
+    pub lanes: Vec<Lane>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Lane {
-    id: String,
-    extension_elements: Option<ExtensionElements>,
-    flow_node_references: Option<Vec<String>>,
-    name: Option<String>,
-    child_lane_set: Option<Vec<String>>,
-    documentation: Option<Vec<String>>,
+    pub id: String,
+    pub extension_elements: Option<ExtensionElements>,
+    pub flow_node_references: Option<Vec<String>>,
+    pub name: Option<String>,
+    pub child_lane_set: Option<Vec<String>>,
+    pub documentation: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ExtensionElements {
-    camunda_execution_listener: Option<CamundaExecutionListener>,
-    camunda_extension_properties: Vec<CamundaExtensionProperties>,
+    pub camunda_execution_listener: Option<CamundaExecutionListener>,
+    pub camunda_extension_properties: Vec<CamundaExtensionProperties>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CamundaExecutionListener {
-    class: String,
-    event: String,
+    pub class: String,
+    pub event: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CamundaExtensionProperties {
-    name: String,
-    value: String,
+    pub name: String,
+    pub value: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct StartEvent {
-    id: String,
-    name: String,
-    process_model_id: String,
-    process_model_name: String,
-    custom_properties: serde_json::Value,
-    flow_node_type: String,
-    timer_type: Option<String>,
-    timer_value: Option<String>,
-    message_name: Option<String>,
-    message_id: Option<String>,
-    signal_name: Option<String>,
-    signal_id: Option<String>,
-    documentation: Option<Vec<String>>,
+    pub id: String,
+    pub name: String,
+    pub process_model_id: String,
+    pub process_model_name: String,
+    pub custom_properties: serde_json::Value,
+    pub flow_node_type: String,
+    pub timer_type: Option<String>,
+    pub timer_value: Option<String>,
+    pub message_name: Option<String>,
+    pub message_id: Option<String>,
+    pub signal_name: Option<String>,
+    pub signal_id: Option<String>,
+    pub documentation: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct EndEvent {
-    id: String,
-    name: String,
-    process_model_id: String,
-    process_model_name: String,
-    custom_properties: serde_json::Value,
-    flow_node_type: String,
-    error_name: Option<String>,
-    error_code: Option<String>,
-    error_message: Option<String>,
-    message_name: Option<String>,
-    message_id: Option<String>,
-    signal_name: Option<String>,
-    signal_id: Option<String>,
-    documentation: Option<Vec<String>>,
+    pub id: String,
+    pub name: String,
+    pub process_model_id: String,
+    pub process_model_name: String,
+    pub custom_properties: serde_json::Value,
+    pub flow_node_type: String,
+    pub error_name: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub message_name: Option<String>,
+    pub message_id: Option<String>,
+    pub signal_name: Option<String>,
+    pub signal_id: Option<String>,
+    pub documentation: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FlowNode {
-    id: String,
-    name: String,
-    custom_properties: serde_json::Value,
-    flow_node_type: String,
-    process_model_id: String,
-    process_model_name: String,
-    documentation: Option<Vec<String>>,
+    pub id: String,
+    pub name: String,
+    pub custom_properties: serde_json::Value,
+    pub flow_node_type: String,
+    pub process_model_id: String,
+    pub process_model_name: String,
+    pub documentation: Option<Vec<String>>,
 }
@@ -1,10 +1,24 @@
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use tokio::time::{sleep, Instant};
+
 use crate::clients::{
-    api::api_client::ApiClient, error::EngineError,
+    api::api_client::ApiClient,
+    error::EngineError,
+    flow_node_instance::{
+        flow_node_instance::FlowNodeInstancesQuery,
+        flow_node_instance_client::FlowNodeInstanceClient,
+    },
+    pagination::paginate,
     process_definition::process_definition::ProcessDefinition,
+    process_instance::{
+        process_instance::ProcessInstanceState, process_instance_client::ProcessInstanceClient,
+    },
 };
 
 use super::process_model::{
-    ProcessModel, ProcessModelList, ProcessStartRequest, ProcessStartResponse,
+    ProcessInstanceResult, ProcessModel, ProcessModelList, ProcessStartRequest,
+    ProcessStartResponse, ReturnOn, WaitOptions,
 };
 
 const PROCESS_MODELS_ENDPOINT: &str = "/process_models";
@@ -23,7 +37,7 @@ impl ProcessModelClient {
     /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     /// use processcube_engine_client::clients::{api::api_client::ApiClient, process_model::process_model_client::ProcessModelClient, error::EngineError};
     /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
     /// const ENGINE_URL: &str = "http://localhost:10560";
@@ -76,6 +90,25 @@ impl ProcessModelClient {
         self.api_client.get::<ProcessModelList>(&url).await
     }
 
+    /// Returns a stream of all ProcessModels, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `page_size` - The number of ProcessModels to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_process_models_stream(
+        &self,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<ProcessModel, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            async move {
+                let list = client.get_process_models(Some(offset), Some(limit)).await?;
+                Ok((list.process_models, list.total_count))
+            }
+        })
+    }
+
     /// Returns the ProcessModel with the given ID.
     pub async fn get_process_model_by_id(
         &self,
@@ -113,6 +146,157 @@ impl ProcessModelClient {
             .await
     }
 
+    /// Starts a new ProcessInstance of the ProcessModel with the given ID and waits for its result.
+    ///
+    /// If the engine already responds with the end token, it is returned right away. Otherwise the
+    /// FlowNodeInstances of the request's `end_event_id`, or the ProcessInstance itself if no
+    /// End Event is given, are polled until the ProcessInstance finishes or `options.timeout` passes.
+    ///
+    /// # Arguments
+    /// * `process_model_id` - The ID of the ProcessModel to start.
+    /// * `request` - The request to start the ProcessInstance with.
+    /// * `options` - How often and how long to poll for the result.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::clients::{
+    ///     client_factory::ClientFactory,
+    ///     error::EngineError,
+    ///     process_model::process_model::{ProcessStartRequest, WaitOptions},
+    /// };
+    /// use serde::Deserialize;
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[derive(Deserialize, Debug)]
+    /// struct Order {
+    ///     approved: bool,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let process_model_client = client_factory.create_process_model_client();
+    ///     let request = ProcessStartRequest::builder()
+    ///         .initial_token(&serde_json::json!({ "orderId": 42 }))
+    ///         .build()?;
+    ///     // Start the ProcessModel and wait for it to finish
+    ///     let result = process_model_client
+    ///         .start_and_wait::<Order>("Order_Process", request, WaitOptions::default())
+    ///         .await?;
+    ///     println!("Approved: {}", result.token.approved);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start_and_wait<T: DeserializeOwned>(
+        &self,
+        process_model_id: &str,
+        request: ProcessStartRequest,
+        options: WaitOptions,
+    ) -> Result<ProcessInstanceResult<T>, EngineError> {
+        let return_on = request.return_on;
+        let end_event_id = request.end_event_id.clone();
+
+        let response = self
+            .start_process_instance_by_process_model_id(process_model_id, request)
+            .await?;
+
+        if return_on != ReturnOn::OnProcessInstanceStarted && !response.end_event_id.is_empty() {
+            return Ok(ProcessInstanceResult {
+                process_instance_id: response.process_instance_id,
+                correlation_id: response.correlation_id,
+                end_event_id: Some(response.end_event_id),
+                token: serde_json::from_value(response.token_payload)?,
+            });
+        }
+
+        let (end_event_id, token) = self
+            .wait_for_end_token(&response, end_event_id, options)
+            .await?;
+
+        Ok(ProcessInstanceResult {
+            process_instance_id: response.process_instance_id,
+            correlation_id: response.correlation_id,
+            end_event_id,
+            token: serde_json::from_value(token)?,
+        })
+    }
+
+    /// Polls until the started ProcessInstance finishes, optionally at the given End Event.
+    async fn wait_for_end_token(
+        &self,
+        response: &ProcessStartResponse,
+        end_event_id: Option<String>,
+        options: WaitOptions,
+    ) -> Result<(Option<String>, serde_json::Value), EngineError> {
+        let process_instance_client = ProcessInstanceClient::new(self.api_client.clone());
+        let flow_node_instance_client = FlowNodeInstanceClient::new(self.api_client.clone());
+        let process_instance_id = &response.process_instance_id;
+        let deadline = Instant::now() + options.timeout;
+
+        loop {
+            // A ProcessInstance may not be visible right after it was started
+            let process_instance = match process_instance_client
+                .get_process_instance_by_id(process_instance_id)
+                .await
+            {
+                Ok(process_instance) => Some(process_instance),
+                Err(EngineError::NotFound(_)) => None,
+                Err(err) => return Err(err),
+            };
+
+            if let Some(end_event_id) = &end_event_id {
+                let query = FlowNodeInstancesQuery {
+                    flow_node_id: Some(end_event_id.clone()),
+                    process_instance_id: Some(process_instance_id.clone()),
+                    state: Some("finished".to_string()),
+                    ..Default::default()
+                };
+                let flow_node_instances = flow_node_instance_client
+                    .get_flow_node_instances(None, Some(1), Some(query))
+                    .await?
+                    .flow_node_instances;
+                if let Some(flow_node_instance) = flow_node_instances.into_iter().next() {
+                    return Ok((
+                        Some(end_event_id.clone()),
+                        flow_node_instance.end_token.unwrap_or_default(),
+                    ));
+                }
+            }
+
+            if let Some(process_instance) = process_instance {
+                match process_instance.state {
+                    ProcessInstanceState::Finished => match &end_event_id {
+                        None => return Ok((None, process_instance.end_token.unwrap_or_default())),
+                        Some(end_event_id) => {
+                            return Err(EngineError::EndEventNotReached {
+                                process_instance_id: process_instance_id.clone(),
+                                end_event_id: end_event_id.clone(),
+                            })
+                        }
+                    },
+                    ProcessInstanceState::Error | ProcessInstanceState::Terminated => {
+                        return Err(EngineError::ProcessInstanceFailed {
+                            process_instance_id: process_instance_id.clone(),
+                            state: process_instance.state,
+                            error: process_instance.error,
+                        })
+                    }
+                    ProcessInstanceState::Running | ProcessInstanceState::Suspended => {}
+                }
+            }
+
+            if Instant::now() + options.poll_interval > deadline {
+                return Err(EngineError::WaitTimeout {
+                    process_instance_id: process_instance_id.clone(),
+                    timeout: options.timeout,
+                });
+            }
+            sleep(options.poll_interval).await;
+        }
+    }
+
     /// Enables the ProcessModel with the given ID.
     pub async fn enable_process_model_by_id(
         &self,
@@ -0,0 +1,97 @@
+use serde::Serialize;
+
+use crate::clients::error::EngineError;
+
+use super::process_model::{ProcessStartRequest, ReturnOn};
+
+/// A builder for a ProcessStartRequest.
+///
+/// By default, the engine chooses the Start Event, creates a new Correlation and responds as soon
+/// as the ProcessInstance was started. The request is validated when calling `build`.
+///
+/// # Example
+/// ```
+/// use processcube_engine_client::clients::process_model::process_model::{ProcessStartRequest, ReturnOn};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Order {
+///     order_id: u32,
+/// }
+///
+/// let request = ProcessStartRequest::builder()
+///     .start_event_id("StartEvent_1")
+///     .initial_token(&Order { order_id: 42 })
+///     .return_on_end_event("EndEvent_1")
+///     .build()
+///     .unwrap();
+/// assert_eq!(request.return_on, ReturnOn::OnEndEventReached);
+/// ```
+#[derive(Debug, Default)]
+pub struct ProcessStartRequestBuilder {
+    request: ProcessStartRequest,
+    token_error: Option<serde_json::Error>,
+}
+
+impl ProcessStartRequestBuilder {
+    /// Creates a new instance of the ProcessStartRequestBuilder.
+    pub fn new() -> ProcessStartRequestBuilder {
+        ProcessStartRequestBuilder::default()
+    }
+
+    /// Sets the Start Event to start the ProcessInstance at.
+    pub fn start_event_id(mut self, start_event_id: &str) -> ProcessStartRequestBuilder {
+        self.request.start_event_id = Some(start_event_id.to_string());
+        self
+    }
+
+    /// Sets the Correlation to add the ProcessInstance to.
+    pub fn correlation_id(mut self, correlation_id: &str) -> ProcessStartRequestBuilder {
+        self.request.correlation_id = Some(correlation_id.to_string());
+        self
+    }
+
+    /// Sets the initial token to any value that serializes to JSON.
+    pub fn initial_token<T: Serialize + ?Sized>(
+        mut self,
+        initial_token: &T,
+    ) -> ProcessStartRequestBuilder {
+        match serde_json::to_value(initial_token) {
+            Ok(initial_token) => self.request.initial_token = Some(initial_token),
+            Err(e) => self.token_error = Some(e),
+        }
+        self
+    }
+
+    /// Sets when the engine responds to the request.
+    pub fn return_on(mut self, return_on: ReturnOn) -> ProcessStartRequestBuilder {
+        self.request.return_on = return_on;
+        self
+    }
+
+    /// Makes the engine respond once the given End Event was reached.
+    pub fn return_on_end_event(mut self, end_event_id: &str) -> ProcessStartRequestBuilder {
+        self.request.return_on = ReturnOn::OnEndEventReached;
+        self.request.end_event_id = Some(end_event_id.to_string());
+        self
+    }
+
+    /// Validates the settings and builds the ProcessStartRequest.
+    ///
+    /// Fails with `EngineError::Deserialization` if the initial token could not be serialized,
+    /// and with `EngineError::Configuration` if the settings contradict each other.
+    pub fn build(self) -> Result<ProcessStartRequest, EngineError> {
+        if let Some(token_error) = self.token_error {
+            return Err(EngineError::Deserialization(token_error));
+        }
+        if self.request.return_on == ReturnOn::OnEndEventReached
+            && self.request.end_event_id.is_none()
+        {
+            return Err(EngineError::Configuration(
+                "An end event ID is required to return on an end event being reached.".to_string(),
+            ));
+        }
+
+        Ok(self.request)
+    }
+}
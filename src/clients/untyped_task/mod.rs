@@ -0,0 +1,2 @@
+pub mod untyped_task;
+pub mod untyped_task_client;
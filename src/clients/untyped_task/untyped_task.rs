@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::clients::flow_node_instance::flow_node_instance::FlowNodeInstanceState;
+
+/// Describes a list of UntypedTasks.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UntypedTaskList {
+    /// The UntypedTasks.
+    pub untyped_tasks: Vec<UntypedTask>,
+    /// The total number of UntypedTasks.
+    pub total_count: u32,
+}
+
+/// Describes a UntypedTask waiting to be finished.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UntypedTask {
+    pub flow_node_instance_id: String,
+    pub flow_node_id: String,
+    pub correlation_id: String,
+    pub process_definition_id: String,
+    pub process_instance_id: String,
+    pub process_model_id: String,
+    pub owner_id: String,
+    pub state: FlowNodeInstanceState,
+
+    pub flow_node_name: Option<String>,
+    pub flow_node_lane: Option<String>,
+    pub process_model_name: Option<String>,
+    pub start_token: Option<serde_json::Value>,
+    pub end_token: Option<serde_json::Value>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UntypedTasksQuery {
+    pub correlation_id: Option<String>,
+    pub flow_node_id: Option<String>,
+    pub flow_node_instance_id: Option<String>,
+    pub flow_node_lane: Option<String>,
+    pub flow_node_name: Option<String>,
+    pub owner_id: Option<String>,
+    pub process_definition_id: Option<String>,
+    pub process_instance_id: Option<String>,
+    pub process_model_id: Option<String>,
+    pub state: Option<String>,
+}
+
+impl UntypedTasksQuery {
+    pub fn to_query_params(&self) -> Vec<String> {
+        let mut parts = Vec::new();
+
+        fn append_param(parts: &mut Vec<String>, key: &str, value: &Option<String>) {
+            if let Some(ref val) = value {
+                parts.push(format!("{}={}", key, val));
+            }
+        }
+
+        append_param(
+            &mut parts,
+            "flowNodeInstanceId",
+            &self.flow_node_instance_id,
+        );
+        append_param(&mut parts, "flowNodeId", &self.flow_node_id);
+        append_param(&mut parts, "flowNodeName", &self.flow_node_name);
+        append_param(&mut parts, "flowNodeLane", &self.flow_node_lane);
+        append_param(&mut parts, "correlationId", &self.correlation_id);
+        append_param(
+            &mut parts,
+            "processDefinitionId",
+            &self.process_definition_id,
+        );
+        append_param(&mut parts, "processModelId", &self.process_model_id);
+        append_param(&mut parts, "processInstanceId", &self.process_instance_id);
+        append_param(&mut parts, "ownerId", &self.owner_id);
+        append_param(&mut parts, "state", &self.state);
+
+        parts
+    }
+}
@@ -0,0 +1,117 @@
+use futures::Stream;
+
+use crate::clients::{api::api_client::ApiClient, error::EngineError, pagination::paginate};
+
+use super::untyped_task::{UntypedTask, UntypedTaskList, UntypedTasksQuery};
+
+const UNTYPED_TASKS_ENDPOINT: &str = "/untyped_tasks";
+
+/// A client for communicating with the ProcessCube® Engine's UntypedTask API.
+#[derive(Clone)]
+pub struct UntypedTaskClient {
+    api_client: ApiClient,
+    pub untyped_tasks_url: String,
+}
+
+impl UntypedTaskClient {
+    /// Creates a new instance of the UntypedTaskClient.
+    ///
+    /// # Arguments
+    /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::clients::{api::api_client::ApiClient, untyped_task::untyped_task_client::UntypedTaskClient, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let untyped_task_client = UntypedTaskClient::new(api_client);
+    ///     // Get all UntypedTasks
+    ///     let untyped_tasks = untyped_task_client
+    ///         .get_untyped_tasks(None, None, None)
+    ///         .await?;
+    ///     println!("UntypedTasks: {:#?}", untyped_tasks);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(api_client: ApiClient) -> UntypedTaskClient {
+        let untyped_tasks_url = format!(
+            "{}{}{}",
+            api_client.get_engine_url(),
+            api_client.get_engine_api_endpoint(),
+            UNTYPED_TASKS_ENDPOINT
+        );
+        UntypedTaskClient {
+            api_client,
+            untyped_tasks_url,
+        }
+    }
+
+    /// Returns all UntypedTasks matching the given query.
+    pub async fn get_untyped_tasks(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        query: Option<UntypedTasksQuery>,
+    ) -> Result<UntypedTaskList, EngineError> {
+        let mut query_params = match query {
+            Some(query) => query.to_query_params(),
+            None => Vec::new(),
+        };
+
+        if let Some(offset) = offset {
+            query_params.push(format!("offset={}", offset));
+        }
+        if let Some(limit) = limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        let url = match query_params.is_empty() {
+            true => self.untyped_tasks_url.clone(),
+            false => format!("{}?{}", self.untyped_tasks_url, query_params.join("&")),
+        };
+
+        self.api_client.get::<UntypedTaskList>(&url).await
+    }
+
+    /// Returns a stream of all UntypedTasks matching the given query, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `query` - The query the UntypedTasks have to match.
+    /// * `page_size` - The number of UntypedTasks to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_untyped_tasks_stream(
+        &self,
+        query: Option<UntypedTasksQuery>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<UntypedTask, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            let query = query.clone();
+            async move {
+                let list = client
+                    .get_untyped_tasks(Some(offset), Some(limit), query)
+                    .await?;
+                Ok((list.untyped_tasks, list.total_count))
+            }
+        })
+    }
+
+    /// Finishes the UntypedTask with the given FlowNodeInstance ID.
+    pub async fn finish_untyped_task(
+        &self,
+        flow_node_instance_id: &str,
+    ) -> Result<(), EngineError> {
+        let url = format!(
+            "{}/{}/finish",
+            self.untyped_tasks_url, flow_node_instance_id
+        );
+
+        self.api_client.put::<()>(&url, None).await
+    }
+}
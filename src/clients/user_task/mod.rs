@@ -0,0 +1,2 @@
+pub mod user_task;
+pub mod user_task_client;
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::clients::flow_node_instance::flow_node_instance::FlowNodeInstanceState;
+
+/// Describes a list of UserTasks.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTaskList {
+    /// The UserTasks.
+    pub user_tasks: Vec<UserTask>,
+    /// The total number of UserTasks.
+    pub total_count: u32,
+}
+
+/// Describes a UserTask waiting to be finished.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTask {
+    pub flow_node_instance_id: String,
+    pub flow_node_id: String,
+    pub correlation_id: String,
+    pub process_definition_id: String,
+    pub process_instance_id: String,
+    pub process_model_id: String,
+    pub owner_id: String,
+    pub state: FlowNodeInstanceState,
+    pub user_task_config: UserTaskConfig,
+
+    pub actual_owner_id: Option<String>,
+    pub flow_node_name: Option<String>,
+    pub flow_node_lane: Option<String>,
+    pub process_model_name: Option<String>,
+    pub start_token: Option<serde_json::Value>,
+    pub end_token: Option<serde_json::Value>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+}
+
+/// Describes the form a UserTask presents to its user.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTaskConfig {
+    pub form_fields: Vec<UserTaskFormField>,
+
+    pub custom_form: Option<String>,
+    pub description: Option<String>,
+    pub finished_description: Option<String>,
+    pub preferred_control: Option<String>,
+}
+
+/// Describes a single field of a UserTask form.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTaskFormField {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+
+    pub label: Option<String>,
+    pub default_value: Option<serde_json::Value>,
+    pub custom_properties: Option<serde_json::Value>,
+}
+
+/// The result a UserTask is finished with, mapping form field IDs to their values.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct UserTaskResult {
+    #[serde(flatten)]
+    pub form_fields: HashMap<String, serde_json::Value>,
+}
+
+impl UserTaskResult {
+    pub fn new() -> UserTaskResult {
+        UserTaskResult::default()
+    }
+
+    /// Sets the value of the form field with the given ID.
+    pub fn with_field<T>(mut self, field_id: &str, value: T) -> UserTaskResult
+    where
+        T: Into<serde_json::Value>,
+    {
+        self.form_fields.insert(field_id.to_string(), value.into());
+        self
+    }
+}
+
+impl From<HashMap<String, serde_json::Value>> for UserTaskResult {
+    fn from(form_fields: HashMap<String, serde_json::Value>) -> Self {
+        UserTaskResult { form_fields }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTasksQuery {
+    pub actual_owner_id: Option<String>,
+    pub correlation_id: Option<String>,
+    pub flow_node_id: Option<String>,
+    pub flow_node_instance_id: Option<String>,
+    pub flow_node_lane: Option<String>,
+    pub flow_node_name: Option<String>,
+    pub owner_id: Option<String>,
+    pub process_definition_id: Option<String>,
+    pub process_instance_id: Option<String>,
+    pub process_model_id: Option<String>,
+    pub state: Option<String>,
+}
+
+impl UserTasksQuery {
+    pub fn to_query_params(&self) -> Vec<String> {
+        let mut parts = Vec::new();
+
+        fn append_param(parts: &mut Vec<String>, key: &str, value: &Option<String>) {
+            if let Some(ref val) = value {
+                parts.push(format!("{}={}", key, val));
+            }
+        }
+
+        append_param(
+            &mut parts,
+            "flowNodeInstanceId",
+            &self.flow_node_instance_id,
+        );
+        append_param(&mut parts, "flowNodeId", &self.flow_node_id);
+        append_param(&mut parts, "flowNodeName", &self.flow_node_name);
+        append_param(&mut parts, "flowNodeLane", &self.flow_node_lane);
+        append_param(&mut parts, "correlationId", &self.correlation_id);
+        append_param(
+            &mut parts,
+            "processDefinitionId",
+            &self.process_definition_id,
+        );
+        append_param(&mut parts, "processModelId", &self.process_model_id);
+        append_param(&mut parts, "processInstanceId", &self.process_instance_id);
+        append_param(&mut parts, "ownerId", &self.owner_id);
+        append_param(&mut parts, "actualOwnerId", &self.actual_owner_id);
+        append_param(&mut parts, "state", &self.state);
+
+        parts
+    }
+}
@@ -0,0 +1,168 @@
+use futures::Stream;
+
+use crate::clients::{api::api_client::ApiClient, error::EngineError, pagination::paginate};
+
+use super::user_task::{UserTask, UserTaskList, UserTaskResult, UserTasksQuery};
+
+const USER_TASKS_ENDPOINT: &str = "/user_tasks";
+
+/// A client for communicating with the ProcessCube® Engine's UserTask API.
+#[derive(Clone)]
+pub struct UserTaskClient {
+    api_client: ApiClient,
+    pub user_tasks_url: String,
+}
+
+impl UserTaskClient {
+    /// Creates a new instance of the UserTaskClient.
+    ///
+    /// # Arguments
+    /// * `api_client` - The ApiClient to use for communication with the ProcessCube® Engine.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::clients::{api::api_client::ApiClient, user_task::user_task_client::UserTaskClient, error::EngineError};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let user_task_client = UserTaskClient::new(api_client);
+    ///     // Get all UserTasks
+    ///     let user_tasks = user_task_client.get_user_tasks(None, None, None).await?;
+    ///     println!("UserTasks: {:#?}", user_tasks);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(api_client: ApiClient) -> UserTaskClient {
+        let user_tasks_url = format!(
+            "{}{}{}",
+            api_client.get_engine_url(),
+            api_client.get_engine_api_endpoint(),
+            USER_TASKS_ENDPOINT
+        );
+        UserTaskClient {
+            api_client,
+            user_tasks_url,
+        }
+    }
+
+    /// Returns all UserTasks matching the given query.
+    pub async fn get_user_tasks(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        query: Option<UserTasksQuery>,
+    ) -> Result<UserTaskList, EngineError> {
+        let mut query_params = match query {
+            Some(query) => query.to_query_params(),
+            None => Vec::new(),
+        };
+
+        if let Some(offset) = offset {
+            query_params.push(format!("offset={}", offset));
+        }
+        if let Some(limit) = limit {
+            query_params.push(format!("limit={}", limit));
+        }
+
+        let url = match query_params.is_empty() {
+            true => self.user_tasks_url.clone(),
+            false => format!("{}?{}", self.user_tasks_url, query_params.join("&")),
+        };
+
+        self.api_client.get::<UserTaskList>(&url).await
+    }
+
+    /// Returns a stream of all UserTasks matching the given query, fetching them page by page.
+    ///
+    /// # Arguments
+    /// * `query` - The query the UserTasks have to match.
+    /// * `page_size` - The number of UserTasks to fetch per request. Defaults to `DEFAULT_PAGE_SIZE`. Must not be zero.
+    pub fn get_user_tasks_stream(
+        &self,
+        query: Option<UserTasksQuery>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<UserTask, EngineError>> {
+        let client = self.clone();
+
+        paginate(page_size, move |offset, limit| {
+            let client = client.clone();
+            let query = query.clone();
+            async move {
+                let list = client
+                    .get_user_tasks(Some(offset), Some(limit), query)
+                    .await?;
+                Ok((list.user_tasks, list.total_count))
+            }
+        })
+    }
+
+    /// Returns the UserTask with the given FlowNodeInstance ID.
+    pub async fn get_user_task_by_id(
+        &self,
+        flow_node_instance_id: &str,
+    ) -> Result<UserTask, EngineError> {
+        let query = UserTasksQuery {
+            flow_node_instance_id: Some(flow_node_instance_id.to_string()),
+            ..Default::default()
+        };
+
+        let user_task_list = self.get_user_tasks(None, Some(1), Some(query)).await?;
+
+        match user_task_list.user_tasks.into_iter().next() {
+            Some(user_task) => Ok(user_task),
+            None => Err(EngineError::not_found(format!(
+                "User task with ID `{}` not found.",
+                flow_node_instance_id
+            ))),
+        }
+    }
+
+    /// Reserves the UserTask with the given FlowNodeInstance ID for the given user.
+    ///
+    /// # Arguments
+    /// * `flow_node_instance_id` - The ID of the FlowNodeInstance of the UserTask to reserve.
+    /// * `actual_owner_id` - The ID of the user who will be working on the UserTask.
+    pub async fn reserve_user_task(
+        &self,
+        flow_node_instance_id: &str,
+        actual_owner_id: &str,
+    ) -> Result<(), EngineError> {
+        let url = format!("{}/{}/reserve", self.user_tasks_url, flow_node_instance_id);
+        let request_json = serde_json::json!({ "actualOwnerId": actual_owner_id });
+
+        self.api_client.put::<()>(&url, Some(&request_json)).await
+    }
+
+    /// Cancels the reservation of the UserTask with the given FlowNodeInstance ID.
+    pub async fn cancel_user_task_reservation(
+        &self,
+        flow_node_instance_id: &str,
+    ) -> Result<(), EngineError> {
+        let url = format!(
+            "{}/{}/cancel-reservation",
+            self.user_tasks_url, flow_node_instance_id
+        );
+
+        self.api_client.delete::<()>(&url).await
+    }
+
+    /// Finishes the UserTask with the given FlowNodeInstance ID.
+    ///
+    /// # Arguments
+    /// * `flow_node_instance_id` - The ID of the FlowNodeInstance of the UserTask to finish.
+    /// * `result` - The values of the UserTask's form fields.
+    pub async fn finish_user_task(
+        &self,
+        flow_node_instance_id: &str,
+        result: UserTaskResult,
+    ) -> Result<(), EngineError> {
+        let url = format!("{}/{}/finish", self.user_tasks_url, flow_node_instance_id);
+        let request_json = serde_json::to_value(result).expect("Failed to serialize request");
+
+        self.api_client.put::<()>(&url, Some(&request_json)).await
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    bpmn::{error::BpmnError, validator::parse_and_validate},
+    clients::{
+        pagination::collect_all,
+        process_definition::{
+            process_definition::{PersistProcessDefinitionPayload, ProcessDefinition},
+            process_definition_client::ProcessDefinitionClient,
+        },
+    },
+};
+
+use super::error::DeploymentError;
+
+/// The file extension of the BPMN 2.0 documents to deploy.
+const BPMN_FILE_EXTENSION: &str = "bpmn";
+
+/// Describes what a deployment does with a single ProcessDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeploymentAction {
+    /// The ProcessDefinition is not deployed yet.
+    Create,
+    /// The ProcessDefinition is deployed with a different XML.
+    Update,
+    /// The ProcessDefinition is deployed with the same XML.
+    Unchanged,
+}
+
+/// A ProcessDefinition read from a BPMN 2.0 document and what a deployment does with it.
+#[derive(Debug, Clone)]
+pub struct PlannedDefinition {
+    pub path: PathBuf,
+    pub process_definition_id: String,
+    pub action: DeploymentAction,
+    pub xml: String,
+}
+
+/// The ProcessDefinitions found in a directory and what a deployment does with them.
+#[derive(Debug, Clone, Default)]
+pub struct DeploymentPlan {
+    pub definitions: Vec<PlannedDefinition>,
+}
+
+/// The IDs of the ProcessDefinitions a deployment created, updated or left unchanged.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeploymentSummary {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+/// Deploys directories of BPMN 2.0 documents, uploading only new or changed ProcessDefinitions.
+///
+/// A ProcessDefinition counts as changed unless the deployed XML is exactly the same as the document's.
+/// The `hash` the ProcessCube® Engine reports is not used, since how it is computed is not known.
+/// If the engine stores the XML in another form than it was uploaded, e.g. with other whitespace,
+/// the ProcessDefinition is uploaded again on every deployment.
+#[derive(Clone)]
+pub struct Deployer {
+    process_definition_client: ProcessDefinitionClient,
+}
+
+impl Deployer {
+    /// Creates a new instance of the Deployer.
+    ///
+    /// # Arguments
+    /// * `process_definition_client` - The client to read and upload ProcessDefinitions with.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::{clients::client_factory::ClientFactory, deployment::deployer::Deployer};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let deployer = Deployer::new(client_factory.create_process_definition_client());
+    ///     // Deploy all BPMN files in the `processes` directory
+    ///     let plan = deployer.plan("processes").await?;
+    ///     println!("{}", plan);
+    ///     let summary = deployer.deploy(&plan).await?;
+    ///     println!("{}", summary);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(process_definition_client: ProcessDefinitionClient) -> Deployer {
+        Deployer {
+            process_definition_client,
+        }
+    }
+
+    /// Reads all BPMN 2.0 documents in the given directory and its subdirectories
+    /// and compares them with the ProcessDefinitions deployed to the ProcessCube® Engine.
+    /// Symbolic links to documents are followed, those to directories are not.
+    ///
+    /// Nothing is uploaded. All documents are validated, so an invalid document fails the plan
+    /// before any ProcessDefinition is changed.
+    pub async fn plan(
+        &self,
+        directory: impl AsRef<Path>,
+    ) -> Result<DeploymentPlan, DeploymentError> {
+        let deployed = collect_all(
+            self.process_definition_client
+                .get_process_definitions_stream(None),
+        )
+        .await?;
+
+        plan_against(directory.as_ref(), &deployed)
+    }
+
+    /// Uploads the new and changed ProcessDefinitions of the given plan.
+    ///
+    /// Stops at the first ProcessDefinition the ProcessCube® Engine rejects. The error is then a
+    /// `DeploymentError::PartialDeployment` holding the summary of what was deployed until then.
+    pub async fn deploy(
+        &self,
+        plan: &DeploymentPlan,
+    ) -> Result<DeploymentSummary, DeploymentError> {
+        let mut summary = DeploymentSummary::default();
+
+        for definition in &plan.definitions {
+            let id = definition.process_definition_id.clone();
+            if definition.action == DeploymentAction::Unchanged {
+                summary.unchanged.push(id);
+                continue;
+            }
+
+            let request = PersistProcessDefinitionPayload {
+                xml: definition.xml.clone(),
+                overwrite_existing: definition.action == DeploymentAction::Update,
+            };
+            if let Err(source) = self
+                .process_definition_client
+                .upload_process_definition(request)
+                .await
+            {
+                return Err(DeploymentError::PartialDeployment {
+                    summary,
+                    source: Box::new(DeploymentError::Engine {
+                        id: Some(id),
+                        source: Box::new(source),
+                    }),
+                });
+            }
+
+            match definition.action {
+                DeploymentAction::Create => summary.created.push(id),
+                _ => summary.updated.push(id),
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Plans and deploys the given directory in one step.
+    pub async fn deploy_directory(
+        &self,
+        directory: impl AsRef<Path>,
+    ) -> Result<DeploymentSummary, DeploymentError> {
+        let plan = self.plan(directory).await?;
+        self.deploy(&plan).await
+    }
+}
+
+impl DeploymentPlan {
+    /// Returns whether deploying the plan uploads any ProcessDefinition.
+    pub fn has_changes(&self) -> bool {
+        self.definitions
+            .iter()
+            .any(|definition| definition.action != DeploymentAction::Unchanged)
+    }
+}
+
+impl fmt::Display for DeploymentAction {
+    /// Formats a DeploymentAction.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeploymentAction::Create => f.pad("create"),
+            DeploymentAction::Update => f.pad("update"),
+            DeploymentAction::Unchanged => f.pad("unchanged"),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentPlan {
+    /// Formats a DeploymentPlan as one line per ProcessDefinition.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.definitions.is_empty() {
+            return write!(f, "No process definitions found.");
+        }
+
+        let lines: Vec<String> = self
+            .definitions
+            .iter()
+            .map(|definition| {
+                format!(
+                    "{:<9} {} ({})",
+                    definition.action,
+                    definition.process_definition_id,
+                    definition.path.display()
+                )
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl fmt::Display for DeploymentSummary {
+    /// Formats a DeploymentSummary.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Created: {}, updated: {}, unchanged: {}",
+            self.created.len(),
+            self.updated.len(),
+            self.unchanged.len()
+        )
+    }
+}
+
+/// Reads all BPMN 2.0 documents in the given directory and compares them with the given ProcessDefinitions.
+pub(crate) fn plan_against(
+    directory: &Path,
+    deployed: &[ProcessDefinition],
+) -> Result<DeploymentPlan, DeploymentError> {
+    let mut paths = Vec::new();
+    find_bpmn_files(directory, &mut paths)?;
+
+    let deployed: HashMap<&str, &ProcessDefinition> = deployed
+        .iter()
+        .map(|process_definition| (process_definition.id.as_str(), process_definition))
+        .collect();
+
+    let mut definitions: Vec<PlannedDefinition> = Vec::new();
+    for path in paths {
+        let xml = std::fs::read_to_string(&path).map_err(|source| DeploymentError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        let process_definition_id = match parse_and_validate(&xml) {
+            Ok(parsed) if parsed.id.is_empty() => {
+                return Err(DeploymentError::InvalidDefinition {
+                    path,
+                    source: BpmnError::InvalidDocument("The definitions have no ID.".to_string()),
+                })
+            }
+            Ok(parsed) => parsed.id,
+            Err(source) => return Err(DeploymentError::InvalidDefinition { path, source }),
+        };
+
+        if let Some(other) = definitions
+            .iter()
+            .find(|definition| definition.process_definition_id == process_definition_id)
+        {
+            return Err(DeploymentError::DuplicateDefinition {
+                id: process_definition_id,
+                paths: vec![other.path.clone(), path],
+            });
+        }
+
+        let action = match deployed.get(process_definition_id.as_str()) {
+            None => DeploymentAction::Create,
+            Some(deployed) if deployed.xml == xml => DeploymentAction::Unchanged,
+            Some(_) => DeploymentAction::Update,
+        };
+
+        definitions.push(PlannedDefinition {
+            path,
+            process_definition_id,
+            action,
+            xml,
+        });
+    }
+
+    Ok(DeploymentPlan { definitions })
+}
+
+/// Collects the paths of all BPMN 2.0 documents in the given directory, sorted by path.
+///
+/// Symbolic links to directories are skipped, so a link pointing to one of its parents can't cause endless recursion.
+fn find_bpmn_files(directory: &Path, paths: &mut Vec<PathBuf>) -> Result<(), DeploymentError> {
+    let io_error = |source| DeploymentError::Io {
+        path: directory.to_path_buf(),
+        source,
+    };
+
+    let mut entries = std::fs::read_dir(directory)
+        .map_err(io_error)?
+        .map(|entry| entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error)?;
+    entries.sort_by(|(path, _), (other_path, _)| path.cmp(other_path));
+
+    for (path, file_type) in entries {
+        if file_type.is_dir() {
+            find_bpmn_files(&path, paths)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == BPMN_FILE_EXTENSION)
+            && path.is_file()
+        {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
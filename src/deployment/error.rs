@@ -0,0 +1,114 @@
+use std::{fmt, path::PathBuf};
+
+use crate::{bpmn::error::BpmnError, clients::error::EngineError};
+
+use super::{deployer::DeploymentSummary, synchronizer::SyncSummary};
+
+/// Represents an error that occurred while deploying a directory of BPMN 2.0 documents.
+#[derive(Debug)]
+pub enum DeploymentError {
+    /// A file or directory could not be read.
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A BPMN 2.0 document is malformed or cannot be executed.
+    InvalidDefinition { path: PathBuf, source: BpmnError },
+    /// Several BPMN 2.0 documents declare the same ProcessDefinition ID.
+    DuplicateDefinition { id: String, paths: Vec<PathBuf> },
+    /// The manifest is malformed or contradicts itself.
+    InvalidManifest(String),
+    /// The manifest refers to a ProcessModel that is neither deployed nor about to be deployed.
+    UnknownProcessModel(String),
+    /// A request to the ProcessCube® Engine failed.
+    Engine {
+        /// The ID of the ProcessDefinition or ProcessModel the request was about.
+        id: Option<String>,
+        source: Box<EngineError>,
+    },
+    /// A deployment stopped partway. The ProcessDefinitions in `summary` were handled before `source` occurred.
+    PartialDeployment {
+        summary: DeploymentSummary,
+        source: Box<DeploymentError>,
+    },
+    /// A sync stopped partway. The changes in `summary` were made before `source` occurred.
+    PartialSync {
+        summary: Box<SyncSummary>,
+        source: Box<DeploymentError>,
+    },
+}
+
+impl fmt::Display for DeploymentError {
+    /// Formats a DeploymentError.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeploymentError::Io { path, source } => {
+                write!(f, "Error reading `{}`: {}", path.display(), source)
+            }
+            DeploymentError::InvalidDefinition { path, source } => {
+                write!(
+                    f,
+                    "Invalid process definition `{}`: {}",
+                    path.display(),
+                    source
+                )
+            }
+            DeploymentError::DuplicateDefinition { id, paths } => {
+                let paths: Vec<String> = paths
+                    .iter()
+                    .map(|path| format!("`{}`", path.display()))
+                    .collect();
+                write!(
+                    f,
+                    "Process definition `{}` is declared in {}.",
+                    id,
+                    paths.join(" and ")
+                )
+            }
+            DeploymentError::InvalidManifest(message) => write!(f, "Invalid manifest: {}", message),
+            DeploymentError::UnknownProcessModel(id) => write!(
+                f,
+                "Process model `{}` is neither deployed nor part of the deployment.",
+                id
+            ),
+            DeploymentError::Engine {
+                id: Some(id),
+                source,
+            } => write!(f, "Error updating `{}`: {}", id, source),
+            DeploymentError::Engine { id: None, source } => {
+                write!(
+                    f,
+                    "Error reading the deployed process definitions: {}",
+                    source
+                )
+            }
+            DeploymentError::PartialDeployment { source, .. }
+            | DeploymentError::PartialSync { source, .. } => write!(f, "{}", source),
+        }
+    }
+}
+
+impl std::error::Error for DeploymentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeploymentError::Io { source, .. } => Some(source),
+            DeploymentError::InvalidDefinition { source, .. } => Some(source),
+            DeploymentError::DuplicateDefinition { .. }
+            | DeploymentError::InvalidManifest(_)
+            | DeploymentError::UnknownProcessModel(_) => None,
+            DeploymentError::Engine { source, .. } => Some(source.as_ref()),
+            DeploymentError::PartialDeployment { source, .. }
+            | DeploymentError::PartialSync { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+impl From<EngineError> for DeploymentError {
+    /// Converts an EngineError that is not tied to a single ProcessDefinition into a DeploymentError.
+    fn from(err: EngineError) -> Self {
+        DeploymentError::Engine {
+            id: None,
+            source: Box::new(err),
+        }
+    }
+}
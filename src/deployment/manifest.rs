@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::error::DeploymentError;
+
+/// Describes the state the ProcessCube® Engine should be in after a sync.
+///
+/// # Example
+/// ```json
+/// {
+///   "directory": "processes",
+///   "enabledProcessModels": ["Order_Process"],
+///   "disabledProcessModels": ["Legacy_Order_Process"],
+///   "removedProcessDefinitions": ["Definitions_Obsolete"],
+///   "deleteAllRelatedData": false
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncManifest {
+    /// The directory containing the BPMN files of the ProcessDefinitions to deploy.
+    #[serde(default)]
+    pub directory: Option<PathBuf>,
+    /// The IDs of the ProcessModels that should be enabled.
+    #[serde(default)]
+    pub enabled_process_models: Vec<String>,
+    /// The IDs of the ProcessModels that should be disabled.
+    #[serde(default)]
+    pub disabled_process_models: Vec<String>,
+    /// The IDs of the ProcessDefinitions that should be removed from the engine.
+    #[serde(default)]
+    pub removed_process_definitions: Vec<String>,
+    /// Whether to delete the ProcessInstances and other data of removed ProcessDefinitions, too.
+    #[serde(default)]
+    pub delete_all_related_data: bool,
+}
+
+impl SyncManifest {
+    /// Reads a manifest from the JSON file at the given path.
+    ///
+    /// A relative `directory` is resolved against the directory containing the manifest.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<SyncManifest, DeploymentError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|source| DeploymentError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut manifest: SyncManifest = serde_json::from_str(&content).map_err(|e| {
+            DeploymentError::InvalidManifest(format!("`{}`: {}", path.display(), e))
+        })?;
+
+        if let (Some(directory), Some(parent)) = (&manifest.directory, path.parent()) {
+            if directory.is_relative() {
+                manifest.directory = Some(parent.join(directory));
+            }
+        }
+
+        Ok(manifest)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod deployer;
+pub mod error;
+pub mod manifest;
+pub mod synchronizer;
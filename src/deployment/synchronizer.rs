@@ -0,0 +1,318 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    bpmn::converter::process_models_from_xml,
+    clients::{
+        error::EngineError, pagination::collect_all,
+        process_definition::process_definition_client::ProcessDefinitionClient,
+        process_model::process_model_client::ProcessModelClient,
+    },
+};
+
+use super::{
+    deployer::{plan_against, Deployer, DeploymentAction, DeploymentPlan, DeploymentSummary},
+    error::DeploymentError,
+    manifest::SyncManifest,
+};
+
+/// The changes that make the ProcessCube® Engine match a manifest.
+#[derive(Debug, Clone, Default)]
+pub struct SyncPlan {
+    pub deployment: DeploymentPlan,
+    pub enable: Vec<String>,
+    pub disable: Vec<String>,
+    pub delete: Vec<String>,
+    pub delete_all_related_data: bool,
+}
+
+/// The changes a sync made to the ProcessCube® Engine.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncSummary {
+    pub deployment: DeploymentSummary,
+    pub enabled: Vec<String>,
+    pub disabled: Vec<String>,
+    pub deleted: Vec<String>,
+}
+
+/// Makes the ProcessDefinitions and ProcessModels of the ProcessCube® Engine match a manifest.
+#[derive(Clone)]
+pub struct Synchronizer {
+    deployer: Deployer,
+    process_definition_client: ProcessDefinitionClient,
+    process_model_client: ProcessModelClient,
+}
+
+impl Synchronizer {
+    /// Creates a new instance of the Synchronizer.
+    ///
+    /// # Arguments
+    /// * `process_definition_client` - The client to read, upload and delete ProcessDefinitions with.
+    /// * `process_model_client` - The client to enable and disable ProcessModels with.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::{
+    ///     clients::client_factory::ClientFactory,
+    ///     deployment::{manifest::SyncManifest, synchronizer::Synchronizer},
+    /// };
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let synchronizer = Synchronizer::new(
+    ///         client_factory.create_process_definition_client(),
+    ///         client_factory.create_process_model_client(),
+    ///     );
+    ///     let manifest = SyncManifest {
+    ///         removed_process_definitions: vec!["Definitions_Obsolete".to_string()],
+    ///         ..Default::default()
+    ///     };
+    ///     // Show the changes before applying them
+    ///     let plan = synchronizer.plan(&manifest).await?;
+    ///     println!("{}", plan);
+    ///     let summary = synchronizer.apply(&plan).await?;
+    ///     println!("{}", summary);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(
+        process_definition_client: ProcessDefinitionClient,
+        process_model_client: ProcessModelClient,
+    ) -> Synchronizer {
+        Synchronizer {
+            deployer: Deployer::new(process_definition_client.clone()),
+            process_definition_client,
+            process_model_client,
+        }
+    }
+
+    /// Compares the manifest with the state of the ProcessCube® Engine and returns the changes
+    /// needed to make them match. Nothing is changed on the engine.
+    ///
+    /// Whether a ProcessModel is enabled is read from its `is_executable` flag. For ProcessDefinitions
+    /// that are about to be created or updated, the flag is read from their BPMN files instead.
+    pub async fn plan(&self, manifest: &SyncManifest) -> Result<SyncPlan, DeploymentError> {
+        if let Some(id) = manifest
+            .enabled_process_models
+            .iter()
+            .find(|id| manifest.disabled_process_models.contains(id))
+        {
+            return Err(DeploymentError::InvalidManifest(format!(
+                "Process model `{}` is both enabled and disabled.",
+                id
+            )));
+        }
+
+        let deployed = collect_all(
+            self.process_definition_client
+                .get_process_definitions_stream(None),
+        )
+        .await?;
+
+        let deployment = match &manifest.directory {
+            Some(directory) => plan_against(directory, &deployed)?,
+            None => DeploymentPlan::default(),
+        };
+
+        if let Some(definition) = deployment.definitions.iter().find(|definition| {
+            manifest
+                .removed_process_definitions
+                .contains(&definition.process_definition_id)
+        }) {
+            return Err(DeploymentError::InvalidManifest(format!(
+                "Process definition `{}` is both deployed from `{}` and removed.",
+                definition.process_definition_id,
+                definition.path.display()
+            )));
+        }
+
+        // Whether each ProcessModel is enabled once the deployment is done
+        let mut enabled_states: HashMap<String, bool> = deployed
+            .iter()
+            .filter(|definition| {
+                !manifest
+                    .removed_process_definitions
+                    .contains(&definition.id)
+            })
+            .flat_map(|definition| &definition.process_models)
+            .map(|process_model| (process_model.id.clone(), process_model.is_executable))
+            .collect();
+        for definition in &deployment.definitions {
+            if definition.action == DeploymentAction::Unchanged {
+                continue;
+            }
+            let process_models = process_models_from_xml(&definition.xml).map_err(|source| {
+                DeploymentError::InvalidDefinition {
+                    path: definition.path.clone(),
+                    source,
+                }
+            })?;
+            for process_model in process_models {
+                enabled_states.insert(process_model.id, process_model.is_executable);
+            }
+        }
+
+        let mut enable = Vec::new();
+        for id in &manifest.enabled_process_models {
+            match enabled_states.get(id) {
+                None => return Err(DeploymentError::UnknownProcessModel(id.clone())),
+                Some(false) => enable.push(id.clone()),
+                Some(true) => {}
+            }
+        }
+        let mut disable = Vec::new();
+        for id in &manifest.disabled_process_models {
+            match enabled_states.get(id) {
+                None => return Err(DeploymentError::UnknownProcessModel(id.clone())),
+                Some(true) => disable.push(id.clone()),
+                Some(false) => {}
+            }
+        }
+
+        let delete = manifest
+            .removed_process_definitions
+            .iter()
+            .filter(|id| deployed.iter().any(|definition| definition.id == **id))
+            .cloned()
+            .collect();
+
+        Ok(SyncPlan {
+            deployment,
+            enable,
+            disable,
+            delete,
+            delete_all_related_data: manifest.delete_all_related_data,
+        })
+    }
+
+    /// Applies the given plan: uploads ProcessDefinitions first, then enables and disables
+    /// ProcessModels and finally removes ProcessDefinitions.
+    ///
+    /// Stops at the first request the ProcessCube® Engine rejects. The error is then a
+    /// `DeploymentError::PartialSync` holding the summary of the changes made until then.
+    pub async fn apply(&self, plan: &SyncPlan) -> Result<SyncSummary, DeploymentError> {
+        let mut summary = SyncSummary::default();
+        match self.apply_into(plan, &mut summary).await {
+            Ok(()) => Ok(summary),
+            Err(source) => Err(DeploymentError::PartialSync {
+                summary: Box::new(summary),
+                source: Box::new(source),
+            }),
+        }
+    }
+
+    /// Applies the given plan, recording each change in the given summary as soon as it is made.
+    async fn apply_into(
+        &self,
+        plan: &SyncPlan,
+        summary: &mut SyncSummary,
+    ) -> Result<(), DeploymentError> {
+        summary.deployment = match self.deployer.deploy(&plan.deployment).await {
+            Ok(deployment) => deployment,
+            Err(DeploymentError::PartialDeployment {
+                summary: deployment,
+                source,
+            }) => {
+                summary.deployment = deployment;
+                return Err(*source);
+            }
+            Err(err) => return Err(err),
+        };
+
+        for id in &plan.enable {
+            self.process_model_client
+                .enable_process_model_by_id(id)
+                .await
+                .map_err(|source| engine_error(id, source))?;
+            summary.enabled.push(id.clone());
+        }
+        for id in &plan.disable {
+            self.process_model_client
+                .disable_process_model_by_id(id)
+                .await
+                .map_err(|source| engine_error(id, source))?;
+            summary.disabled.push(id.clone());
+        }
+        for id in &plan.delete {
+            self.process_definition_client
+                .delete_process_definition_by_id(id, Some(plan.delete_all_related_data))
+                .await
+                .map_err(|source| engine_error(id, source))?;
+            summary.deleted.push(id.clone());
+        }
+
+        Ok(())
+    }
+}
+
+impl SyncPlan {
+    /// Returns whether applying the plan changes anything on the ProcessCube® Engine.
+    pub fn has_changes(&self) -> bool {
+        self.deployment.has_changes()
+            || !self.enable.is_empty()
+            || !self.disable.is_empty()
+            || !self.delete.is_empty()
+    }
+}
+
+impl fmt::Display for SyncPlan {
+    /// Formats a SyncPlan as a diff with one line per change.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.has_changes() {
+            return write!(f, "No changes.");
+        }
+
+        let mut lines = Vec::new();
+        for definition in &self.deployment.definitions {
+            let marker = match definition.action {
+                DeploymentAction::Create => '+',
+                DeploymentAction::Update => '~',
+                DeploymentAction::Unchanged => continue,
+            };
+            lines.push(format!(
+                "{} {:<9} {} ({})",
+                marker,
+                definition.action,
+                definition.process_definition_id,
+                definition.path.display()
+            ));
+        }
+        for id in &self.enable {
+            lines.push(format!("~ {:<9} {}", "enable", id));
+        }
+        for id in &self.disable {
+            lines.push(format!("~ {:<9} {}", "disable", id));
+        }
+        for id in &self.delete {
+            match self.delete_all_related_data {
+                true => lines.push(format!("- {:<9} {} (with all related data)", "delete", id)),
+                false => lines.push(format!("- {:<9} {}", "delete", id)),
+            }
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl fmt::Display for SyncSummary {
+    /// Formats a SyncSummary.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, enabled: {}, disabled: {}, deleted: {}",
+            self.deployment,
+            self.enabled.len(),
+            self.disabled.len(),
+            self.deleted.len()
+        )
+    }
+}
+
+fn engine_error(id: &str, source: EngineError) -> DeploymentError {
+    DeploymentError::Engine {
+        id: Some(id.to_string()),
+        source: Box::new(source),
+    }
+}
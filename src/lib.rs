@@ -1,2 +1,9 @@
+#![allow(clippy::module_inception)]
+
+pub mod bpmn;
 pub mod cli;
 pub mod clients;
+pub mod deployment;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod worker;
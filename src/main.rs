@@ -2,7 +2,7 @@ use clap::Parser;
 use processcube_engine_client::cli::client::{register_commands, Cli};
 
 #[tokio::main]
-async fn main() -> () {
+async fn main() {
     let cli = Cli::parse();
     register_commands(cli).await;
 }
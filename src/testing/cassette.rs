@@ -0,0 +1,307 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode, Url,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::clients::{
+    api::transport::{Transport, TransportRequest, TransportResponse},
+    error::EngineError,
+};
+
+/// The value recorded instead of the value of a redacted header.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// The requests sent to the ProcessCube® Engine and the responses received, in the order they were sent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+/// A request and the response it was answered with.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedRequest {
+    pub method: String,
+    /// The path and query of the URL, so cassettes can be replayed against any engine URL.
+    pub path: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The body, if it is a JSON document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json: Option<Value>,
+    /// The body, if it is not empty and not a JSON document, e.g. the HTML page of a reverse proxy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl Cassette {
+    /// Reads a cassette from the JSON file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Cassette> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Writes the cassette as JSON to the file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        std::fs::write(path, content)
+    }
+}
+
+/// A Transport that sends requests with another Transport and records them in a Cassette.
+///
+/// The `Authorization` header is recorded as `[REDACTED]`, so cassettes can be committed.
+/// Clones share the recorded interactions, so a clone can be passed to the ApiClient and the
+/// original saved afterwards.
+///
+/// # Example
+/// ```no_run
+/// use processcube_engine_client::{
+///     clients::{api::transport::ReqwestTransport, client_factory::ClientFactory, error::EngineError},
+///     testing::cassette::RecordingTransport,
+/// };
+/// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+/// const ENGINE_URL: &str = "http://localhost:10560";
+/// // Be sure to have a running ProcessCube® Engine at the given URL
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let recorder = RecordingTransport::new(ReqwestTransport::default());
+///     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(recorder.clone());
+///     client_factory
+///         .create_process_definition_client()
+///         .get_process_definitions(None, None)
+///         .await?;
+///     recorder.save("tests/cassettes/process_definitions.json")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    redacted_headers: Vec<HeaderName>,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl RecordingTransport {
+    /// Creates a new instance of the RecordingTransport.
+    ///
+    /// # Arguments
+    /// * `inner` - The Transport actually sending the requests.
+    pub fn new<T>(inner: T) -> RecordingTransport
+    where
+        T: Transport + 'static,
+    {
+        RecordingTransport {
+            inner: Arc::new(inner),
+            redacted_headers: vec![reqwest::header::AUTHORIZATION],
+            interactions: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns the RecordingTransport redacting the given request and response header, too.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the header, e.g. `Cookie`.
+    pub fn with_redacted_header(mut self, name: HeaderName) -> RecordingTransport {
+        self.redacted_headers.push(name);
+        self
+    }
+
+    /// Returns a Cassette with the interactions recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        Cassette {
+            interactions: lock(&self.interactions).clone(),
+        }
+    }
+
+    /// Writes the interactions recorded so far as a Cassette to the file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.cassette().save(path)
+    }
+
+    fn record_headers(&self, headers: &HeaderMap) -> BTreeMap<String, String> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = match self.redacted_headers.contains(name) {
+                    true => REDACTED.to_string(),
+                    false => String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                };
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Transport for RecordingTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, EngineError> {
+        let recorded_request = RecordedRequest {
+            method: request.method.to_string(),
+            path: path_of(&request.url),
+            headers: self.record_headers(&request.headers),
+            body: request.body.as_deref().and_then(parse_json),
+        };
+
+        let response = self.inner.send(request).await?;
+
+        let (json, text) = match parse_json(&response.body) {
+            Some(json) => (Some(json), None),
+            None if response.body.is_empty() => (None, None),
+            None => (
+                None,
+                Some(String::from_utf8_lossy(&response.body).into_owned()),
+            ),
+        };
+        lock(&self.interactions).push(Interaction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status: response.status.as_u16(),
+                headers: self.record_headers(&response.headers),
+                json,
+                text,
+            },
+        });
+
+        Ok(response)
+    }
+}
+
+/// A Transport that answers requests with the responses recorded in a Cassette, without sending them.
+///
+/// A request is answered with the first unused interaction with the same method, path and body,
+/// so repeated requests are answered in the order they were recorded. Headers are not compared.
+pub struct ReplayingTransport {
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl ReplayingTransport {
+    /// Creates a new instance of the ReplayingTransport.
+    ///
+    /// # Arguments
+    /// * `cassette` - The Cassette with the interactions to answer requests with.
+    pub fn new(cassette: Cassette) -> ReplayingTransport {
+        ReplayingTransport {
+            interactions: Mutex::new(
+                cassette
+                    .interactions
+                    .into_iter()
+                    .map(|interaction| (interaction, false))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Creates a new instance of the ReplayingTransport with the Cassette at the given path.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<ReplayingTransport> {
+        Ok(ReplayingTransport::new(Cassette::load(path)?))
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayingTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, EngineError> {
+        let method = request.method.to_string();
+        let path = path_of(&request.url);
+        let body = request.body.as_deref().and_then(parse_json);
+
+        let mut interactions = lock(&self.interactions);
+        let (interaction, used) = interactions
+            .iter_mut()
+            .find(|(interaction, used)| {
+                !*used
+                    && interaction.request.method == method
+                    && interaction.request.path == path
+                    && interaction.request.body == body
+            })
+            .ok_or_else(|| {
+                EngineError::Configuration(format!(
+                    "No recorded interaction left for `{} {}`.",
+                    method, path
+                ))
+            })?;
+        *used = true;
+
+        to_response(&interaction.response)
+    }
+}
+
+fn to_response(response: &RecordedResponse) -> Result<TransportResponse, EngineError> {
+    let invalid_cassette =
+        |message: String| EngineError::Configuration(format!("Invalid cassette: {}", message));
+
+    let status = StatusCode::from_u16(response.status)
+        .map_err(|_| invalid_cassette(format!("Invalid status `{}`.", response.status)))?;
+
+    let mut headers = HeaderMap::new();
+    for (name, value) in &response.headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| invalid_cassette(format!("Invalid header name `{}`.", name)))?;
+        let header_value = HeaderValue::from_str(value)
+            .map_err(|_| invalid_cassette(format!("Invalid value for header `{}`.", name)))?;
+        headers.append(header_name, header_value);
+    }
+
+    let body = match (&response.json, &response.text) {
+        (Some(json), _) => serde_json::to_vec(json)?,
+        (None, Some(text)) => text.as_bytes().to_vec(),
+        (None, None) => Vec::new(),
+    };
+
+    Ok(TransportResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// Returns the path and query of the given URL.
+fn path_of(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+fn parse_json(body: &[u8]) -> Option<Value> {
+    serde_json::from_slice(body).ok()
+}
+
+/// Locks the interactions, even if a previous request panicked while holding them.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
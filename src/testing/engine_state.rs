@@ -0,0 +1,852 @@
+use std::{
+    collections::HashMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    bpmn::{converter::process_models_from_xml, validator::parse_and_validate},
+    clients::{
+        error::EngineErrorDetails,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+        process_instance::process_instance::ChangeProcessInstanceOwnerPayload,
+        process_model::process_model::{ProcessStartRequest, ReturnOn},
+    },
+};
+
+use super::fake_engine::{EventKind, TriggeredEvent};
+
+/// The query parameters that select a page instead of filtering.
+const PAGINATION_PARAMS: [&str; 2] = ["offset", "limit"];
+
+/// The fields a task shares with its FlowNodeInstance.
+const TASK_FIELDS: [&str; 14] = [
+    "flowNodeInstanceId",
+    "flowNodeId",
+    "flowNodeName",
+    "flowNodeLane",
+    "correlationId",
+    "processDefinitionId",
+    "processInstanceId",
+    "processModelId",
+    "ownerId",
+    "state",
+    "startToken",
+    "endToken",
+    "startedAt",
+    "finishedAt",
+];
+
+/// The body of a successful response, if any, or the error document of a failed one.
+pub(crate) type Reply = Result<Option<Value>, EngineErrorDetails>;
+
+/// The kinds of tasks a ProcessInstance of the FakeEngine waits at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TaskKind {
+    User,
+    Manual,
+    Untyped,
+}
+
+impl TaskKind {
+    /// Returns the kind of task of a flow node of a ProcessModel.
+    fn from_flow_node_type(flow_node_type: &str) -> Option<TaskKind> {
+        match flow_node_type {
+            "bpmn:UserTask" => Some(TaskKind::User),
+            "bpmn:ManualTask" => Some(TaskKind::Manual),
+            "bpmn:EmptyActivity" => Some(TaskKind::Untyped),
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the flow nodes and FlowNodeInstances of this kind.
+    fn flow_node_type(&self) -> &'static str {
+        match *self {
+            TaskKind::User => "bpmn:UserTask",
+            TaskKind::Manual => "bpmn:ManualTask",
+            TaskKind::Untyped => "bpmn:EmptyActivity",
+        }
+    }
+
+    fn list_key(&self) -> &'static str {
+        match *self {
+            TaskKind::User => "userTasks",
+            TaskKind::Manual => "manualTasks",
+            TaskKind::Untyped => "untypedTasks",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match *self {
+            TaskKind::User => "User task",
+            TaskKind::Manual => "Manual task",
+            TaskKind::Untyped => "Untyped task",
+        }
+    }
+}
+
+/// A ProcessDefinition deployed to the FakeEngine.
+struct StoredProcessDefinition {
+    id: String,
+    xml: String,
+    hash: String,
+    process_models: Vec<Value>,
+    deployed_at: String,
+    deployed_by_user_id: String,
+}
+
+/// The in-memory state of the FakeEngine.
+///
+/// Resources are kept as the JSON documents the ProcessCube® Engine responds with,
+/// so queries can filter them by the names of their fields.
+#[derive(Default)]
+pub(crate) struct EngineState {
+    process_definitions: Vec<StoredProcessDefinition>,
+    process_instances: Vec<Value>,
+    flow_node_instances: Vec<Value>,
+    /// The actual owners of reserved UserTasks by FlowNodeInstance ID.
+    reservations: HashMap<String, String>,
+    pub(crate) triggered_events: Vec<TriggeredEvent>,
+}
+
+impl EngineState {
+    pub(crate) fn process_definitions(&self, query: &HashMap<String, String>) -> Reply {
+        let process_definitions = self.process_definitions.iter().map(to_process_definition);
+        Ok(Some(list("processDefinitions", process_definitions, query)))
+    }
+
+    pub(crate) fn process_definition(&self, process_definition_id: &str) -> Reply {
+        self.process_definitions
+            .iter()
+            .find(|definition| definition.id == process_definition_id)
+            .map(|definition| Some(to_process_definition(definition)))
+            .ok_or_else(|| process_definition_not_found(process_definition_id))
+    }
+
+    pub(crate) fn upload_process_definition(&mut self, body: &[u8], user_id: &str) -> Reply {
+        let payload: PersistProcessDefinitionPayload = parse_body(body)?;
+
+        // The engine's XML parser rejects anything before the first tag with this message
+        if let Some(char) = payload
+            .xml
+            .trim_start()
+            .chars()
+            .next()
+            .filter(|c| *c != '<')
+        {
+            return Err(bad_request(format!(
+                "Error: Non-whitespace before first tag.\nLine: 0\nColumn: 1\nChar: {}",
+                char
+            )));
+        }
+        let process_definition_id = parse_and_validate(&payload.xml)
+            .map_err(|err| bad_request(err.to_string()))?
+            .id;
+        let process_models = process_models_from_xml(&payload.xml)
+            .map_err(|err| bad_request(err.to_string()))?
+            .iter()
+            .map(|process_model| serde_json::to_value(process_model).unwrap_or_default())
+            .collect();
+
+        let existing = self
+            .process_definitions
+            .iter()
+            .position(|definition| definition.id == process_definition_id);
+        if existing.is_some() && !payload.overwrite_existing {
+            return Err(EngineErrorDetails::new(
+                "ConflictError",
+                &format!(
+                    "Process definition with ID `{}` already exists.",
+                    process_definition_id
+                ),
+                409,
+            ));
+        }
+
+        let definition = StoredProcessDefinition {
+            id: process_definition_id,
+            hash: hash(&payload.xml),
+            xml: payload.xml,
+            process_models,
+            deployed_at: now(),
+            deployed_by_user_id: user_id.to_string(),
+        };
+        match existing {
+            Some(index) => self.process_definitions[index] = definition,
+            None => self.process_definitions.push(definition),
+        }
+
+        Ok(None)
+    }
+
+    pub(crate) fn delete_process_definition(
+        &mut self,
+        process_definition_id: &str,
+        query: &HashMap<String, String>,
+    ) -> Reply {
+        let index = self
+            .process_definitions
+            .iter()
+            .position(|definition| definition.id == process_definition_id)
+            .ok_or_else(|| process_definition_not_found(process_definition_id))?;
+        self.process_definitions.remove(index);
+
+        if query.get("deleteAllRelatedData").map(String::as_str) == Some("true") {
+            let is_unrelated =
+                |value: &Value| value["processDefinitionId"] != process_definition_id;
+            self.process_instances.retain(is_unrelated);
+            self.flow_node_instances.retain(is_unrelated);
+        }
+
+        Ok(None)
+    }
+
+    pub(crate) fn process_models(&self, query: &HashMap<String, String>) -> Reply {
+        let process_models = self
+            .process_definitions
+            .iter()
+            .flat_map(|definition| definition.process_models.iter().cloned());
+        Ok(Some(list("processModels", process_models, query)))
+    }
+
+    pub(crate) fn process_model(&self, process_model_id: &str) -> Reply {
+        let (_, process_model) = self.find_process_model(process_model_id)?;
+        Ok(Some(process_model.clone()))
+    }
+
+    pub(crate) fn process_definition_of_process_model(&self, process_model_id: &str) -> Reply {
+        let (definition, _) = self.find_process_model(process_model_id)?;
+        Ok(Some(to_process_definition(definition)))
+    }
+
+    pub(crate) fn set_process_model_executable(
+        &mut self,
+        process_model_id: &str,
+        is_executable: bool,
+    ) -> Reply {
+        let process_model = self
+            .process_definitions
+            .iter_mut()
+            .flat_map(|definition| definition.process_models.iter_mut())
+            .find(|process_model| process_model["processModelId"] == process_model_id)
+            .ok_or_else(|| process_model_not_found(process_model_id))?;
+        process_model["isExecutable"] = Value::Bool(is_executable);
+
+        Ok(None)
+    }
+
+    pub(crate) fn delete_process_model(&mut self, process_model_id: &str) -> Reply {
+        self.find_process_model(process_model_id)?;
+        for definition in &mut self.process_definitions {
+            definition
+                .process_models
+                .retain(|process_model| process_model["processModelId"] != process_model_id);
+        }
+
+        Ok(None)
+    }
+
+    /// Starts a ProcessInstance, which runs until it reaches the first task of its ProcessModel.
+    pub(crate) fn start_process_instance(
+        &mut self,
+        process_model_id: &str,
+        body: &[u8],
+        user_id: &str,
+    ) -> Reply {
+        let request: ProcessStartRequest = parse_body(body)?;
+        let (definition, process_model) = self.find_process_model(process_model_id)?;
+        let (process_definition_id, hash, process_model) = (
+            definition.id.clone(),
+            definition.hash.clone(),
+            process_model.clone(),
+        );
+
+        if process_model["isExecutable"] != true {
+            return Err(bad_request(format!(
+                "Process model `{}` is not executable.",
+                process_model_id
+            )));
+        }
+
+        let start_events = process_model["startEvents"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        let start_event = match &request.start_event_id {
+            Some(start_event_id) => start_events
+                .into_iter()
+                .find(|start_event| start_event["id"] == start_event_id.as_str())
+                .ok_or_else(|| {
+                    not_found(format!(
+                        "Start event with ID `{}` not found in process model `{}`.",
+                        start_event_id, process_model_id
+                    ))
+                })?,
+            None if start_events.len() == 1 => start_events.into_iter().next().unwrap(),
+            None => {
+                return Err(bad_request(format!(
+                    "Process model `{}` has {} start events, but no start event ID was given.",
+                    process_model_id,
+                    start_events.len()
+                )))
+            }
+        };
+
+        let process_instance_id = new_id();
+        let correlation_id = request.correlation_id.clone().unwrap_or_else(new_id);
+        let token = request.initial_token.clone().unwrap_or_else(|| json!({}));
+        let process_instance = json!({
+            "processInstanceId": process_instance_id,
+            "processDefinitionId": process_definition_id,
+            "processModelId": process_model_id,
+            "processModelName": process_model["processModelName"],
+            "correlationId": correlation_id,
+            "ownerId": user_id,
+            "state": "running",
+            "hash": hash,
+            "parentProcessInstanceId": null,
+            "startToken": token,
+            "endToken": null,
+            "error": null,
+            "metadata": {},
+            "terminatedByUserId": null,
+            "createdAt": now(),
+            "finishedAt": null,
+        });
+
+        let start_event_instance =
+            new_flow_node_instance(&process_instance, &start_event, "finished", &token);
+        self.process_instances.push(process_instance);
+        self.flow_node_instances.push(start_event_instance);
+        self.advance(&process_instance_id, None, token);
+
+        let mut response = json!({
+            "processInstanceId": process_instance_id,
+            "correlationId": correlation_id,
+        });
+        let process_instance = self.find_process_instance(&process_instance_id)?;
+        if request.return_on != ReturnOn::OnProcessInstanceStarted
+            && process_instance["state"] == "finished"
+        {
+            let end_event_instance = self.flow_node_instances.iter().rev().find(|instance| {
+                instance["processInstanceId"] == process_instance_id.as_str()
+                    && instance["flowNodeType"] == "bpmn:EndEvent"
+            });
+            if let Some(end_event_instance) = end_event_instance {
+                let reached = request.end_event_id.as_ref().is_none_or(|end_event_id| {
+                    end_event_instance["flowNodeId"] == end_event_id.as_str()
+                });
+                if reached {
+                    response["endEventId"] = end_event_instance["flowNodeId"].clone();
+                    response["tokenPayload"] = end_event_instance["endToken"].clone();
+                }
+            }
+        }
+
+        Ok(Some(response))
+    }
+
+    pub(crate) fn process_instances(&self, query: &HashMap<String, String>) -> Reply {
+        let process_instances = self.process_instances.iter().cloned();
+        Ok(Some(list("processInstances", process_instances, query)))
+    }
+
+    pub(crate) fn terminate_process_instance(
+        &mut self,
+        process_instance_id: &str,
+        user_id: &str,
+    ) -> Reply {
+        let process_instance = self
+            .process_instances
+            .iter_mut()
+            .find(|instance| instance["processInstanceId"] == process_instance_id)
+            .ok_or_else(|| process_instance_not_found(process_instance_id))?;
+        if process_instance["state"] != "running" {
+            return Ok(None);
+        }
+
+        process_instance["state"] = json!("terminated");
+        process_instance["terminatedByUserId"] = json!(user_id);
+        process_instance["finishedAt"] = json!(now());
+        for flow_node_instance in &mut self.flow_node_instances {
+            if flow_node_instance["processInstanceId"] == process_instance_id
+                && flow_node_instance["state"] == "suspended"
+            {
+                flow_node_instance["state"] = json!("terminated");
+                flow_node_instance["finishedAt"] = json!(now());
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub(crate) fn change_process_instance_owner(&mut self, body: &[u8]) -> Reply {
+        let payload: ChangeProcessInstanceOwnerPayload = parse_body(body)?;
+        let process_instance = self
+            .process_instances
+            .iter_mut()
+            .find(|instance| instance["processInstanceId"] == payload.process_instance_id.as_str())
+            .ok_or_else(|| process_instance_not_found(&payload.process_instance_id))?;
+        process_instance["ownerId"] = json!(payload.new_owner.user_id);
+
+        Ok(None)
+    }
+
+    pub(crate) fn correlations(&self, query: &HashMap<String, String>) -> Reply {
+        Ok(Some(list(
+            "correlations",
+            self.to_correlations().into_iter(),
+            query,
+        )))
+    }
+
+    pub(crate) fn correlation(&self, correlation_id: &str) -> Reply {
+        self.to_correlations()
+            .into_iter()
+            .find(|correlation| correlation["correlationId"] == correlation_id)
+            .map(Some)
+            .ok_or_else(|| {
+                not_found(format!(
+                    "Correlation with ID `{}` not found.",
+                    correlation_id
+                ))
+            })
+    }
+
+    pub(crate) fn flow_node_instances(&self, query: &HashMap<String, String>) -> Reply {
+        let flow_node_instances = self.flow_node_instances.iter().cloned();
+        Ok(Some(list("flowNodeInstances", flow_node_instances, query)))
+    }
+
+    pub(crate) fn tasks(&self, kind: TaskKind, query: &HashMap<String, String>) -> Reply {
+        let tasks = self
+            .flow_node_instances
+            .iter()
+            .filter(|instance| instance["flowNodeType"] == kind.flow_node_type())
+            .map(|instance| self.to_task(kind, instance));
+        Ok(Some(list(kind.list_key(), tasks, query)))
+    }
+
+    pub(crate) fn reserve_user_task(&mut self, flow_node_instance_id: &str, body: &[u8]) -> Reply {
+        let payload: Value = parse_body(body)?;
+        let actual_owner_id = payload["actualOwnerId"]
+            .as_str()
+            .ok_or_else(|| bad_request("Must provide an actualOwnerId.".to_string()))?;
+        self.find_waiting_task(TaskKind::User, flow_node_instance_id)?;
+        self.reservations.insert(
+            flow_node_instance_id.to_string(),
+            actual_owner_id.to_string(),
+        );
+
+        Ok(None)
+    }
+
+    pub(crate) fn cancel_user_task_reservation(&mut self, flow_node_instance_id: &str) -> Reply {
+        self.find_waiting_task(TaskKind::User, flow_node_instance_id)?;
+        self.reservations.remove(flow_node_instance_id);
+
+        Ok(None)
+    }
+
+    /// Finishes a waiting task and runs its ProcessInstance on to the next task.
+    ///
+    /// The result of a UserTask becomes the token; other tasks pass their start token on.
+    pub(crate) fn finish_task(
+        &mut self,
+        kind: TaskKind,
+        flow_node_instance_id: &str,
+        body: &[u8],
+    ) -> Reply {
+        let index = self.find_waiting_task(kind, flow_node_instance_id)?;
+        let token = match kind {
+            TaskKind::User => parse_body(body)?,
+            TaskKind::Manual | TaskKind::Untyped => {
+                self.flow_node_instances[index]["startToken"].clone()
+            }
+        };
+
+        let task_instance = &mut self.flow_node_instances[index];
+        task_instance["state"] = json!("finished");
+        task_instance["endToken"] = token.clone();
+        task_instance["finishedAt"] = json!(now());
+        let process_instance_id = task_instance["processInstanceId"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        let flow_node_id = task_instance["flowNodeId"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        self.reservations.remove(flow_node_instance_id);
+        self.advance(&process_instance_id, Some(&flow_node_id), token);
+
+        Ok(None)
+    }
+
+    pub(crate) fn trigger_event(
+        &mut self,
+        kind: EventKind,
+        name: &str,
+        query: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Reply {
+        let process_instance_id = query.get("processInstanceId").cloned();
+        if let Some(process_instance_id) = &process_instance_id {
+            self.find_process_instance(process_instance_id)?;
+        }
+        let payload = match body.is_empty() {
+            true => None,
+            false => parse_body::<Value>(body)?.get("payload").cloned(),
+        };
+
+        self.triggered_events.push(TriggeredEvent {
+            kind,
+            name: name.to_string(),
+            process_instance_id,
+            payload,
+        });
+
+        Ok(None)
+    }
+
+    fn find_process_model(
+        &self,
+        process_model_id: &str,
+    ) -> Result<(&StoredProcessDefinition, &Value), EngineErrorDetails> {
+        self.process_definitions
+            .iter()
+            .find_map(|definition| {
+                definition
+                    .process_models
+                    .iter()
+                    .find(|process_model| process_model["processModelId"] == process_model_id)
+                    .map(|process_model| (definition, process_model))
+            })
+            .ok_or_else(|| process_model_not_found(process_model_id))
+    }
+
+    fn find_process_instance(
+        &self,
+        process_instance_id: &str,
+    ) -> Result<&Value, EngineErrorDetails> {
+        self.process_instances
+            .iter()
+            .find(|instance| instance["processInstanceId"] == process_instance_id)
+            .ok_or_else(|| process_instance_not_found(process_instance_id))
+    }
+
+    /// Returns the index of the FlowNodeInstance of a task that waits to be finished.
+    fn find_waiting_task(
+        &self,
+        kind: TaskKind,
+        flow_node_instance_id: &str,
+    ) -> Result<usize, EngineErrorDetails> {
+        self.flow_node_instances
+            .iter()
+            .position(|instance| {
+                instance["flowNodeInstanceId"] == flow_node_instance_id
+                    && instance["flowNodeType"] == kind.flow_node_type()
+                    && instance["state"] == "suspended"
+            })
+            .ok_or_else(|| {
+                not_found(format!(
+                    "{} with ID `{}` not found.",
+                    kind.name(),
+                    flow_node_instance_id
+                ))
+            })
+    }
+
+    /// Moves a ProcessInstance on to the next task after the given flow node, in document order.
+    /// Without another task, the ProcessInstance finishes at the first End Event of its ProcessModel.
+    fn advance(
+        &mut self,
+        process_instance_id: &str,
+        after_flow_node_id: Option<&str>,
+        token: Value,
+    ) {
+        let Some(index) = self
+            .process_instances
+            .iter()
+            .position(|instance| instance["processInstanceId"] == process_instance_id)
+        else {
+            return;
+        };
+        let process_instance = self.process_instances[index].clone();
+        let process_model_id = process_instance["processModelId"]
+            .as_str()
+            .unwrap_or_default();
+        let (flow_nodes, end_event) = match self.find_process_model(process_model_id) {
+            Ok((_, process_model)) => (
+                process_model["flowNodes"]
+                    .as_array()
+                    .cloned()
+                    .unwrap_or_default(),
+                process_model["endEvents"].get(0).cloned(),
+            ),
+            Err(_) => (Vec::new(), None),
+        };
+
+        let start = after_flow_node_id
+            .and_then(|id| {
+                flow_nodes
+                    .iter()
+                    .position(|flow_node| flow_node["id"] == id)
+            })
+            .map_or(0, |position| position + 1);
+        let next_task = flow_nodes[start.min(flow_nodes.len())..]
+            .iter()
+            .find(|flow_node| {
+                TaskKind::from_flow_node_type(
+                    flow_node["flowNodeType"].as_str().unwrap_or_default(),
+                )
+                .is_some()
+            });
+
+        if let Some(task) = next_task {
+            let task_instance =
+                new_flow_node_instance(&process_instance, task, "suspended", &token);
+            self.flow_node_instances.push(task_instance);
+            return;
+        }
+
+        if let Some(end_event) = end_event {
+            let end_event_instance =
+                new_flow_node_instance(&process_instance, &end_event, "finished", &token);
+            self.flow_node_instances.push(end_event_instance);
+        }
+        let process_instance = &mut self.process_instances[index];
+        process_instance["state"] = json!("finished");
+        process_instance["endToken"] = token;
+        process_instance["finishedAt"] = json!(now());
+    }
+
+    /// Groups the ProcessInstances by their Correlation, in the order the Correlations were created.
+    fn to_correlations(&self) -> Vec<Value> {
+        let mut correlations: Vec<Value> = Vec::new();
+        for process_instance in &self.process_instances {
+            let correlation_id = &process_instance["correlationId"];
+            match correlations
+                .iter_mut()
+                .find(|correlation| correlation["correlationId"] == *correlation_id)
+            {
+                Some(correlation) => {
+                    if let Some(process_instances) = correlation["processInstances"].as_array_mut()
+                    {
+                        process_instances.push(process_instance.clone());
+                    }
+                }
+                None => correlations.push(json!({
+                    "correlationId": correlation_id,
+                    "metadata": {},
+                    "processInstances": [process_instance],
+                })),
+            }
+        }
+        correlations
+    }
+
+    fn to_task(&self, kind: TaskKind, flow_node_instance: &Value) -> Value {
+        let mut task: Map<String, Value> = TASK_FIELDS
+            .iter()
+            .map(|field| (field.to_string(), flow_node_instance[*field].clone()))
+            .collect();
+
+        let process_instance_id = flow_node_instance["processInstanceId"]
+            .as_str()
+            .unwrap_or_default();
+        let process_model_name = self
+            .find_process_instance(process_instance_id)
+            .map(|instance| instance["processModelName"].clone())
+            .unwrap_or_default();
+        task.insert("processModelName".to_string(), process_model_name);
+
+        if kind == TaskKind::User {
+            let flow_node_instance_id = flow_node_instance["flowNodeInstanceId"]
+                .as_str()
+                .unwrap_or_default();
+            task.insert(
+                "actualOwnerId".to_string(),
+                json!(self.reservations.get(flow_node_instance_id)),
+            );
+            task.insert("userTaskConfig".to_string(), json!({ "formFields": [] }));
+        }
+
+        Value::Object(task)
+    }
+}
+
+fn to_process_definition(definition: &StoredProcessDefinition) -> Value {
+    json!({
+        "processDefinitionId": definition.id,
+        "xml": definition.xml,
+        "hash": definition.hash,
+        "processModels": definition.process_models,
+        "deployedAt": definition.deployed_at,
+        "deployedByUserId": definition.deployed_by_user_id,
+    })
+}
+
+fn new_flow_node_instance(
+    process_instance: &Value,
+    flow_node: &Value,
+    state: &str,
+    token: &Value,
+) -> Value {
+    let flow_node_instance_id = new_id();
+    let finished = state == "finished";
+    let now = now();
+
+    json!({
+        "flowNodeInstanceId": flow_node_instance_id,
+        "flowNodeId": flow_node["id"],
+        "flowNodeName": flow_node["name"],
+        "flowNodeType": flow_node["flowNodeType"],
+        "flowNodeLane": null,
+        "eventType": null,
+        "correlationId": process_instance["correlationId"],
+        "processDefinitionId": process_instance["processDefinitionId"],
+        "processInstanceId": process_instance["processInstanceId"],
+        "processModelId": process_instance["processModelId"],
+        "ownerId": process_instance["ownerId"],
+        "parentProcessInstanceId": null,
+        "previousFlowNodeInstanceId": null,
+        "state": state,
+        "startToken": token,
+        "endToken": if finished { token.clone() } else { Value::Null },
+        "tokens": [{
+            "createdAt": now,
+            "flowNodeInstanceId": flow_node_instance_id,
+            "payload": token,
+        }],
+        "error": null,
+        "startedAt": now,
+        "finishedAt": if finished { json!(now) } else { Value::Null },
+    })
+}
+
+/// Returns a page of the values matching the query, together with the number of all matching values.
+fn list(key: &str, values: impl Iterator<Item = Value>, query: &HashMap<String, String>) -> Value {
+    let matching: Vec<Value> = values.filter(|value| matches_query(value, query)).collect();
+    let total_count = matching.len();
+    let offset = query
+        .get("offset")
+        .and_then(|offset| offset.parse().ok())
+        .unwrap_or(0);
+    let limit = query
+        .get("limit")
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(usize::MAX);
+    let page: Vec<Value> = matching.into_iter().skip(offset).take(limit).collect();
+
+    json!({ key: page, "totalCount": total_count })
+}
+
+/// Returns whether every query parameter, except those for pagination, equals the field of the same name.
+fn matches_query(value: &Value, query: &HashMap<String, String>) -> bool {
+    query
+        .iter()
+        .filter(|(key, _)| !PAGINATION_PARAMS.contains(&key.as_str()))
+        .all(|(key, expected)| match value.get(key) {
+            Some(Value::String(actual)) => actual == expected,
+            Some(Value::Null) | None => false,
+            Some(actual) => expected.parse::<Value>().ok().as_ref() == Some(actual),
+        })
+}
+
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, EngineErrorDetails> {
+    let body = match body.is_empty() {
+        true => b"{}".as_slice(),
+        false => body,
+    };
+    serde_json::from_slice(body)
+        .map_err(|err| bad_request(format!("Invalid request body: {}", err)))
+}
+
+pub(crate) fn bad_request(message: String) -> EngineErrorDetails {
+    EngineErrorDetails::new("BadRequestError", &message, 400)
+}
+
+fn not_found(message: String) -> EngineErrorDetails {
+    EngineErrorDetails::new("NotFoundError", &message, 404)
+}
+
+fn process_definition_not_found(process_definition_id: &str) -> EngineErrorDetails {
+    not_found(format!(
+        "Process definition with name `{}` not found.",
+        process_definition_id
+    ))
+}
+
+fn process_model_not_found(process_model_id: &str) -> EngineErrorDetails {
+    not_found(format!(
+        "Process model with ID `{}` not found.",
+        process_model_id
+    ))
+}
+
+fn process_instance_not_found(process_instance_id: &str) -> EngineErrorDetails {
+    not_found(format!(
+        "Process instance with ID `{}` not found.",
+        process_instance_id
+    ))
+}
+
+/// Returns a random ID in the format of a UUID v4, like the IDs the engine creates.
+fn new_id() -> String {
+    format!(
+        "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+        fastrand::u32(..),
+        fastrand::u16(..),
+        fastrand::u16(..0x1000),
+        fastrand::u16(0x8000..0xc000),
+        fastrand::u64(..1 << 48)
+    )
+}
+
+/// Returns the hash stored for an uploaded ProcessDefinition.
+///
+/// How the ProcessCube® Engine computes its hash is not known, so the hex encoded SHA-256 digest
+/// of the XML stands in for it. Nothing in this crate relies on its value.
+fn hash(xml: &str) -> String {
+    format!("{:x}", Sha256::digest(xml.as_bytes()))
+}
+
+/// Returns the current time as an ISO 8601 timestamp in UTC.
+fn now() -> String {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (days, seconds_of_day) = ((seconds / 86_400) as i64, seconds % 86_400);
+
+    // Converts days since 1970-01-01 into a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60,
+        seconds_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
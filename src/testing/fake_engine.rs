@@ -0,0 +1,239 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use serde_json::{json, Value};
+use wiremock::{matchers::any, Mock, MockServer, Request, Respond, ResponseTemplate};
+
+use crate::clients::{api::api_client::ENGINE_API_ENDPOINT, error::EngineErrorDetails};
+
+use super::engine_state::{bad_request, EngineState, Reply, TaskKind};
+
+/// The authority the FakeEngine reports, matching the default setup of the ProcessCube® Engine.
+const AUTHORITY_URL: &str = "http://localhost:11560/";
+
+/// The kind of an event triggered on the FakeEngine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Message,
+    Signal,
+}
+
+/// A message or signal that was triggered on the FakeEngine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggeredEvent {
+    pub kind: EventKind,
+    pub name: String,
+    /// The ProcessInstance the event was sent to, or `None` if it was sent to all of them.
+    pub process_instance_id: Option<String>,
+    pub payload: Option<Value>,
+}
+
+/// An in-process stand-in for the ProcessCube® Engine that keeps its state in memory.
+///
+/// It answers the requests of all clients of this crate, except those for ExternalTasks and
+/// retrying ProcessInstances, with the documents and errors the ProcessCube® Engine responds with.
+/// Tokens are accepted if they have the form `Bearer <token>`.
+///
+/// ProcessInstances are not executed. They run through the UserTasks, ManualTasks and UntypedTasks
+/// of their ProcessModel in document order, waiting at each until it is finished, and then finish
+/// at the first End Event with the last token. Triggered messages and signals are only recorded.
+///
+/// The ProcessModels of an uploaded ProcessDefinition are derived with the converter of the `bpmn`
+/// module and its hash is the SHA-256 digest of the XML. Neither is verified against the
+/// ProcessCube® Engine, so tests relying on their details should also run against a live engine.
+pub struct FakeEngine {
+    server: MockServer,
+    state: Arc<Mutex<EngineState>>,
+}
+
+impl FakeEngine {
+    /// Starts a FakeEngine without any ProcessDefinitions on a random local port.
+    ///
+    /// The FakeEngine stops when it is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use processcube_engine_client::{clients::{client_factory::ClientFactory, error::EngineError}, testing::fake_engine::FakeEngine};
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let engine = FakeEngine::start().await;
+    ///     let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    ///     let process_definitions = client_factory
+    ///         .create_process_definition_client()
+    ///         .get_process_definitions(None, None)
+    ///         .await?;
+    ///     assert_eq!(process_definitions.total_count, 0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start() -> FakeEngine {
+        let state = Arc::new(Mutex::new(EngineState::default()));
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(Router {
+                state: state.clone(),
+            })
+            .mount(&server)
+            .await;
+
+        FakeEngine { server, state }
+    }
+
+    /// Returns the URL to create clients for the FakeEngine with.
+    pub fn url(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Returns the messages and signals triggered so far, in the order they were triggered.
+    pub fn triggered_events(&self) -> Vec<TriggeredEvent> {
+        lock(&self.state).triggered_events.clone()
+    }
+}
+
+/// Answers the requests sent to the FakeEngine.
+struct Router {
+    state: Arc<Mutex<EngineState>>,
+}
+
+impl Respond for Router {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let method = request.method.as_str();
+        let path = request.url.path();
+        let Some(route) = path.strip_prefix(ENGINE_API_ENDPOINT) else {
+            return route_not_found(method, path);
+        };
+        let segments: Vec<&str> = route.split('/').skip(1).collect();
+        let query: HashMap<String, String> = request.url.query_pairs().into_owned().collect();
+        let body = request.body.as_slice();
+
+        // Both are readable without a token
+        match (method, segments.as_slice()) {
+            ("GET", ["info"]) => return to_response(Ok(Some(application_info()))),
+            ("GET", ["authority"]) => return to_response(Ok(Some(json!(AUTHORITY_URL)))),
+            _ => {}
+        }
+
+        let user_id = match authenticate(request) {
+            Ok(user_id) => user_id,
+            Err(details) => return to_response(Err(details)),
+        };
+
+        let mut state = lock(&self.state);
+        let reply = match (method, segments.as_slice()) {
+            ("GET", ["process_definitions"]) => state.process_definitions(&query),
+            ("POST", ["process_definitions"]) => state.upload_process_definition(body, &user_id),
+            ("GET", ["process_definitions", id]) => state.process_definition(id),
+            ("DELETE", ["process_definitions", id]) => state.delete_process_definition(id, &query),
+            ("GET", ["process_models"]) => state.process_models(&query),
+            ("GET", ["process_models", id]) => state.process_model(id),
+            ("DELETE", ["process_models", id]) => state.delete_process_model(id),
+            ("GET", ["process_models", id, "process_definition"]) => {
+                state.process_definition_of_process_model(id)
+            }
+            ("POST", ["process_models", id, "start"]) => {
+                state.start_process_instance(id, body, &user_id)
+            }
+            ("POST", ["process_models", id, "enable"]) => {
+                state.set_process_model_executable(id, true)
+            }
+            ("POST", ["process_models", id, "disable"]) => {
+                state.set_process_model_executable(id, false)
+            }
+            ("GET", ["process_instances", "query"]) => state.process_instances(&query),
+            ("POST", ["process_instances", "owner"]) => state.change_process_instance_owner(body),
+            ("PUT", ["process_instances", id, "terminate"]) => {
+                state.terminate_process_instance(id, &user_id)
+            }
+            ("GET", ["correlations"]) => state.correlations(&query),
+            ("GET", ["correlations", id]) => state.correlation(id),
+            ("GET", ["flow_node_instances"]) => state.flow_node_instances(&query),
+            ("GET", ["user_tasks"]) => state.tasks(TaskKind::User, &query),
+            ("PUT", ["user_tasks", id, "reserve"]) => state.reserve_user_task(id, body),
+            ("DELETE", ["user_tasks", id, "cancel-reservation"]) => {
+                state.cancel_user_task_reservation(id)
+            }
+            ("PUT", ["user_tasks", id, "finish"]) => state.finish_task(TaskKind::User, id, body),
+            ("GET", ["manual_tasks"]) => state.tasks(TaskKind::Manual, &query),
+            ("PUT", ["manual_tasks", id, "finish"]) => {
+                state.finish_task(TaskKind::Manual, id, body)
+            }
+            ("GET", ["untyped_tasks"]) => state.tasks(TaskKind::Untyped, &query),
+            ("PUT", ["untyped_tasks", id, "finish"]) => {
+                state.finish_task(TaskKind::Untyped, id, body)
+            }
+            ("POST", ["messages", name, "trigger"]) => {
+                state.trigger_event(EventKind::Message, name, &query, body)
+            }
+            ("POST", ["signals", name, "trigger"]) => {
+                state.trigger_event(EventKind::Signal, name, &query, body)
+            }
+            _ => return route_not_found(method, path),
+        };
+
+        to_response(reply)
+    }
+}
+
+/// Returns the ID of the user the request's token belongs to.
+fn authenticate(request: &Request) -> Result<String, EngineErrorDetails> {
+    let authorization = request
+        .headers
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok());
+
+    match authorization {
+        None => Err(EngineErrorDetails::new(
+            "UnauthorizedError",
+            "No auth token provided!",
+            401,
+        )),
+        Some(authorization) => match authorization.strip_prefix("Bearer ").map(str::trim) {
+            Some(token) if !token.is_empty() => Ok(token.to_string()),
+            _ => Err(bad_request(
+                "Must provide a token by which to create an identity!".to_string(),
+            )),
+        },
+    }
+}
+
+fn application_info() -> Value {
+    json!({
+        "id": "fake_engine",
+        "name": "5Minds Engine",
+        "packageName": "@5minds/processcube_engine",
+        "version": "16.0.0",
+        "authorityUrl": AUTHORITY_URL,
+        "allowAnonymousRootAccess": true,
+        "extraInfo": {
+            "portalUrl": "",
+            "startedIn": "fake_engine",
+        },
+    })
+}
+
+fn to_response(reply: Reply) -> ResponseTemplate {
+    match reply {
+        Ok(Some(body)) => ResponseTemplate::new(200).set_body_json(body),
+        Ok(None) => ResponseTemplate::new(204),
+        Err(details) => ResponseTemplate::new(details.code).set_body_json(&details),
+    }
+}
+
+/// Returns the page the engine's web framework responds with for unknown routes.
+fn route_not_found(method: &str, path: &str) -> ResponseTemplate {
+    let body = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Error</title>\n</head>\n<body>\n<pre>Cannot {} {}</pre>\n</body>\n</html>\n",
+        method, path
+    );
+
+    ResponseTemplate::new(404).set_body_raw(body, "text/html; charset=utf-8")
+}
+
+/// Locks the state, even if a previous request panicked while holding it.
+fn lock(state: &Mutex<EngineState>) -> MutexGuard<'_, EngineState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
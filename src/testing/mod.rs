@@ -0,0 +1,3 @@
+pub mod cassette;
+mod engine_state;
+pub mod fake_engine;
@@ -0,0 +1,72 @@
+use std::{fmt, future::Future};
+
+use async_trait::async_trait;
+
+use crate::clients::external_task::external_task::ExternalTask;
+
+/// Describes why an ExternalTask could not be finished.
+#[derive(Debug, Clone)]
+pub enum ExternalTaskError {
+    /// A business error, which can be caught by an error boundary event with the same code.
+    BpmnError { code: String, message: String },
+    /// A technical error, which puts the ProcessInstance into the error state.
+    ServiceError {
+        message: String,
+        details: Option<serde_json::Value>,
+    },
+}
+
+impl ExternalTaskError {
+    /// Creates a new BPMN error with the given code and message.
+    pub fn bpmn(code: &str, message: &str) -> ExternalTaskError {
+        ExternalTaskError::BpmnError {
+            code: code.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates a new service error with the given message.
+    pub fn service(message: &str) -> ExternalTaskError {
+        ExternalTaskError::ServiceError {
+            message: message.to_string(),
+            details: None,
+        }
+    }
+}
+
+impl fmt::Display for ExternalTaskError {
+    /// Formats an ExternalTaskError.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExternalTaskError::BpmnError { code, message } => {
+                write!(f, "BpmnError: code: {}, message: {}", code, message)
+            }
+            ExternalTaskError::ServiceError { message, .. } => {
+                write!(f, "ServiceError: message: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExternalTaskError {}
+
+/// Handles the ExternalTasks fetched by an ExternalTaskWorker.
+///
+/// The value returned on success is sent to the ProcessCube® Engine as the result of the ExternalTask.
+///
+/// Besides implementing this trait, any async closure taking an `ExternalTask` can be used as a handler.
+#[async_trait]
+pub trait ExternalTaskHandler: Send + Sync {
+    async fn handle(&self, task: &ExternalTask) -> Result<serde_json::Value, ExternalTaskError>;
+}
+
+#[async_trait]
+impl<F, Fut> ExternalTaskHandler for F
+where
+    F: Fn(ExternalTask) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<serde_json::Value, ExternalTaskError>> + Send,
+{
+    async fn handle(&self, task: &ExternalTask) -> Result<serde_json::Value, ExternalTaskError> {
+        self(task.clone()).await
+    }
+}
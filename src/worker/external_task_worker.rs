@@ -0,0 +1,312 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    task::JoinSet,
+    time::{interval_at, sleep, timeout, Instant, MissedTickBehavior},
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::clients::{
+    error::EngineError,
+    external_task::{
+        external_task::{ExternalTask, FetchAndLockRequest},
+        external_task_client::ExternalTaskClient,
+    },
+};
+
+use super::external_task_handler::{ExternalTaskError, ExternalTaskHandler};
+
+/// Configures how an ExternalTaskWorker fetches, locks and processes ExternalTasks.
+#[derive(Clone, Debug)]
+pub struct ExternalTaskWorkerConfig {
+    /// The ID the worker identifies itself with when locking ExternalTasks.
+    pub worker_id: String,
+    /// The maximum number of ExternalTasks handled at the same time.
+    pub max_concurrent_tasks: usize,
+    /// How long the engine may hold a fetch request open while waiting for new ExternalTasks.
+    pub long_polling_timeout: Duration,
+    /// How long a fetched ExternalTask stays locked, and how far each lock extension reaches.
+    pub lock_duration: Duration,
+    /// How often the lock of a running ExternalTask is extended. Must be shorter than `lock_duration`.
+    pub lock_extension_interval: Duration,
+    /// How long to wait before fetching again after a failed fetch request.
+    pub error_backoff: Duration,
+    /// How long to wait for running ExternalTasks once the worker stops. ExternalTasks still
+    /// running afterwards are abandoned, and the engine hands them out again once their lock expires.
+    pub shutdown_timeout: Duration,
+}
+
+impl Default for ExternalTaskWorkerConfig {
+    fn default() -> Self {
+        ExternalTaskWorkerConfig {
+            worker_id: "processcube_engine_client".to_string(),
+            max_concurrent_tasks: 10,
+            long_polling_timeout: Duration::from_secs(10),
+            lock_duration: Duration::from_secs(30),
+            lock_extension_interval: Duration::from_secs(10),
+            error_backoff: Duration::from_secs(1),
+            shutdown_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A worker that long-polls the ProcessCube® Engine for ExternalTasks of a topic and processes them.
+pub struct ExternalTaskWorker<H> {
+    client: ExternalTaskClient,
+    topic: String,
+    handler: Arc<H>,
+    config: ExternalTaskWorkerConfig,
+}
+
+impl<H> ExternalTaskWorker<H>
+where
+    H: ExternalTaskHandler + 'static,
+{
+    /// Creates a new instance of the ExternalTaskWorker.
+    ///
+    /// # Arguments
+    /// * `client` - The ExternalTaskClient to use for communication with the ProcessCube® Engine.
+    /// * `topic` - The topic to fetch ExternalTasks for.
+    /// * `handler` - The handler processing each fetched ExternalTask.
+    /// * `config` - The configuration of the worker.
+    ///
+    /// Fails with `EngineError::Configuration` if `max_concurrent_tasks` or `lock_extension_interval` is zero,
+    /// or if `lock_extension_interval` isn't shorter than `lock_duration`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use processcube_engine_client::{
+    ///     clients::{
+    ///         client_factory::ClientFactory, error::EngineError,
+    ///         external_task::external_task::ExternalTask,
+    ///     },
+    ///     worker::{
+    ///         external_task_handler::ExternalTaskError,
+    ///         external_task_worker::{ExternalTaskWorker, ExternalTaskWorkerConfig},
+    ///     },
+    /// };
+    /// use tokio_util::sync::CancellationToken;
+    /// const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
+    /// const ENGINE_URL: &str = "http://localhost:10560";
+    /// // Be sure to have a running ProcessCube® Engine at the given URL
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), EngineError> {
+    ///     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    ///     let worker = ExternalTaskWorker::new(
+    ///         client_factory.create_external_task_client(),
+    ///         "sample_topic",
+    ///         |task: ExternalTask| async move {
+    ///             Ok::<_, ExternalTaskError>(serde_json::json!({ "received": task.payload }))
+    ///         },
+    ///         ExternalTaskWorkerConfig::default(),
+    ///     )?;
+    ///     // Stop the worker on Ctrl+C
+    ///     let shutdown = CancellationToken::new();
+    ///     let shutdown_on_ctrl_c = shutdown.clone();
+    ///     tokio::spawn(async move {
+    ///         tokio::signal::ctrl_c().await.unwrap();
+    ///         shutdown_on_ctrl_c.cancel();
+    ///     });
+    ///     worker.run(shutdown).await
+    /// }
+    /// ```
+    pub fn new(
+        client: ExternalTaskClient,
+        topic: &str,
+        handler: H,
+        config: ExternalTaskWorkerConfig,
+    ) -> Result<ExternalTaskWorker<H>, EngineError> {
+        if config.max_concurrent_tasks == 0 {
+            return Err(EngineError::Configuration(
+                "The worker must be allowed to handle at least one ExternalTask at a time."
+                    .to_string(),
+            ));
+        }
+        if config.lock_extension_interval.is_zero() {
+            return Err(EngineError::Configuration(
+                "The lock extension interval must not be zero.".to_string(),
+            ));
+        }
+        if config.lock_extension_interval >= config.lock_duration {
+            return Err(EngineError::Configuration(
+                "The lock extension interval must be shorter than the lock duration, so locks are extended before they expire."
+                    .to_string(),
+            ));
+        }
+
+        Ok(ExternalTaskWorker {
+            client,
+            topic: topic.to_string(),
+            handler: Arc::new(handler),
+            config,
+        })
+    }
+
+    /// Fetches and processes ExternalTasks until the given token is cancelled.
+    ///
+    /// Once cancelled, no further ExternalTasks are fetched and the returned future
+    /// resolves as soon as all ExternalTasks already being handled are finished,
+    /// or `shutdown_timeout` has passed.
+    ///
+    /// Failed fetch requests are repeated after `error_backoff`, unless repeating them can't succeed,
+    /// e.g. because the token is rejected or the engine URL is wrong. In that case the worker stops
+    /// fetching and returns the error once the running ExternalTasks are finished.
+    pub async fn run(&self, shutdown: CancellationToken) -> Result<(), EngineError> {
+        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrent_tasks));
+        let mut running_tasks = JoinSet::new();
+
+        let result = loop {
+            while running_tasks.try_join_next().is_some() {}
+
+            // Wait for at least one free slot, then lock as many tasks as there are free slots.
+            let mut permits = tokio::select! {
+                _ = shutdown.cancelled() => break Ok(()),
+                permit = semaphore.clone().acquire_owned() => {
+                    vec![permit.expect("Semaphore is never closed")]
+                }
+            };
+            while let Ok(permit) = semaphore.clone().try_acquire_owned() {
+                permits.push(permit);
+            }
+
+            let request = FetchAndLockRequest {
+                worker_id: self.config.worker_id.clone(),
+                topic_name: self.topic.clone(),
+                max_tasks: permits.len() as u32,
+                long_polling_timeout: self.config.long_polling_timeout.as_millis() as u64,
+                lock_duration: self.config.lock_duration.as_millis() as u64,
+            };
+
+            let fetched_tasks = tokio::select! {
+                _ = shutdown.cancelled() => break Ok(()),
+                fetched_tasks = self.client.fetch_and_lock(request) => fetched_tasks,
+            };
+
+            match fetched_tasks {
+                Ok(tasks) => {
+                    let mut permits = permits.into_iter();
+                    for task in tasks {
+                        // The engine may hand out more tasks than requested. These wait for a free slot.
+                        let permit = permits.next();
+                        let semaphore = semaphore.clone();
+                        let permit = async move {
+                            match permit {
+                                Some(permit) => permit,
+                                None => semaphore
+                                    .acquire_owned()
+                                    .await
+                                    .expect("Semaphore is never closed"),
+                            }
+                        };
+
+                        let client = self.client.clone();
+                        let handler = self.handler.clone();
+                        let config = self.config.clone();
+                        running_tasks.spawn(async move {
+                            process_task(&client, handler.as_ref(), &config, task, permit).await;
+                        });
+                    }
+                }
+                Err(err) if is_permanent(&err) => break Err(err),
+                Err(_) => {
+                    drop(permits);
+                    tokio::select! {
+                        _ = shutdown.cancelled() => break Ok(()),
+                        _ = sleep(self.config.error_backoff) => {}
+                    }
+                }
+            }
+        };
+
+        let all_finished = async { while running_tasks.join_next().await.is_some() {} };
+        if timeout(self.config.shutdown_timeout, all_finished)
+            .await
+            .is_err()
+        {
+            tracing::warn!(
+                topic = %self.topic,
+                abandoned_tasks = running_tasks.len(),
+                "ExternalTasks still running at shutdown are abandoned"
+            );
+            running_tasks.abort_all();
+        }
+
+        result
+    }
+}
+
+/// Returns whether repeating the fetch request that failed with the given error can't succeed.
+fn is_permanent(error: &EngineError) -> bool {
+    matches!(
+        error,
+        EngineError::Configuration(_)
+            | EngineError::Unauthorized(_)
+            | EngineError::Forbidden(_)
+            | EngineError::NotFound(_)
+    )
+}
+
+/// Runs the handler for a single ExternalTask once the given permit is acquired, extending its
+/// lock until the handler is done, and reports the outcome to the ProcessCube® Engine.
+async fn process_task<H>(
+    client: &ExternalTaskClient,
+    handler: &H,
+    config: &ExternalTaskWorkerConfig,
+    task: ExternalTask,
+    permit: impl Future<Output = OwnedSemaphorePermit>,
+) where
+    H: ExternalTaskHandler,
+{
+    let worker_id = &config.worker_id;
+    let lock_duration = config.lock_duration.as_millis() as u64;
+
+    // The lock is extended alongside waiting for a slot and handling, so neither waits for the engine.
+    let extend_lock = async {
+        let mut lock_extension = interval_at(
+            Instant::now() + config.lock_extension_interval,
+            config.lock_extension_interval,
+        );
+        lock_extension.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            lock_extension.tick().await;
+            // A failed extension is not fatal; the engine reports a lost lock when finishing.
+            if let Err(err) = client.extend_lock(&task.id, worker_id, lock_duration).await {
+                tracing::debug!(task_id = %task.id, error = %err, "Failed to extend the lock of an ExternalTask");
+            }
+        }
+    };
+
+    let handling = async {
+        let permit = permit.await;
+        (permit, handler.handle(&task).await)
+    };
+
+    let (_permit, outcome) = tokio::select! {
+        outcome = handling => outcome,
+        _ = extend_lock => unreachable!("The lock is extended until the handler is done"),
+    };
+
+    // If reporting fails, the lock expires and the ExternalTask is handed out again.
+    let reported = match outcome {
+        Ok(result) => {
+            client
+                .finish_external_task(&task.id, worker_id, result)
+                .await
+        }
+        Err(ExternalTaskError::BpmnError { code, message }) => {
+            client
+                .handle_bpmn_error(&task.id, worker_id, &code, &message)
+                .await
+        }
+        Err(ExternalTaskError::ServiceError { message, details }) => {
+            client
+                .handle_service_error(&task.id, worker_id, &message, details)
+                .await
+        }
+    };
+    if let Err(err) = reported {
+        tracing::warn!(task_id = %task.id, error = %err, "Failed to report the outcome of an ExternalTask");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod external_task_handler;
+pub mod external_task_worker;
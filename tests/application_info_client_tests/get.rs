@@ -1,13 +1,15 @@
 use processcube_engine_client::clients::client_factory::ClientFactory;
 
+use crate::fixtures::TestEngine;
+
 const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
-const ENGINE_URL: &str = "http://localhost:10560";
 
 // Happy cases
 
 #[tokio::test]
 async fn get_application_info() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_application_info_client();
     let result = client.get_application_info().await;
 
@@ -27,7 +29,8 @@ async fn get_application_info() {
 
 #[tokio::test]
 async fn get_authority_info() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_application_info_client();
     let result = client.get_authority_info().await;
 
@@ -0,0 +1,292 @@
+use processcube_engine_client::clients::{
+    api::auth_provider::ClientCredentialsProvider, client_factory::ClientFactory,
+    error::EngineError,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_string_contains, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::{start_mock_engine, start_mock_engine_with_auth_provider};
+
+const CORRELATIONS_PATH: &str = "/atlas_engine/api/v1/correlations";
+
+/// Starts a fake authority whose token endpoint issues the given tokens, one per request.
+async fn start_fake_authority(tokens: &[(&str, u64)]) -> MockServer {
+    let authority = MockServer::start().await;
+    mount_discovery(&authority).await;
+
+    for (priority, (access_token, expires_in)) in tokens.iter().enumerate() {
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(body_string_contains("grant_type=client_credentials"))
+            .and(body_string_contains("client_id=test_client"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": access_token,
+                "token_type": "Bearer",
+                "expires_in": expires_in
+            })))
+            .up_to_n_times(1)
+            .with_priority(priority as u8 + 1)
+            .expect(1)
+            .mount(&authority)
+            .await;
+    }
+
+    authority
+}
+
+/// Mounts the discovery document of the given authority, which is expected to be requested once.
+async fn mount_discovery(authority: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/.well-known/openid-configuration"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "issuer": authority.uri(),
+            "token_endpoint": format!("{}/token", authority.uri())
+        })))
+        .expect(1)
+        .mount(authority)
+        .await;
+}
+
+async fn mount_correlations(engine: &MockServer, auth_token: &str, status: u16) {
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .and(header("Authorization", auth_token))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .set_body_json(json!({ "correlations": [], "totalCount": 0 })),
+        )
+        .mount(engine)
+        .await;
+}
+
+fn create_provider(authority: &MockServer) -> ClientCredentialsProvider {
+    ClientCredentialsProvider::new(
+        &authority.uri(),
+        "test_client",
+        "test_secret",
+        "engine_read engine_write",
+    )
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn client_credentials_token_is_cached_until_expiry() {
+    let authority = start_fake_authority(&[("token_1", 3600)]).await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+    mount_correlations(&engine, "Bearer token_1", 200).await;
+
+    let client = client_factory.create_correlation_client();
+
+    for _ in 0..3 {
+        let result = client.get_correlations().await;
+        assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+    }
+}
+
+#[tokio::test]
+async fn client_credentials_short_lived_token_is_cached() {
+    let authority = start_fake_authority(&[("token_1", 20)]).await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+    mount_correlations(&engine, "Bearer token_1", 200).await;
+
+    let client = client_factory.create_correlation_client();
+
+    for _ in 0..3 {
+        let result = client.get_correlations().await;
+        assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+    }
+}
+
+#[tokio::test]
+async fn client_credentials_token_is_refreshed_after_expiry() {
+    let authority = start_fake_authority(&[("token_1", 0), ("token_2", 3600)]).await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+    mount_correlations(&engine, "Bearer token_1", 200).await;
+    mount_correlations(&engine, "Bearer token_2", 200).await;
+
+    let client = client_factory.create_correlation_client();
+
+    let result = client.get_correlations().await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+    let result = client.get_correlations().await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+
+    let engine_requests = engine.received_requests().await.unwrap();
+    assert_eq!(
+        engine_requests[1].headers["Authorization"],
+        "Bearer token_2"
+    );
+}
+
+#[tokio::test]
+async fn client_credentials_token_is_refreshed_on_unauthorized() {
+    let authority = start_fake_authority(&[("token_1", 3600), ("token_2", 3600)]).await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+    mount_correlations(&engine, "Bearer token_1", 401).await;
+    mount_correlations(&engine, "Bearer token_2", 200).await;
+
+    let client = client_factory.create_correlation_client();
+
+    let result = client.get_correlations().await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn client_credentials_token_endpoint_is_discovered_once() {
+    let authority =
+        start_fake_authority(&[("token_1", 0), ("token_2", 0), ("token_3", 3600)]).await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+    for auth_token in ["Bearer token_1", "Bearer token_2", "Bearer token_3"] {
+        mount_correlations(&engine, auth_token, 200).await;
+    }
+
+    let client = client_factory.create_correlation_client();
+
+    for _ in 0..3 {
+        let result = client.get_correlations().await;
+        assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+    }
+
+    let discovery_requests = authority
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.url.path() == "/.well-known/openid-configuration")
+        .count();
+    assert_eq!(discovery_requests, 1);
+}
+
+#[tokio::test]
+async fn client_credentials_provider_uses_authority_of_engine() {
+    let authority = start_fake_authority(&[("token_1", 3600)]).await;
+    let (engine, static_token_client_factory) = start_mock_engine().await;
+    Mock::given(method("GET"))
+        .and(path("/atlas_engine/api/v1/authority"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(format!("{}/", authority.uri())))
+        .mount(&engine)
+        .await;
+    mount_correlations(&engine, "Bearer token_1", 200).await;
+
+    let application_info_client = static_token_client_factory.create_application_info_client();
+    let provider = ClientCredentialsProvider::from_engine(
+        &application_info_client,
+        "test_client",
+        "test_secret",
+        "engine_read",
+    )
+    .await
+    .unwrap();
+    assert_eq!(provider.get_authority_url(), authority.uri());
+
+    let client_factory = ClientFactory::with_auth_provider(&engine.uri(), provider);
+    let result = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn client_credentials_uses_http_settings_of_builder() {
+    let authority = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/.well-known/openid-configuration"))
+        .and(header("X-Tenant-Id", "sample_tenant"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "token_endpoint": format!("{}/token", authority.uri())
+        })))
+        .expect(1)
+        .mount(&authority)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(header("X-Tenant-Id", "sample_tenant"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "token_1",
+            "token_type": "Bearer",
+            "expires_in": 3600
+        })))
+        .expect(1)
+        .mount(&authority)
+        .await;
+    let engine = MockServer::start().await;
+    mount_correlations(&engine, "Bearer token_1", 200).await;
+
+    let client_factory = ClientFactory::builder(&engine.uri())
+        .client_credentials(create_provider(&authority))
+        .default_header("X-Tenant-Id", "sample_tenant")
+        .build()
+        .unwrap();
+    let result = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await;
+
+    assert!(result.is_ok());
+}
+
+// Error cases
+
+#[tokio::test]
+async fn client_credentials_invalid_client() {
+    let authority = MockServer::start().await;
+    mount_discovery(&authority).await;
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "invalid_client"
+        })))
+        .mount(&authority)
+        .await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+
+    let result = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, EngineError::Unauthorized(_)));
+    assert_eq!(err.status(), Some(401));
+    assert_eq!(
+        err.details().unwrap().error_class_name,
+        "AuthenticationError"
+    );
+    assert!(engine.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn client_credentials_unavailable_authority() {
+    let authority = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/.well-known/openid-configuration"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&authority)
+        .await;
+    let (engine, client_factory) =
+        start_mock_engine_with_auth_provider(create_provider(&authority)).await;
+
+    let result = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, EngineError::Engine(_)));
+    assert_eq!(err.status(), Some(503));
+    assert!(err.is_retryable());
+    assert!(engine.received_requests().await.unwrap().is_empty());
+}
@@ -0,0 +1,75 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use processcube_engine_client::clients::{
+    api::auth_provider::StaticTokenProvider, client_factory::ClientFactory, error::EngineError,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CORRELATIONS_PATH: &str = "/atlas_engine/api/v1/correlations";
+
+// Happy cases
+
+#[tokio::test]
+async fn closure_provider_is_asked_before_each_request() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .and(header("Authorization", "Bearer custom_token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "correlations": [], "totalCount": 0 })),
+        )
+        .expect(2)
+        .mount(&engine)
+        .await;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let provider_calls = calls.clone();
+    let client_factory = ClientFactory::with_auth_provider(&engine.uri(), move || {
+        let provider_calls = provider_calls.clone();
+        async move {
+            provider_calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, EngineError>("Bearer custom_token".to_string())
+        }
+    });
+    let client = client_factory.create_correlation_client();
+
+    assert!(client.get_correlations().await.is_ok());
+    assert!(client.get_correlations().await.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn static_token_provider_is_not_retried_on_unauthorized() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "errorClassName": "UnauthorizedError",
+            "message": "Invalid token",
+            "callStack": "",
+            "code": 401,
+            "fatal": false
+        })))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::with_auth_provider(&engine.uri(), StaticTokenProvider::new("Bearer foo"));
+    let result = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await;
+    assert!(result.is_err());
+    assert!(matches!(result.unwrap_err(), EngineError::Unauthorized(_)));
+}
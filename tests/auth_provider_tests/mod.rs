@@ -0,0 +1,2 @@
+mod client_credentials;
+mod custom;
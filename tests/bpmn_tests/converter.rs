@@ -0,0 +1,206 @@
+use std::path::Path;
+
+use processcube_engine_client::{
+    bpmn::{converter::process_models_from_xml, error::BpmnError},
+    clients::{
+        api::transport::ReqwestTransport, client_factory::ClientFactory,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+    },
+    testing::cassette::RecordingTransport,
+};
+use serde_json::json;
+
+use crate::fixtures::{live_engine_url, DUMMY_TOKEN};
+
+const SIMPLE_PROCESS_XML: &str = include_str!("../fixtures/bpmn/simple_process.bpmn");
+const ORDER_PROCESS_XML: &str = include_str!("../fixtures/bpmn/order_process.bpmn");
+const APPROVAL_PROCESS_XML: &str = include_str!("../fixtures/bpmn/approval_process.bpmn");
+
+/// Deploys the given BPMN fixture to the live engine and asserts that the converter returns
+/// the same ProcessModels as the engine does. The engine's responses are saved as a Cassette
+/// to `tests/fixtures/process_models/<name>.json`.
+async fn assert_converted_like_engine(name: &str, xml: &str) {
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory = ClientFactory::new(&live_engine_url(), DUMMY_TOKEN);
+    client_factory
+        .create_process_definition_client()
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: xml.to_string(),
+            overwrite_existing: true,
+        })
+        .await
+        .unwrap();
+    let process_model_client = client_factory
+        .with_transport(recorder.clone())
+        .create_process_model_client();
+
+    let converted = process_models_from_xml(xml).unwrap();
+    let mut deployed = Vec::new();
+    for process_model in &converted {
+        deployed.push(
+            process_model_client
+                .get_process_model_by_id(&process_model.id)
+                .await
+                .unwrap(),
+        );
+    }
+
+    // Saved before comparing, so the responses are kept even if the converter differs
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/process_models");
+    std::fs::create_dir_all(&directory).unwrap();
+    recorder
+        .save(directory.join(format!("{}.json", name)))
+        .unwrap();
+
+    for (converted, deployed) in converted.iter().zip(&deployed) {
+        assert_eq!(
+            serde_json::to_value(converted).unwrap(),
+            serde_json::to_value(deployed).unwrap(),
+            "ProcessModel `{}` differs from the one of the engine",
+            converted.id
+        );
+    }
+}
+
+// Happy cases
+#[test]
+fn convert_simple_process() {
+    let process_models = process_models_from_xml(SIMPLE_PROCESS_XML).unwrap();
+    assert_eq!(process_models.len(), 1);
+
+    let process_model = &process_models[0];
+    assert_eq!(process_model.id, "Simple_Process");
+    assert_eq!(process_model.name.as_deref(), Some("Simple Process"));
+    assert_eq!(process_model.process_definition_id, "Definitions_Simple");
+    assert_eq!(process_model.version, None);
+    assert!(process_model.is_executable);
+
+    let start_event = &process_model.start_events[0];
+    assert_eq!(start_event.id, "StartEvent_1");
+    assert_eq!(start_event.name, "Start");
+    assert_eq!(start_event.flow_node_type, "bpmn:StartEvent");
+    assert_eq!(start_event.process_model_id, "Simple_Process");
+    assert_eq!(start_event.process_model_name, "Simple Process");
+
+    let end_event = &process_model.end_events[0];
+    assert_eq!(end_event.id, "Event_1lpuf0o");
+    assert_eq!(end_event.flow_node_type, "bpmn:EndEvent");
+
+    let lane = &process_model.lane_set.lanes[0];
+    assert_eq!(lane.id, "Lane_1xzf0d3");
+    assert_eq!(lane.name.as_deref(), Some("Bar Lane"));
+    assert_eq!(
+        lane.flow_node_references,
+        Some(vec![
+            "StartEvent_1".to_string(),
+            "Event_1lpuf0o".to_string()
+        ])
+    );
+}
+
+#[test]
+fn convert_order_process() {
+    let process_models = process_models_from_xml(ORDER_PROCESS_XML).unwrap();
+    let process_model = &process_models[0];
+
+    assert_eq!(process_model.id, "Order_Process");
+    assert_eq!(process_model.version.as_deref(), Some("1.2.0"));
+    assert_eq!(
+        process_model.custom_properties,
+        json!({ "department": "sales" })
+    );
+
+    let timer_start_event = &process_model.start_events[2];
+    assert_eq!(timer_start_event.id, "StartEvent_Nightly");
+    assert_eq!(timer_start_event.timer_type.as_deref(), Some("cycle"));
+    assert_eq!(timer_start_event.timer_value.as_deref(), Some("0 0 * * *"));
+
+    let error_end_event = &process_model.end_events[1];
+    assert_eq!(error_end_event.id, "EndEvent_Failed");
+    assert_eq!(error_end_event.error_name.as_deref(), Some("ShippingError"));
+    assert_eq!(
+        error_end_event.error_code.as_deref(),
+        Some("SHIPPING_FAILED")
+    );
+    assert_eq!(
+        error_end_event.error_message.as_deref(),
+        Some("The order could not be shipped")
+    );
+
+    let flow_node_types: Vec<_> = process_model
+        .flow_nodes
+        .iter()
+        .map(|flow_node| (flow_node.id.as_str(), flow_node.flow_node_type.as_str()))
+        .collect();
+    assert!(flow_node_types.contains(&("Gateway_Merge", "bpmn:ExclusiveGateway")));
+    assert!(flow_node_types.contains(&("Task_ReviewOrder", "bpmn:UserTask")));
+    assert!(flow_node_types.contains(&("Task_ShipOrder", "bpmn:ServiceTask")));
+    assert!(flow_node_types.contains(&("Event_ShippingFailed", "bpmn:BoundaryEvent")));
+    assert!(flow_node_types.contains(&("Task_WriteInvoice", "bpmn:EmptyActivity")));
+
+    let lane_ids: Vec<_> = process_model
+        .lane_set
+        .lanes
+        .iter()
+        .map(|lane| lane.id.as_str())
+        .collect();
+    assert_eq!(lane_ids, vec!["Lane_Shop", "Lane_Warehouse"]);
+}
+
+#[test]
+fn convert_start_and_end_events() {
+    let process_models = process_models_from_xml(ORDER_PROCESS_XML).unwrap();
+    let process_model = &process_models[0];
+
+    let message_names: Vec<_> = process_model
+        .start_events
+        .iter()
+        .filter_map(|start_event| start_event.message_name.as_deref())
+        .collect();
+    assert_eq!(message_names, vec!["order_received"]);
+
+    let signal_names: Vec<_> = process_model
+        .end_events
+        .iter()
+        .filter_map(|end_event| end_event.signal_name.as_deref())
+        .collect();
+    assert_eq!(signal_names, vec!["order_shipped"]);
+
+    // Events nested in SubProcesses cannot start or end the process
+    assert!(!process_model
+        .start_events
+        .iter()
+        .any(|start_event| start_event.id == "StartEvent_Invoice"));
+    assert!(process_model
+        .flow_nodes
+        .iter()
+        .any(|flow_node| flow_node.id == "StartEvent_Invoice"));
+}
+
+// Golden cases against a live engine
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn convert_simple_process_like_engine() {
+    assert_converted_like_engine("simple_process", SIMPLE_PROCESS_XML).await;
+}
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn convert_order_process_like_engine() {
+    assert_converted_like_engine("order_process", ORDER_PROCESS_XML).await;
+}
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn convert_approval_process_like_engine() {
+    assert_converted_like_engine("approval_process", APPROVAL_PROCESS_XML).await;
+}
+
+// Error cases
+#[test]
+fn convert_with_invalid_xml() {
+    let result = process_models_from_xml("foo");
+
+    assert!(matches!(result, Err(BpmnError::Xml { .. })));
+}
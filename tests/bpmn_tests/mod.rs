@@ -0,0 +1,3 @@
+mod converter;
+mod parser;
+mod validator;
@@ -0,0 +1,207 @@
+use processcube_engine_client::{
+    bpmn::{
+        bpmn::{EventDefinition, Timer},
+        error::BpmnError,
+        parser::parse,
+    },
+    clients::flow_node_instance::flow_node_instance::BpmnType,
+};
+
+const ORDER_PROCESS_XML: &str = include_str!("../fixtures/bpmn/order_process.bpmn");
+
+// Happy cases
+#[test]
+fn parse_process() {
+    let definitions = parse(ORDER_PROCESS_XML).unwrap();
+
+    assert_eq!(definitions.id, "Definitions_Order");
+    assert_eq!(definitions.processes.len(), 1);
+    let process = &definitions.processes[0];
+    assert_eq!(process.id, "Order_Process");
+    assert_eq!(process.name.as_deref(), Some("Order Process"));
+    assert!(process.is_executable);
+    assert_eq!(process.version.as_deref(), Some("1.2.0"));
+    assert_eq!(process.custom_properties["department"], "sales");
+    assert_eq!(process.flow_nodes.len(), 10);
+    assert_eq!(process.sequence_flows.len(), 8);
+
+    assert_eq!(definitions.participants.len(), 1);
+    assert_eq!(
+        definitions.participants[0].process_ref.as_deref(),
+        Some("Order_Process")
+    );
+}
+
+#[test]
+fn parse_lanes() {
+    let definitions = parse(ORDER_PROCESS_XML).unwrap();
+    let process = &definitions.processes[0];
+
+    assert_eq!(process.lanes.len(), 2);
+    assert_eq!(process.lanes[0].name.as_deref(), Some("Shop"));
+    assert_eq!(process.lanes[0].flow_node_refs.len(), 5);
+    assert_eq!(process.lanes[0].child_lanes.len(), 1);
+    assert_eq!(
+        process.lanes[0].child_lanes[0].flow_node_refs,
+        vec!["Task_ReviewOrder"]
+    );
+
+    let lane_ids: Vec<&str> = process
+        .all_lanes()
+        .iter()
+        .map(|lane| lane.id.as_str())
+        .collect();
+    assert_eq!(lane_ids, vec!["Lane_Shop", "Lane_Clerk", "Lane_Warehouse"]);
+}
+
+#[test]
+fn parse_flow_nodes() {
+    let definitions = parse(ORDER_PROCESS_XML).unwrap();
+    let process = &definitions.processes[0];
+    let flow_node = |id: &str| {
+        process
+            .all_flow_nodes()
+            .into_iter()
+            .find(|flow_node| flow_node.id == id)
+            .unwrap()
+            .clone()
+    };
+
+    let start_event = flow_node("StartEvent_Manual");
+    assert_eq!(start_event.bpmn_type, BpmnType::StartEvent);
+    assert_eq!(start_event.documentation, vec!["Started by a clerk"]);
+    assert_eq!(start_event.outgoing, vec!["Flow_Manual"]);
+    assert_eq!(start_event.event_definition, None);
+
+    let gateway = flow_node("Gateway_Merge");
+    assert_eq!(gateway.bpmn_type, BpmnType::ExclusiveGateway);
+    assert_eq!(gateway.incoming.len(), 3);
+
+    let user_task = flow_node("Task_ReviewOrder");
+    assert_eq!(user_task.bpmn_type, BpmnType::UserTask);
+    assert_eq!(user_task.custom_properties["priority"], "high");
+
+    let boundary_event = flow_node("Event_ShippingFailed");
+    assert_eq!(boundary_event.bpmn_type, BpmnType::BoundaryEvent);
+    assert_eq!(
+        boundary_event.attached_to_ref.as_deref(),
+        Some("Task_ShipOrder")
+    );
+
+    let sub_process = flow_node("SubProcess_Invoice");
+    assert_eq!(sub_process.bpmn_type, BpmnType::SubProcess);
+    assert_eq!(sub_process.flow_nodes.len(), 3);
+    assert_eq!(sub_process.sequence_flows.len(), 2);
+    assert_eq!(
+        flow_node("Task_WriteInvoice").bpmn_type,
+        BpmnType::EmptyActivity
+    );
+}
+
+#[test]
+fn parse_event_definitions() {
+    let definitions = parse(ORDER_PROCESS_XML).unwrap();
+    let process = &definitions.processes[0];
+    let event_definition = |id: &str| {
+        process
+            .all_flow_nodes()
+            .into_iter()
+            .find(|flow_node| flow_node.id == id)
+            .and_then(|flow_node| flow_node.event_definition.clone())
+            .unwrap()
+    };
+
+    assert_eq!(
+        event_definition("StartEvent_OrderReceived"),
+        EventDefinition::Message {
+            message_ref: Some("Message_Order".to_string())
+        }
+    );
+    assert_eq!(
+        event_definition("StartEvent_Nightly"),
+        EventDefinition::Timer {
+            timer: Some(Timer::Cycle("0 0 * * *".to_string()))
+        }
+    );
+    assert_eq!(
+        event_definition("EndEvent_Shipped"),
+        EventDefinition::Signal {
+            signal_ref: Some("Signal_Shipped".to_string())
+        }
+    );
+    assert_eq!(
+        event_definition("EndEvent_Failed"),
+        EventDefinition::Error {
+            error_ref: Some("Error_Shipping".to_string())
+        }
+    );
+
+    assert_eq!(
+        definitions
+            .message("Message_Order")
+            .unwrap()
+            .name
+            .as_deref(),
+        Some("order_received")
+    );
+    assert_eq!(
+        definitions
+            .signal("Signal_Shipped")
+            .unwrap()
+            .name
+            .as_deref(),
+        Some("order_shipped")
+    );
+    let error = definitions.error("Error_Shipping").unwrap();
+    assert_eq!(error.error_code.as_deref(), Some("SHIPPING_FAILED"));
+    assert_eq!(
+        error.error_message.as_deref(),
+        Some("The order could not be shipped")
+    );
+}
+
+#[test]
+fn parse_sequence_flows() {
+    let definitions = parse(ORDER_PROCESS_XML).unwrap();
+    let sequence_flow = definitions.processes[0]
+        .sequence_flows
+        .iter()
+        .find(|sequence_flow| sequence_flow.id == "Flow_ToShip")
+        .unwrap();
+
+    assert_eq!(sequence_flow.name.as_deref(), Some("approved"));
+    assert_eq!(sequence_flow.source_ref, "Task_ReviewOrder");
+    assert_eq!(sequence_flow.target_ref, "Task_ShipOrder");
+    assert_eq!(
+        sequence_flow.condition_expression.as_deref(),
+        Some("token.current.approved === true")
+    );
+}
+
+// Error cases
+#[test]
+fn parse_with_text_before_first_tag() {
+    let result = parse("foo");
+
+    assert!(matches!(result, Err(BpmnError::Xml { .. })));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("Non-whitespace before first tag."));
+}
+
+#[test]
+fn parse_with_unclosed_tag() {
+    let xml = ORDER_PROCESS_XML.replace("</bpmn:process>", "");
+
+    let result = parse(&xml);
+
+    assert!(matches!(result, Err(BpmnError::Xml { .. })));
+}
+
+#[test]
+fn parse_with_wrong_root_element() {
+    let result = parse("<?xml version=\"1.0\"?><html><body /></html>");
+
+    assert!(matches!(result, Err(BpmnError::InvalidDocument(_))));
+}
@@ -0,0 +1,146 @@
+use processcube_engine_client::{
+    bpmn::{
+        error::{BpmnError, ValidationIssue},
+        parser::parse,
+        validator::{find_issues, parse_and_validate},
+    },
+    clients::process_definition::process_definition::PersistProcessDefinitionPayload,
+};
+
+use crate::fixtures::get_valid_process_definition_xml;
+
+const ORDER_PROCESS_XML: &str = include_str!("../fixtures/bpmn/order_process.bpmn");
+
+fn issues(xml: &str) -> Vec<ValidationIssue> {
+    find_issues(&parse(xml).unwrap())
+}
+
+// Happy cases
+#[test]
+fn validate_process_definition() {
+    let result = parse_and_validate(&get_valid_process_definition_xml());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn validate_process_with_sub_process_and_boundary_event() {
+    assert_eq!(issues(ORDER_PROCESS_XML), vec![]);
+}
+
+#[test]
+fn validate_payload() {
+    let payload = PersistProcessDefinitionPayload {
+        xml: get_valid_process_definition_xml(),
+        overwrite_existing: false,
+    };
+
+    assert!(payload.validate().is_ok());
+}
+
+#[test]
+fn validate_collaboration_with_non_executable_pool() {
+    let xml = ORDER_PROCESS_XML
+        .replace(
+            "</bpmn:collaboration>",
+            "<bpmn:participant id=\"Participant_Customer\" name=\"Customer\" processRef=\"Customer_Process\" />\n  </bpmn:collaboration>",
+        )
+        .replace(
+            "</bpmn:definitions>",
+            "<bpmn:process id=\"Customer_Process\" isExecutable=\"false\" />\n</bpmn:definitions>",
+        );
+
+    assert_eq!(issues(&xml), vec![]);
+}
+
+// Error cases
+#[test]
+fn validate_without_process() {
+    let xml = "<bpmn:definitions xmlns:bpmn=\"http://www.omg.org/spec/BPMN/20100524/MODEL\" id=\"Definitions_1\" />";
+
+    assert_eq!(issues(xml), vec![ValidationIssue::NoProcess]);
+}
+
+#[test]
+fn validate_not_executable_process() {
+    let xml = ORDER_PROCESS_XML.replace("isExecutable=\"true\"", "isExecutable=\"false\"");
+
+    assert_eq!(issues(&xml), vec![ValidationIssue::NoExecutableProcess]);
+}
+
+#[test]
+fn validate_without_start_and_end_event() {
+    let xml = ORDER_PROCESS_XML
+        .replace("bpmn:startEvent", "bpmn:task")
+        .replace("bpmn:endEvent", "bpmn:task");
+
+    assert_eq!(
+        issues(&xml),
+        vec![
+            ValidationIssue::MissingStartEvent {
+                process_id: "Order_Process".to_string()
+            },
+            ValidationIssue::MissingEndEvent {
+                process_id: "Order_Process".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn validate_dangling_sequence_flow() {
+    let xml = ORDER_PROCESS_XML.replace(
+        "sourceRef=\"StartEvent_Manual\" targetRef=\"Gateway_Merge\"",
+        "sourceRef=\"StartEvent_Missing\" targetRef=\"Gateway_Missing\"",
+    );
+
+    assert_eq!(
+        issues(&xml),
+        vec![
+            ValidationIssue::DanglingSourceRef {
+                sequence_flow_id: "Flow_Manual".to_string(),
+                source_ref: "StartEvent_Missing".to_string(),
+            },
+            ValidationIssue::DanglingTargetRef {
+                sequence_flow_id: "Flow_Manual".to_string(),
+                target_ref: "Gateway_Missing".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn validate_duplicate_id() {
+    let xml = ORDER_PROCESS_XML.replace("id=\"Task_WriteInvoice\"", "id=\"Task_ShipOrder\"");
+
+    assert!(issues(&xml).contains(&ValidationIssue::DuplicateId {
+        id: "Task_ShipOrder".to_string()
+    }));
+}
+
+#[test]
+fn validate_reports_all_issues() {
+    let xml = ORDER_PROCESS_XML
+        .replace("isExecutable=\"true\"", "isExecutable=\"false\"")
+        .replace(
+            "targetRef=\"EndEvent_Failed\"",
+            "targetRef=\"EndEvent_Missing\"",
+        );
+
+    let result = parse_and_validate(&xml);
+
+    match result {
+        Err(BpmnError::Validation(issues)) => assert_eq!(issues.len(), 2),
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn validate_payload_with_invalid_xml() {
+    let payload = PersistProcessDefinitionPayload {
+        xml: "foo".to_string(),
+        overwrite_existing: false,
+    };
+
+    assert!(matches!(payload.validate(), Err(BpmnError::Xml { .. })));
+}
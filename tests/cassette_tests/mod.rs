@@ -0,0 +1 @@
+mod record_and_replay;
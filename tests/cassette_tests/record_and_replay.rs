@@ -0,0 +1,160 @@
+use processcube_engine_client::{
+    clients::{
+        api::transport::ReqwestTransport, client_factory::ClientFactory, error::EngineError,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+    },
+    testing::{
+        cassette::{Cassette, RecordingTransport, ReplayingTransport, REDACTED},
+        fake_engine::FakeEngine,
+    },
+};
+
+use crate::fixtures::{create_temp_directory, get_valid_process_definition_xml, DUMMY_TOKEN};
+
+/// The URL the replaying clients are created with. Nothing listens there.
+const OFFLINE_ENGINE_URL: &str = "http://localhost:1";
+
+// Happy cases
+
+#[tokio::test]
+async fn recorded_requests_are_replayed_offline() {
+    let engine = FakeEngine::start().await;
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory =
+        ClientFactory::new(&engine.url(), DUMMY_TOKEN).with_transport(recorder.clone());
+    let client = client_factory.create_process_definition_client();
+    let xml = get_valid_process_definition_xml();
+    client
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: xml.clone(),
+            overwrite_existing: false,
+        })
+        .await
+        .unwrap();
+    let recorded = client.get_process_definitions(None, None).await.unwrap();
+    let cassette_path = create_temp_directory().join("cassette.json");
+    recorder.save(&cassette_path).unwrap();
+    drop(engine);
+
+    let client_factory = ClientFactory::new(OFFLINE_ENGINE_URL, DUMMY_TOKEN)
+        .with_transport(ReplayingTransport::from_file(&cassette_path).unwrap());
+    let client = client_factory.create_process_definition_client();
+    client
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml,
+            overwrite_existing: false,
+        })
+        .await
+        .unwrap();
+    let replayed = client.get_process_definitions(None, None).await.unwrap();
+
+    assert_eq!(replayed.total_count, recorded.total_count);
+    assert_eq!(
+        replayed.process_definitions[0].id,
+        recorded.process_definitions[0].id
+    );
+}
+
+#[tokio::test]
+async fn authorization_header_is_redacted() {
+    let engine = FakeEngine::start().await;
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory =
+        ClientFactory::new(&engine.url(), DUMMY_TOKEN).with_transport(recorder.clone());
+    client_factory
+        .create_process_model_client()
+        .get_process_models(None, None)
+        .await
+        .unwrap();
+    let cassette_path = create_temp_directory().join("cassette.json");
+    recorder.save(&cassette_path).unwrap();
+
+    let content = std::fs::read_to_string(&cassette_path).unwrap();
+    let cassette = Cassette::load(&cassette_path).unwrap();
+
+    assert!(!content.contains(DUMMY_TOKEN));
+    assert_eq!(
+        cassette.interactions[0].request.headers["authorization"],
+        REDACTED
+    );
+}
+
+#[tokio::test]
+async fn repeated_requests_are_replayed_in_order() {
+    let engine = FakeEngine::start().await;
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory =
+        ClientFactory::new(&engine.url(), DUMMY_TOKEN).with_transport(recorder.clone());
+    let client = client_factory.create_process_definition_client();
+    let before = client.get_process_definitions(None, None).await.unwrap();
+    client
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: get_valid_process_definition_xml(),
+            overwrite_existing: false,
+        })
+        .await
+        .unwrap();
+    let after = client.get_process_definitions(None, None).await.unwrap();
+
+    let client_factory = ClientFactory::new(OFFLINE_ENGINE_URL, DUMMY_TOKEN)
+        .with_transport(ReplayingTransport::new(recorder.cassette()));
+    let client = client_factory.create_process_definition_client();
+
+    assert_eq!(
+        client
+            .get_process_definitions(None, None)
+            .await
+            .unwrap()
+            .total_count,
+        before.total_count
+    );
+    assert_eq!(
+        client
+            .get_process_definitions(None, None)
+            .await
+            .unwrap()
+            .total_count,
+        after.total_count
+    );
+}
+
+#[tokio::test]
+async fn recorded_errors_are_replayed() {
+    let engine = FakeEngine::start().await;
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory =
+        ClientFactory::new(&engine.url(), DUMMY_TOKEN).with_transport(recorder.clone());
+    client_factory
+        .create_process_definition_client()
+        .get_process_definition_by_id("Definitions_Unknown")
+        .await
+        .unwrap_err();
+
+    let client_factory = ClientFactory::new(OFFLINE_ENGINE_URL, DUMMY_TOKEN)
+        .with_transport(ReplayingTransport::new(recorder.cassette()));
+    let err = client_factory
+        .create_process_definition_client()
+        .get_process_definition_by_id("Definitions_Unknown")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, EngineError::NotFound(_)));
+}
+
+// Error cases
+
+#[tokio::test]
+async fn unrecorded_request_fails() {
+    let client_factory = ClientFactory::new(OFFLINE_ENGINE_URL, DUMMY_TOKEN)
+        .with_transport(ReplayingTransport::new(Cassette::default()));
+
+    let err = client_factory
+        .create_process_model_client()
+        .get_process_models(None, None)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, EngineError::Configuration(message) if message.contains("GET /atlas_engine/api/v1/process_models"))
+    );
+}
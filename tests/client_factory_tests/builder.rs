@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use processcube_engine_client::clients::{client_factory::ClientFactory, error::EngineError};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const CORRELATIONS_PATH: &str = "/atlas_engine/api/v1/correlations";
+
+fn empty_correlation_list() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({ "correlations": [], "totalCount": 0 }))
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn builder_sends_user_agent_and_default_headers() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .and(header("Authorization", DUMMY_TOKEN))
+        .and(header("User-Agent", "sample_application/1.0"))
+        .and(header("X-Tenant-Id", "sample_tenant"))
+        .respond_with(empty_correlation_list())
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory = ClientFactory::builder(&engine.uri())
+        .auth_token(DUMMY_TOKEN)
+        .user_agent("sample_application/1.0")
+        .default_header("X-Tenant-Id", "sample_tenant")
+        .build()
+        .unwrap();
+    let client = client_factory.create_correlation_client();
+
+    assert!(client.get_correlations().await.is_ok());
+}
+
+#[tokio::test]
+async fn builder_sends_requests_through_proxy() {
+    let proxy = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(empty_correlation_list())
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let client_factory = ClientFactory::builder("http://engine.invalid")
+        .auth_token(DUMMY_TOKEN)
+        .proxy(&proxy.uri())
+        .build()
+        .unwrap();
+    let client = client_factory.create_correlation_client();
+
+    assert!(client.get_correlations().await.is_ok());
+}
+
+// Error cases
+
+#[tokio::test]
+async fn builder_applies_request_timeout() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(empty_correlation_list().set_delay(Duration::from_secs(5)))
+        .mount(&engine)
+        .await;
+
+    let client_factory = ClientFactory::builder(&engine.uri())
+        .auth_token(DUMMY_TOKEN)
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let client = client_factory.create_correlation_client();
+
+    assert!(matches!(
+        client.get_correlations().await.unwrap_err(),
+        EngineError::Timeout(_)
+    ));
+}
+
+#[test]
+fn builder_requires_authentication() {
+    let result = ClientFactory::builder("http://localhost:10560").build();
+
+    assert!(matches!(
+        result.err().unwrap(),
+        EngineError::Configuration(_)
+    ));
+}
+
+#[test]
+fn builder_rejects_invalid_default_header() {
+    let result = ClientFactory::builder("http://localhost:10560")
+        .auth_token(DUMMY_TOKEN)
+        .default_header("Invalid Header", "value")
+        .build();
+
+    match result.err().unwrap() {
+        EngineError::Configuration(message) => {
+            assert_eq!(message, "Invalid header name `Invalid Header`.")
+        }
+        error => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn builder_rejects_invalid_root_certificate() {
+    let result = ClientFactory::builder("http://localhost:10560")
+        .auth_token(DUMMY_TOKEN)
+        .add_root_certificate_pem(b"not a certificate")
+        .build();
+
+    assert!(matches!(
+        result.err().unwrap(),
+        EngineError::Configuration(_)
+    ));
+}
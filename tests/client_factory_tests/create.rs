@@ -21,14 +21,50 @@ fn create_event_client() {
     let _client = client_factory.create_event_client();
 }
 
+#[test]
+fn create_external_task_client() {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let _client = client_factory.create_external_task_client();
+}
+
+#[test]
+fn create_flow_node_instance_client() {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let _client = client_factory.create_flow_node_instance_client();
+}
+
+#[test]
+fn create_manual_task_client() {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let _client = client_factory.create_manual_task_client();
+}
+
 #[test]
 fn create_process_definition_client() {
     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
     let _client = client_factory.create_process_definition_client();
 }
 
+#[test]
+fn create_process_instance_client() {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let _client = client_factory.create_process_instance_client();
+}
+
 #[test]
 fn create_process_model_client() {
     let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
     let _client = client_factory.create_process_model_client();
 }
+
+#[test]
+fn create_untyped_task_client() {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let _client = client_factory.create_untyped_task_client();
+}
+
+#[test]
+fn create_user_task_client() {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let _client = client_factory.create_user_task_client();
+}
@@ -1,13 +1,15 @@
-use processcube_engine_client::clients::client_factory::ClientFactory;
+use processcube_engine_client::clients::{client_factory::ClientFactory, error::EngineError};
+
+use crate::fixtures::TestEngine;
 
 const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
-const ENGINE_URL: &str = "http://localhost:10560";
 
 // Happy cases
 
 #[tokio::test]
 async fn get_correlations() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_correlation_client();
     let result = client.get_correlations().await;
     assert!(result.is_ok());
@@ -19,13 +21,18 @@ async fn get_correlations() {
 
 #[tokio::test]
 async fn get_correlation_by_id_not_found() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_correlation_client();
     let result = client.get_correlation_by_id("dummy").await;
 
     assert!(result.is_err());
     let error = result.unwrap_err();
-    assert_eq!(error.code, 404);
-    assert_eq!(error.error_type, "NotFoundError");
-    assert_eq!(error.message, "Correlation with ID `dummy` not found.");
+    assert!(matches!(error, EngineError::NotFound(_)));
+    assert_eq!(error.status(), Some(404));
+    assert_eq!(error.details().unwrap().error_class_name, "NotFoundError");
+    assert_eq!(
+        error.details().unwrap().message,
+        "Correlation with ID `dummy` not found."
+    );
 }
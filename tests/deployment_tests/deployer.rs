@@ -0,0 +1,325 @@
+use std::path::PathBuf;
+
+use processcube_engine_client::{
+    clients::{
+        client_factory::ClientFactory,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+    },
+    deployment::{
+        deployer::{Deployer, DeploymentAction, DeploymentSummary},
+        error::DeploymentError,
+    },
+    testing::fake_engine::FakeEngine,
+};
+
+use crate::fixtures::{create_temp_directory, live_engine_url, write_temp_file, DUMMY_TOKEN};
+
+const SIMPLE_PROCESS_XML: &str = include_str!("../fixtures/bpmn/simple_process.bpmn");
+const ORDER_PROCESS_XML: &str = include_str!("../fixtures/bpmn/order_process.bpmn");
+
+/// Creates a directory with the simple process at the top level and the order process in a subdirectory.
+fn create_process_directory() -> PathBuf {
+    let directory = create_temp_directory();
+    write_temp_file(&directory, "simple_process.bpmn", SIMPLE_PROCESS_XML);
+    write_temp_file(&directory, "orders/order_process.bpmn", ORDER_PROCESS_XML);
+    write_temp_file(&directory, "README.md", "Not a process definition");
+    directory
+}
+
+/// Returns the given XML with other indentation, like an outdated version of the same ProcessDefinition.
+fn reformat(xml: &str) -> String {
+    xml.replace("  ", "    ")
+}
+
+/// Starts a FakeEngine on which the given ProcessDefinitions are deployed.
+async fn start_engine(deployed: &[&str]) -> (FakeEngine, ClientFactory) {
+    let engine = FakeEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    for xml in deployed {
+        client_factory
+            .create_process_definition_client()
+            .upload_process_definition(PersistProcessDefinitionPayload {
+                xml: xml.to_string(),
+                overwrite_existing: false,
+            })
+            .await
+            .unwrap();
+    }
+
+    (engine, client_factory)
+}
+
+fn create_deployer(client_factory: &ClientFactory) -> Deployer {
+    Deployer::new(client_factory.create_process_definition_client())
+}
+
+/// Returns the XML the engine holds for the given ProcessDefinition.
+async fn deployed_xml(client_factory: &ClientFactory, process_definition_id: &str) -> String {
+    client_factory
+        .create_process_definition_client()
+        .get_process_definition_by_id(process_definition_id)
+        .await
+        .unwrap()
+        .xml
+}
+
+// Happy cases
+#[tokio::test]
+async fn plan_deployment() {
+    let outdated_order_xml = reformat(ORDER_PROCESS_XML);
+    let (_engine, client_factory) = start_engine(&[SIMPLE_PROCESS_XML, &outdated_order_xml]).await;
+    let directory = create_process_directory();
+
+    let plan = create_deployer(&client_factory)
+        .plan(&directory)
+        .await
+        .unwrap();
+
+    assert_eq!(plan.definitions.len(), 2);
+    assert_eq!(
+        plan.definitions[0].process_definition_id,
+        "Definitions_Order"
+    );
+    assert_eq!(plan.definitions[0].action, DeploymentAction::Update);
+    assert_eq!(
+        plan.definitions[0].path,
+        directory.join("orders/order_process.bpmn")
+    );
+    assert_eq!(
+        plan.definitions[1].process_definition_id,
+        "Definitions_Simple"
+    );
+    assert_eq!(plan.definitions[1].action, DeploymentAction::Unchanged);
+    assert!(plan.has_changes());
+    assert!(plan.to_string().contains("update    Definitions_Order"));
+    assert_eq!(
+        deployed_xml(&client_factory, "Definitions_Order").await,
+        outdated_order_xml
+    );
+}
+
+#[tokio::test]
+async fn deploy_uploads_new_and_changed_definitions() {
+    let (_engine, client_factory) = start_engine(&[&reformat(ORDER_PROCESS_XML)]).await;
+    let directory = create_process_directory();
+
+    let summary = create_deployer(&client_factory)
+        .deploy_directory(&directory)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        summary,
+        DeploymentSummary {
+            created: vec!["Definitions_Simple".to_string()],
+            updated: vec!["Definitions_Order".to_string()],
+            unchanged: vec![],
+        }
+    );
+    assert_eq!(summary.to_string(), "Created: 1, updated: 1, unchanged: 0");
+    assert_eq!(
+        deployed_xml(&client_factory, "Definitions_Simple").await,
+        SIMPLE_PROCESS_XML
+    );
+    assert_eq!(
+        deployed_xml(&client_factory, "Definitions_Order").await,
+        ORDER_PROCESS_XML
+    );
+}
+
+#[tokio::test]
+async fn deploy_skips_unchanged_definitions() {
+    let (_engine, client_factory) = start_engine(&[SIMPLE_PROCESS_XML, ORDER_PROCESS_XML]).await;
+    let directory = create_process_directory();
+
+    let summary = create_deployer(&client_factory)
+        .deploy_directory(&directory)
+        .await
+        .unwrap();
+
+    assert_eq!(summary.unchanged.len(), 2);
+    assert!(summary.created.is_empty());
+    assert!(summary.updated.is_empty());
+}
+
+#[tokio::test]
+async fn plan_updates_definitions_with_reformatted_xml() {
+    let (_engine, client_factory) = start_engine(&[&reformat(SIMPLE_PROCESS_XML)]).await;
+    let directory = create_temp_directory();
+    write_temp_file(&directory, "simple_process.bpmn", SIMPLE_PROCESS_XML);
+
+    let plan = create_deployer(&client_factory)
+        .plan(&directory)
+        .await
+        .unwrap();
+
+    assert_eq!(plan.definitions[0].action, DeploymentAction::Update);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn plan_follows_symlinked_files_but_not_directories() {
+    let (_engine, client_factory) = start_engine(&[]).await;
+    let linked_directory = create_temp_directory();
+    write_temp_file(&linked_directory, "simple_process.bpmn", SIMPLE_PROCESS_XML);
+    let directory = create_temp_directory();
+    write_temp_file(&directory, "orders/order_process.bpmn", ORDER_PROCESS_XML);
+    std::os::unix::fs::symlink(
+        linked_directory.join("simple_process.bpmn"),
+        directory.join("simple_process.bpmn"),
+    )
+    .unwrap();
+    // Following this link would recurse until the stack overflows
+    std::os::unix::fs::symlink(&directory, directory.join("orders/loop")).unwrap();
+
+    let plan = create_deployer(&client_factory)
+        .plan(&directory)
+        .await
+        .unwrap();
+
+    let paths: Vec<_> = plan
+        .definitions
+        .iter()
+        .map(|definition| definition.path.clone())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            directory.join("orders/order_process.bpmn"),
+            directory.join("simple_process.bpmn")
+        ]
+    );
+}
+
+// Against a live engine
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn plan_against_live_engine() {
+    let client_factory = ClientFactory::new(&live_engine_url(), DUMMY_TOKEN);
+    let deployer = create_deployer(&client_factory);
+    let directory = create_process_directory();
+
+    deployer.deploy_directory(&directory).await.unwrap();
+    let plan = deployer.plan(&directory).await.unwrap();
+    assert!(!plan.has_changes(), "{}", plan);
+
+    write_temp_file(
+        &directory,
+        "orders/order_process.bpmn",
+        &reformat(ORDER_PROCESS_XML),
+    );
+    let plan = deployer.plan(&directory).await.unwrap();
+    let actions: Vec<_> = plan
+        .definitions
+        .iter()
+        .map(|definition| (definition.process_definition_id.as_str(), definition.action))
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            ("Definitions_Order", DeploymentAction::Update),
+            ("Definitions_Simple", DeploymentAction::Unchanged),
+        ]
+    );
+}
+
+// Error cases
+#[tokio::test]
+async fn plan_with_invalid_definition() {
+    let (_engine, client_factory) = start_engine(&[]).await;
+    let directory = create_process_directory();
+    write_temp_file(&directory, "broken.bpmn", "foo");
+
+    let result = create_deployer(&client_factory).plan(&directory).await;
+
+    match result {
+        Err(DeploymentError::InvalidDefinition { path, .. }) => {
+            assert_eq!(path, directory.join("broken.bpmn"))
+        }
+        other => panic!("Expected invalid definition, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn plan_with_definition_without_id() {
+    let (_engine, client_factory) = start_engine(&[]).await;
+    let directory = create_temp_directory();
+    write_temp_file(
+        &directory,
+        "without_id.bpmn",
+        &SIMPLE_PROCESS_XML.replace("id=\"Definitions_Simple\"", "id=\"\""),
+    );
+
+    let result = create_deployer(&client_factory).plan(&directory).await;
+
+    match result {
+        Err(DeploymentError::InvalidDefinition { path, .. }) => {
+            assert_eq!(path, directory.join("without_id.bpmn"))
+        }
+        other => panic!("Expected invalid definition, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn plan_with_duplicate_definition() {
+    let (_engine, client_factory) = start_engine(&[]).await;
+    let directory = create_process_directory();
+    write_temp_file(&directory, "copy.bpmn", SIMPLE_PROCESS_XML);
+
+    let result = create_deployer(&client_factory).plan(&directory).await;
+
+    assert!(matches!(
+        result,
+        Err(DeploymentError::DuplicateDefinition { id, .. }) if id == "Definitions_Simple"
+    ));
+}
+
+#[tokio::test]
+async fn plan_with_missing_directory() {
+    let (_engine, client_factory) = start_engine(&[]).await;
+    let directory = create_temp_directory().join("missing");
+
+    let result = create_deployer(&client_factory).plan(&directory).await;
+
+    assert!(matches!(result, Err(DeploymentError::Io { .. })));
+}
+
+#[tokio::test]
+async fn deploy_with_rejected_definition() {
+    let (_engine, client_factory) = start_engine(&[]).await;
+    let directory = create_process_directory();
+    let deployer = create_deployer(&client_factory);
+
+    let plan = deployer.plan(&directory).await.unwrap();
+    // Deployed by someone else after planning, so creating it again conflicts
+    client_factory
+        .create_process_definition_client()
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: SIMPLE_PROCESS_XML.to_string(),
+            overwrite_existing: false,
+        })
+        .await
+        .unwrap();
+    let result = deployer.deploy(&plan).await;
+
+    match result {
+        Err(DeploymentError::PartialDeployment { summary, source }) => {
+            assert_eq!(
+                summary,
+                DeploymentSummary {
+                    created: vec!["Definitions_Order".to_string()],
+                    ..Default::default()
+                }
+            );
+            match *source {
+                DeploymentError::Engine { id, source } => {
+                    assert_eq!(id.as_deref(), Some("Definitions_Simple"));
+                    assert_eq!(source.status(), Some(409));
+                }
+                other => panic!("Expected engine error, got {:?}", other),
+            }
+        }
+        other => panic!("Expected partial deployment, got {:?}", other),
+    }
+}
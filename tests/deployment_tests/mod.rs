@@ -0,0 +1,2 @@
+mod deployer;
+mod synchronizer;
@@ -0,0 +1,284 @@
+use std::path::PathBuf;
+
+use processcube_engine_client::{
+    clients::{
+        client_factory::ClientFactory, error::EngineError,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+    },
+    deployment::{
+        deployer::DeploymentSummary,
+        error::DeploymentError,
+        manifest::SyncManifest,
+        synchronizer::{SyncSummary, Synchronizer},
+    },
+    testing::fake_engine::FakeEngine,
+};
+use serde_json::{json, Value};
+
+use crate::fixtures::{create_temp_directory, live_engine_url, write_temp_file, DUMMY_TOKEN};
+
+const SIMPLE_PROCESS_XML: &str = include_str!("../fixtures/bpmn/simple_process.bpmn");
+const ORDER_PROCESS_XML: &str = include_str!("../fixtures/bpmn/order_process.bpmn");
+
+/// Returns the simple process as a ProcessDefinition with the given ID and a ProcessModel with the given ID.
+fn simple_definition(process_definition_id: &str, process_model_id: &str) -> String {
+    SIMPLE_PROCESS_XML
+        .replace("Definitions_Simple", process_definition_id)
+        .replace("Simple_Process", process_model_id)
+}
+
+/// Starts a FakeEngine with the simple process, an enabled legacy and a disabled paused
+/// ProcessModel, and an obsolete ProcessDefinition.
+async fn start_engine() -> (FakeEngine, ClientFactory) {
+    let engine = FakeEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let process_definition_client = client_factory.create_process_definition_client();
+    for xml in [
+        SIMPLE_PROCESS_XML.to_string(),
+        simple_definition("Definitions_Legacy", "Legacy_Process"),
+        simple_definition("Definitions_Paused", "Paused_Process"),
+        simple_definition("Definitions_Obsolete", "Obsolete_Process"),
+    ] {
+        process_definition_client
+            .upload_process_definition(PersistProcessDefinitionPayload {
+                xml,
+                overwrite_existing: false,
+            })
+            .await
+            .unwrap();
+    }
+    client_factory
+        .create_process_model_client()
+        .disable_process_model_by_id("Paused_Process")
+        .await
+        .unwrap();
+
+    (engine, client_factory)
+}
+
+/// Returns whether the given ProcessModel is deployed and enabled.
+async fn is_enabled(client_factory: &ClientFactory, process_model_id: &str) -> bool {
+    client_factory
+        .create_process_model_client()
+        .get_process_model_by_id(process_model_id)
+        .await
+        .unwrap()
+        .is_executable
+}
+
+/// Writes the simple and the order process along with a manifest referring to them.
+fn write_manifest(manifest: Value) -> PathBuf {
+    let directory = create_temp_directory();
+    write_temp_file(
+        &directory,
+        "processes/simple_process.bpmn",
+        SIMPLE_PROCESS_XML,
+    );
+    write_temp_file(
+        &directory,
+        "processes/order_process.bpmn",
+        ORDER_PROCESS_XML,
+    );
+    write_temp_file(&directory, "manifest.json", &manifest.to_string());
+    directory.join("manifest.json")
+}
+
+fn create_synchronizer(client_factory: &ClientFactory) -> Synchronizer {
+    Synchronizer::new(
+        client_factory.create_process_definition_client(),
+        client_factory.create_process_model_client(),
+    )
+}
+
+// Happy cases
+#[tokio::test]
+async fn sync_with_manifest() {
+    let (_engine, client_factory) = start_engine().await;
+    let manifest_path = write_manifest(json!({
+        "directory": "processes",
+        "enabledProcessModels": ["Simple_Process", "Order_Process", "Paused_Process"],
+        "disabledProcessModels": ["Legacy_Process"],
+        "removedProcessDefinitions": ["Definitions_Obsolete", "Definitions_Gone"],
+        "deleteAllRelatedData": true,
+    }));
+    let synchronizer = create_synchronizer(&client_factory);
+
+    let manifest = SyncManifest::from_file(&manifest_path).unwrap();
+    let plan = synchronizer.plan(&manifest).await.unwrap();
+    let summary = synchronizer.apply(&plan).await.unwrap();
+
+    assert_eq!(
+        plan.to_string(),
+        format!(
+            "+ create    Definitions_Order ({})\n~ enable    Paused_Process\n~ disable   Legacy_Process\n- delete    Definitions_Obsolete (with all related data)",
+            manifest_path
+                .parent()
+                .unwrap()
+                .join("processes/order_process.bpmn")
+                .display()
+        )
+    );
+    assert_eq!(
+        summary,
+        SyncSummary {
+            deployment: DeploymentSummary {
+                created: vec!["Definitions_Order".to_string()],
+                updated: vec![],
+                unchanged: vec!["Definitions_Simple".to_string()],
+            },
+            enabled: vec!["Paused_Process".to_string()],
+            disabled: vec!["Legacy_Process".to_string()],
+            deleted: vec!["Definitions_Obsolete".to_string()],
+        }
+    );
+    assert!(is_enabled(&client_factory, "Order_Process").await);
+    assert!(is_enabled(&client_factory, "Paused_Process").await);
+    assert!(!is_enabled(&client_factory, "Legacy_Process").await);
+    let result = client_factory
+        .create_process_definition_client()
+        .get_process_definition_by_id("Definitions_Obsolete")
+        .await;
+    assert!(matches!(result, Err(EngineError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn sync_without_changes() {
+    let (_engine, client_factory) = start_engine().await;
+    let manifest = SyncManifest {
+        enabled_process_models: vec!["Legacy_Process".to_string()],
+        disabled_process_models: vec!["Paused_Process".to_string()],
+        removed_process_definitions: vec!["Definitions_Gone".to_string()],
+        ..Default::default()
+    };
+    let synchronizer = create_synchronizer(&client_factory);
+
+    let plan = synchronizer.plan(&manifest).await.unwrap();
+    let summary = synchronizer.apply(&plan).await.unwrap();
+
+    assert!(!plan.has_changes());
+    assert_eq!(plan.to_string(), "No changes.");
+    assert_eq!(summary, SyncSummary::default());
+}
+
+// Against a live engine
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn sync_with_manifest_on_live_engine() {
+    let client_factory = ClientFactory::new(&live_engine_url(), DUMMY_TOKEN);
+    let synchronizer = create_synchronizer(&client_factory);
+    let sync = |enabled: &str, disabled: &str| {
+        write_manifest(json!({
+            "directory": "processes",
+            "enabledProcessModels": [enabled],
+            "disabledProcessModels": [disabled],
+        }))
+    };
+
+    for (enabled, disabled) in [
+        ("Simple_Process", "Order_Process"),
+        ("Order_Process", "Simple_Process"),
+    ] {
+        let manifest = SyncManifest::from_file(sync(enabled, disabled)).unwrap();
+        let plan = synchronizer.plan(&manifest).await.unwrap();
+        synchronizer.apply(&plan).await.unwrap();
+
+        assert!(is_enabled(&client_factory, enabled).await, "{}", enabled);
+        assert!(!is_enabled(&client_factory, disabled).await, "{}", disabled);
+    }
+}
+
+// Error cases
+#[tokio::test]
+async fn sync_with_contradicting_manifest() {
+    let (_engine, client_factory) = start_engine().await;
+    let manifest = SyncManifest {
+        enabled_process_models: vec!["Legacy_Process".to_string()],
+        disabled_process_models: vec!["Legacy_Process".to_string()],
+        ..Default::default()
+    };
+
+    let result = create_synchronizer(&client_factory).plan(&manifest).await;
+
+    assert!(matches!(result, Err(DeploymentError::InvalidManifest(_))));
+}
+
+#[tokio::test]
+async fn sync_removing_deployed_definition() {
+    let (_engine, client_factory) = start_engine().await;
+    let manifest_path = write_manifest(json!({
+        "directory": "processes",
+        "removedProcessDefinitions": ["Definitions_Simple"],
+    }));
+    let manifest = SyncManifest::from_file(&manifest_path).unwrap();
+
+    let result = create_synchronizer(&client_factory).plan(&manifest).await;
+
+    assert!(matches!(result, Err(DeploymentError::InvalidManifest(_))));
+}
+
+#[tokio::test]
+async fn sync_with_unknown_process_model() {
+    let (_engine, client_factory) = start_engine().await;
+    let manifest = SyncManifest {
+        enabled_process_models: vec!["Unknown_Process".to_string()],
+        ..Default::default()
+    };
+
+    let result = create_synchronizer(&client_factory).plan(&manifest).await;
+
+    assert!(matches!(
+        result,
+        Err(DeploymentError::UnknownProcessModel(id)) if id == "Unknown_Process"
+    ));
+}
+
+#[test]
+fn sync_with_malformed_manifest() {
+    let directory = create_temp_directory();
+    write_temp_file(&directory, "manifest.json", "{ \"directory\": 42 }");
+
+    let result = SyncManifest::from_file(directory.join("manifest.json"));
+
+    assert!(matches!(result, Err(DeploymentError::InvalidManifest(_))));
+}
+
+#[tokio::test]
+async fn sync_with_rejected_request() {
+    let (_engine, client_factory) = start_engine().await;
+    let manifest = SyncManifest {
+        enabled_process_models: vec!["Paused_Process".to_string()],
+        disabled_process_models: vec!["Legacy_Process".to_string()],
+        ..Default::default()
+    };
+    let synchronizer = create_synchronizer(&client_factory);
+
+    let plan = synchronizer.plan(&manifest).await.unwrap();
+    // Removed by someone else after planning, so it can't be disabled anymore
+    client_factory
+        .create_process_definition_client()
+        .delete_process_definition_by_id("Definitions_Legacy", None)
+        .await
+        .unwrap();
+    let result = synchronizer.apply(&plan).await;
+
+    match result {
+        Err(DeploymentError::PartialSync { summary, source }) => {
+            assert_eq!(
+                *summary,
+                SyncSummary {
+                    enabled: vec!["Paused_Process".to_string()],
+                    ..Default::default()
+                }
+            );
+            match *source {
+                DeploymentError::Engine { id, source } => {
+                    assert_eq!(id.as_deref(), Some("Legacy_Process"));
+                    assert_eq!(source.status(), Some(404));
+                }
+                other => panic!("Expected engine error, got {:?}", other),
+            }
+        }
+        other => panic!("Expected partial sync, got {:?}", other),
+    }
+}
@@ -0,0 +1,169 @@
+use processcube_engine_client::clients::{client_factory::ClientFactory, error::EngineError};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const CORRELATIONS_PATH: &str = "/atlas_engine/api/v1/correlations";
+
+async fn get_correlations_answered_with(response: ResponseTemplate) -> EngineError {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(response)
+        .mount(&engine)
+        .await;
+
+    ClientFactory::new(&engine.uri(), DUMMY_TOKEN)
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap_err()
+}
+
+fn engine_error(error_class_name: &str, code: u16) -> ResponseTemplate {
+    ResponseTemplate::new(code).set_body_json(json!({
+        "errorClassName": error_class_name,
+        "message": "Something went wrong",
+        "callStack": "at somewhere",
+        "code": code,
+        "additionalInformation": { "hint": "sample" },
+    }))
+}
+
+// Error cases
+
+#[tokio::test]
+async fn forbidden_response_is_mapped() {
+    let err = get_correlations_answered_with(engine_error("ForbiddenError", 403)).await;
+
+    assert!(matches!(err, EngineError::Forbidden(_)));
+    assert_eq!(err.status(), Some(403));
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn conflict_response_is_mapped() {
+    let err = get_correlations_answered_with(engine_error("ConflictError", 409)).await;
+
+    assert!(matches!(err, EngineError::Conflict(_)));
+    assert_eq!(err.status(), Some(409));
+}
+
+#[tokio::test]
+async fn engine_error_details_are_kept() {
+    let err = get_correlations_answered_with(engine_error("ServiceUnavailableError", 503)).await;
+
+    assert!(matches!(err, EngineError::Engine(_)));
+    assert!(err.is_retryable());
+
+    let details = err.details().unwrap();
+    assert_eq!(details.error_class_name, "ServiceUnavailableError");
+    assert_eq!(details.message, "Something went wrong");
+    assert_eq!(details.call_stack.as_deref(), Some("at somewhere"));
+    assert_eq!(
+        details.additional_information,
+        Some(json!({ "hint": "sample" }))
+    );
+}
+
+#[tokio::test]
+async fn malformed_response_is_a_deserialization_error() {
+    let err = get_correlations_answered_with(
+        ResponseTemplate::new(200).set_body_json(json!({ "unexpected": true })),
+    )
+    .await;
+
+    assert!(matches!(err, EngineError::Deserialization(_)));
+    assert_eq!(err.status(), None);
+}
+
+#[tokio::test]
+async fn refused_connection_is_a_retryable_transport_error() {
+    // Bind and release a port so nothing is listening on it.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let engine_url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let err = ClientFactory::new(&engine_url, DUMMY_TOKEN)
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, EngineError::Transport(_)));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn html_response_keeps_status_headers_and_body() {
+    let html = "<html><body><h1>502 Bad Gateway</h1></body></html>";
+    let err =
+        get_correlations_answered_with(ResponseTemplate::new(502).set_body_raw(html, "text/html"))
+            .await;
+
+    assert!(matches!(err, EngineError::Engine(_)));
+    assert_eq!(err.status(), Some(502));
+    assert!(err.is_retryable());
+
+    let raw_response = err.details().unwrap().raw_response.as_ref().unwrap();
+    assert_eq!(raw_response.status, 502);
+    assert_eq!(raw_response.headers["content-type"], "text/html");
+    assert_eq!(raw_response.body, html);
+}
+
+#[tokio::test]
+async fn empty_response_keeps_status() {
+    let err = get_correlations_answered_with(ResponseTemplate::new(404)).await;
+
+    assert!(matches!(err, EngineError::NotFound(_)));
+    assert_eq!(err.status(), Some(404));
+
+    let details = err.details().unwrap();
+    assert_eq!(details.error_class_name, "UnexpectedResponseError");
+    assert_eq!(details.raw_response.as_ref().unwrap().body, "");
+}
+
+#[tokio::test]
+async fn truncated_response_keeps_body() {
+    let truncated = r#"{"errorClassName": "InternalServerError", "mess"#;
+    let err = get_correlations_answered_with(
+        ResponseTemplate::new(500).set_body_raw(truncated, "application/json"),
+    )
+    .await;
+
+    assert!(matches!(err, EngineError::Engine(_)));
+    assert_eq!(err.status(), Some(500));
+
+    let details = err.details().unwrap();
+    assert_eq!(details.error_class_name, "UnexpectedResponseError");
+    assert_eq!(details.raw_response.as_ref().unwrap().body, truncated);
+}
+
+#[tokio::test]
+async fn engine_error_keeps_raw_response() {
+    let err = get_correlations_answered_with(engine_error("ForbiddenError", 403)).await;
+
+    let raw_response = err.details().unwrap().raw_response.as_ref().unwrap();
+    assert_eq!(raw_response.status, 403);
+    assert!(raw_response.body.contains("ForbiddenError"));
+}
+
+#[tokio::test]
+async fn proxied_engine_error_has_status_of_response() {
+    let response = ResponseTemplate::new(502).set_body_json(json!({
+        "errorClassName": "InternalServerError",
+        "message": "Something went wrong",
+        "callStack": "",
+        "code": 500,
+    }));
+    let err = get_correlations_answered_with(response).await;
+
+    assert!(matches!(err, EngineError::Engine(_)));
+    assert_eq!(err.status(), Some(502));
+    assert_eq!(err.details().unwrap().code, 500);
+    assert!(err.is_retryable());
+}
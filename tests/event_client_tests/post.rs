@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use processcube_engine_client::clients::{client_factory::ClientFactory, error::EngineError};
+use serde::Serialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_API_PATH: &str = "/atlas_engine/api/v1";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderReceived {
+    order_id: u32,
+    items: Vec<String>,
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn trigger_message_sends_payload_as_json_object() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/messages/Message_1/trigger",
+            ENGINE_API_PATH
+        )))
+        .and(body_json(
+            json!({ "payload": { "orderId": 42, "approved": true } }),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client_factory = ClientFactory::new(&server.uri(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+
+    let result = client
+        .trigger_message(
+            "Message_1",
+            None,
+            Some(json!({ "orderId": 42, "approved": true })),
+        )
+        .await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn trigger_message_without_payload_sends_empty_object() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/messages/Message_1/trigger",
+            ENGINE_API_PATH
+        )))
+        .and(body_json(json!({})))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client_factory = ClientFactory::new(&server.uri(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+
+    let result = client.trigger_message("Message_1", None, None).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn trigger_message_with_payload_serializes_payload() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/messages/Message_1/trigger",
+            ENGINE_API_PATH
+        )))
+        .and(body_json(json!({
+            "payload": { "orderId": 7, "items": ["apple", "pear"] }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client_factory = ClientFactory::new(&server.uri(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+
+    let payload = OrderReceived {
+        order_id: 7,
+        items: vec!["apple".to_string(), "pear".to_string()],
+    };
+    let result = client
+        .trigger_message_with_payload("Message_1", None, &payload)
+        .await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn trigger_signal_for_process_instance_sends_payload_to_signals_endpoint() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/signals/Signal_1/trigger",
+            ENGINE_API_PATH
+        )))
+        .and(query_param("processInstanceId", "ProcessInstance_1"))
+        .and(body_json(json!({ "payload": ["a", "b"] })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client_factory = ClientFactory::new(&server.uri(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+
+    let result = client
+        .trigger_signal_with_payload("Signal_1", Some("ProcessInstance_1"), &["a", "b"])
+        .await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn trigger_signal_invalid_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "{}/signals/Signal_1/trigger",
+            ENGINE_API_PATH
+        )))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "errorClassName": "BadRequestError",
+            "message": "Must provide a token by which to create an identity!",
+            "callStack": "",
+            "code": 400,
+            "fatal": false
+        })))
+        .mount(&server)
+        .await;
+
+    let client_factory = ClientFactory::new(&server.uri(), "foo");
+    let client = client_factory.create_event_client();
+
+    let result = client.trigger_signal("Signal_1", None, None).await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
+}
+
+#[tokio::test]
+async fn trigger_message_with_unserializable_payload() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client_factory = ClientFactory::new(&server.uri(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+    // JSON objects only have string keys
+    let payload = HashMap::from([((1, 2), "a")]);
+
+    let result = client
+        .trigger_message_with_payload("Message_1", None, &payload)
+        .await;
+    assert!(matches!(result, Err(EngineError::Deserialization(_))));
+}
@@ -0,0 +1,2 @@
+mod new;
+mod run;
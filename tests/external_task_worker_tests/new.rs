@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use processcube_engine_client::{
+    clients::{
+        client_factory::ClientFactory, error::EngineError,
+        external_task::external_task::ExternalTask,
+    },
+    worker::external_task_worker::{ExternalTaskWorker, ExternalTaskWorkerConfig},
+};
+use serde_json::json;
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+/// Creates a worker with the given config and returns the error, if it is rejected.
+fn create_worker(config: ExternalTaskWorkerConfig) -> Option<EngineError> {
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move { Ok(json!({})) },
+        config,
+    )
+    .err()
+}
+
+// Happy cases
+
+#[test]
+fn new_accepts_default_config() {
+    assert!(create_worker(ExternalTaskWorkerConfig::default()).is_none());
+}
+
+// Error cases
+
+#[test]
+fn new_rejects_zero_max_concurrent_tasks() {
+    let config = ExternalTaskWorkerConfig {
+        max_concurrent_tasks: 0,
+        ..ExternalTaskWorkerConfig::default()
+    };
+
+    assert!(matches!(
+        create_worker(config),
+        Some(EngineError::Configuration(_))
+    ));
+}
+
+#[test]
+fn new_rejects_zero_lock_extension_interval() {
+    let config = ExternalTaskWorkerConfig {
+        lock_extension_interval: Duration::ZERO,
+        ..ExternalTaskWorkerConfig::default()
+    };
+
+    assert!(matches!(
+        create_worker(config),
+        Some(EngineError::Configuration(_))
+    ));
+}
+
+#[test]
+fn new_rejects_lock_extension_interval_not_shorter_than_lock_duration() {
+    for lock_extension_interval in [Duration::from_secs(30), Duration::from_secs(60)] {
+        let config = ExternalTaskWorkerConfig {
+            lock_duration: Duration::from_secs(30),
+            lock_extension_interval,
+            ..ExternalTaskWorkerConfig::default()
+        };
+
+        assert!(matches!(
+            create_worker(config),
+            Some(EngineError::Configuration(_))
+        ));
+    }
+}
@@ -0,0 +1,509 @@
+use std::time::Duration;
+
+use processcube_engine_client::{
+    clients::{
+        client_factory::ClientFactory, error::EngineError,
+        external_task::external_task::ExternalTask,
+    },
+    worker::{
+        external_task_handler::ExternalTaskError,
+        external_task_worker::{ExternalTaskWorker, ExternalTaskWorkerConfig},
+    },
+};
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use wiremock::{
+    matchers::{body_partial_json, method, path, path_regex},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::{engine_error_response, start_mock_engine};
+
+const EXTERNAL_TASKS_PATH: &str = "/atlas_engine/api/v1/external_tasks";
+const WORKER_ID: &str = "test_worker";
+
+fn external_task_json(id: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "workerId": WORKER_ID,
+        "topic": "test_topic",
+        "flowNodeInstanceId": "FlowNodeInstance_1",
+        "correlationId": "Correlation_1",
+        "processDefinitionId": "ProcessDefinition_1",
+        "processInstanceId": "ProcessInstance_1",
+        "payload": { "value": 42 },
+        "state": "pending"
+    })
+}
+
+fn worker_config() -> ExternalTaskWorkerConfig {
+    ExternalTaskWorkerConfig {
+        worker_id: WORKER_ID.to_string(),
+        max_concurrent_tasks: 2,
+        long_polling_timeout: Duration::from_millis(50),
+        lock_duration: Duration::from_millis(500),
+        lock_extension_interval: Duration::from_millis(50),
+        error_backoff: Duration::from_millis(10),
+        shutdown_timeout: Duration::from_secs(1),
+    }
+}
+
+/// Starts a stub engine that hands out the given ExternalTasks once and then long-polls empty lists.
+async fn start_stub_engine(tasks: Vec<serde_json::Value>) -> (MockServer, ClientFactory) {
+    let (server, client_factory) = start_mock_engine().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/fetch_and_lock", EXTERNAL_TASKS_PATH)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tasks))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/fetch_and_lock", EXTERNAL_TASKS_PATH)))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([]))
+                .set_delay(Duration::from_millis(50)),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/Task_1/extend_lock", EXTERNAL_TASKS_PATH)))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    (server, client_factory)
+}
+
+/// Waits until the stub engine received a request to the given path.
+async fn wait_for_request(server: &MockServer, request_path: &str) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let requests = server.received_requests().await.unwrap();
+            if requests.iter().any(|r| r.url.path() == request_path) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("Expected a request to {}", request_path));
+}
+
+async fn count_requests(server: &MockServer, request_path: &str) -> usize {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.url.path() == request_path)
+        .count()
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn run_finishes_external_task() {
+    let (server, client_factory) = start_stub_engine(vec![external_task_json("Task_1")]).await;
+    let finish_path = format!("{}/Task_1/finish", EXTERNAL_TASKS_PATH);
+    Mock::given(method("PUT"))
+        .and(path(finish_path.as_str()))
+        .and(body_partial_json(json!({
+            "workerId": WORKER_ID,
+            "result": { "doubled": 84 }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |task: ExternalTask| async move {
+            let value = task.payload["value"].as_i64().unwrap();
+            Ok(json!({ "doubled": value * 2 }))
+        },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    wait_for_request(&server, &finish_path).await;
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn run_reports_bpmn_error() {
+    let (server, client_factory) = start_stub_engine(vec![external_task_json("Task_1")]).await;
+    let error_path = format!("{}/Task_1/error", EXTERNAL_TASKS_PATH);
+    Mock::given(method("PUT"))
+        .and(path(error_path.as_str()))
+        .and(body_partial_json(json!({
+            "workerId": WORKER_ID,
+            "error": { "errorCode": "InvalidValue", "errorMessage": "Value is invalid" }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move {
+            Err(ExternalTaskError::bpmn("InvalidValue", "Value is invalid"))
+        },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    wait_for_request(&server, &error_path).await;
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn run_handles_external_tasks_concurrently() {
+    let (server, client_factory) = start_stub_engine(vec![
+        external_task_json("Task_1"),
+        external_task_json("Task_2"),
+    ])
+    .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/Task_2/extend_lock", EXTERNAL_TASKS_PATH)))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    for task_id in ["Task_1", "Task_2"] {
+        Mock::given(method("PUT"))
+            .and(path(format!("{}/{}/finish", EXTERNAL_TASKS_PATH, task_id)))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    // Both handlers only complete if they are running at the same time.
+    let barrier = std::sync::Arc::new(tokio::sync::Barrier::new(2));
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        move |_task: ExternalTask| {
+            let barrier = barrier.clone();
+            async move {
+                barrier.wait().await;
+                Ok(json!({}))
+            }
+        },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    wait_for_request(&server, &format!("{}/Task_1/finish", EXTERNAL_TASKS_PATH)).await;
+    wait_for_request(&server, &format!("{}/Task_2/finish", EXTERNAL_TASKS_PATH)).await;
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn run_handles_more_external_tasks_than_requested() {
+    let tasks = vec![
+        external_task_json("Task_1"),
+        external_task_json("Task_2"),
+        external_task_json("Task_3"),
+    ];
+    let (server, client_factory) = start_stub_engine(tasks).await;
+    Mock::given(method("PUT"))
+        .and(path_regex(format!(
+            "^{}/Task_[0-9]+/(finish|extend_lock)$",
+            EXTERNAL_TASKS_PATH
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move { Ok(json!({})) },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    // The engine hands out three tasks although the worker only has two free slots.
+    let finish_path = format!("{}/Task_3/finish", EXTERNAL_TASKS_PATH);
+    wait_for_request(&server, &finish_path).await;
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+
+    for id in ["Task_1", "Task_2", "Task_3"] {
+        let finish_path = format!("{}/{}/finish", EXTERNAL_TASKS_PATH, id);
+        assert_eq!(count_requests(&server, &finish_path).await, 1);
+    }
+}
+
+#[tokio::test]
+async fn run_extends_lock_while_handler_is_running() {
+    let (server, client_factory) = start_stub_engine(vec![external_task_json("Task_1")]).await;
+    let finish_path = format!("{}/Task_1/finish", EXTERNAL_TASKS_PATH);
+    Mock::given(method("PUT"))
+        .and(path(finish_path.as_str()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            Ok(json!({}))
+        },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    wait_for_request(&server, &finish_path).await;
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+
+    let extend_lock_path = format!("{}/Task_1/extend_lock", EXTERNAL_TASKS_PATH);
+    assert!(count_requests(&server, &extend_lock_path).await >= 2);
+}
+
+#[tokio::test]
+async fn run_waits_for_running_handlers_on_shutdown() {
+    let (server, client_factory) = start_stub_engine(vec![external_task_json("Task_1")]).await;
+    let finish_path = format!("{}/Task_1/finish", EXTERNAL_TASKS_PATH);
+    Mock::given(method("PUT"))
+        .and(path(finish_path.as_str()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let (started_sender, started_receiver) = tokio::sync::oneshot::channel::<()>();
+    let started_sender = std::sync::Mutex::new(Some(started_sender));
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        move |_task: ExternalTask| {
+            let started_sender = started_sender.lock().unwrap().take();
+            async move {
+                if let Some(started_sender) = started_sender {
+                    let _ = started_sender.send(());
+                }
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                Ok(json!({}))
+            }
+        },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    started_receiver.await.unwrap();
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+
+    assert_eq!(count_requests(&server, &finish_path).await, 1);
+}
+
+#[tokio::test]
+async fn run_handles_external_task_while_lock_extension_is_pending() {
+    let (server, client_factory) = start_stub_engine(vec![external_task_json("Task_1")]).await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/Task_1/extend_lock", EXTERNAL_TASKS_PATH)))
+        .respond_with(ResponseTemplate::new(204).set_delay(Duration::from_secs(10)))
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    let finish_path = format!("{}/Task_1/finish", EXTERNAL_TASKS_PATH);
+    Mock::given(method("PUT"))
+        .and(path(finish_path.as_str()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok(json!({}))
+        },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    // The first lock extension is still pending when the handler is done.
+    tokio::time::timeout(
+        Duration::from_secs(2),
+        wait_for_request(&server, &finish_path),
+    )
+    .await
+    .expect("Expected the ExternalTask to be finished before the lock extension completes");
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn run_stops_waiting_for_running_handlers_after_shutdown_timeout() {
+    let (server, client_factory) = start_stub_engine(vec![external_task_json("Task_1")]).await;
+    let finish_path = format!("{}/Task_1/finish", EXTERNAL_TASKS_PATH);
+
+    let (started_sender, started_receiver) = tokio::sync::oneshot::channel::<()>();
+    let started_sender = std::sync::Mutex::new(Some(started_sender));
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        move |_task: ExternalTask| {
+            let started_sender = started_sender.lock().unwrap().take();
+            async move {
+                if let Some(started_sender) = started_sender {
+                    let _ = started_sender.send(());
+                }
+                std::future::pending::<()>().await;
+                Ok(json!({}))
+            }
+        },
+        ExternalTaskWorkerConfig {
+            shutdown_timeout: Duration::from_millis(100),
+            ..worker_config()
+        },
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    started_receiver.await.unwrap();
+    shutdown.cancel();
+    tokio::time::timeout(Duration::from_secs(5), running_worker)
+        .await
+        .expect("Expected the worker to stop")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(count_requests(&server, &finish_path).await, 0);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn run_keeps_polling_after_failed_fetch() {
+    let (server, client_factory) = start_mock_engine().await;
+    let fetch_path = format!("{}/fetch_and_lock", EXTERNAL_TASKS_PATH);
+    Mock::given(method("POST"))
+        .and(path(fetch_path.as_str()))
+        .respond_with(engine_error_response(
+            "InternalServerError",
+            "Something went wrong",
+            500,
+        ))
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move { Ok(json!({})) },
+        worker_config(),
+    )
+    .unwrap();
+
+    let shutdown = CancellationToken::new();
+    let running_worker = tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move { worker.run(shutdown).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    shutdown.cancel();
+    running_worker.await.unwrap().unwrap();
+
+    assert!(count_requests(&server, &fetch_path).await >= 2);
+}
+
+#[tokio::test]
+async fn run_fails_if_token_is_rejected() {
+    let (server, client_factory) = start_mock_engine().await;
+    let fetch_path = format!("{}/fetch_and_lock", EXTERNAL_TASKS_PATH);
+    Mock::given(method("POST"))
+        .and(path(fetch_path.as_str()))
+        .respond_with(engine_error_response(
+            "UnauthorizedError",
+            "Invalid token",
+            401,
+        ))
+        .mount(&server)
+        .await;
+
+    let worker = ExternalTaskWorker::new(
+        client_factory.create_external_task_client(),
+        "test_topic",
+        |_task: ExternalTask| async move { Ok(json!({})) },
+        worker_config(),
+    )
+    .unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(5), worker.run(CancellationToken::new()))
+        .await
+        .expect("Expected the worker to stop");
+
+    assert!(matches!(result, Err(EngineError::Unauthorized(_))));
+}
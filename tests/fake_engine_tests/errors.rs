@@ -0,0 +1,100 @@
+use processcube_engine_client::{
+    clients::{
+        api::transport::ReqwestTransport, client_factory::ClientFactory,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+        process_model::process_model::ProcessStartRequest, user_task::user_task::UserTaskResult,
+    },
+    testing::{
+        cassette::{Cassette, RecordingTransport},
+        fake_engine::FakeEngine,
+    },
+};
+
+use crate::fixtures::{live_engine_url, DUMMY_TOKEN};
+
+const SIMPLE_PROCESS_XML: &str = include_str!("../fixtures/bpmn/simple_process.bpmn");
+
+/// Sends requests the ProcessCube® Engine rejects to the engine at the given URL and records its responses.
+async fn record_rejected_requests(engine_url: &str) -> Cassette {
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory =
+        ClientFactory::new(engine_url, DUMMY_TOKEN).with_transport(recorder.clone());
+    let process_definition_client = client_factory.create_process_definition_client();
+    let process_model_client = client_factory.create_process_model_client();
+    let upload = |overwrite_existing| PersistProcessDefinitionPayload {
+        xml: SIMPLE_PROCESS_XML.to_string(),
+        overwrite_existing,
+    };
+
+    process_definition_client
+        .upload_process_definition(upload(true))
+        .await
+        .unwrap();
+    let _ = process_definition_client
+        .upload_process_definition(upload(false))
+        .await;
+    let _ = process_definition_client
+        .get_process_definition_by_id("Definitions_Unknown")
+        .await;
+    let _ = process_model_client
+        .get_process_model_by_id("Unknown_Process")
+        .await;
+    let _ = process_model_client
+        .start_process_instance_by_process_model_id(
+            "Unknown_Process",
+            ProcessStartRequest::default(),
+        )
+        .await;
+    process_model_client
+        .disable_process_model_by_id("Simple_Process")
+        .await
+        .unwrap();
+    let _ = process_model_client
+        .start_process_instance_by_process_model_id(
+            "Simple_Process",
+            ProcessStartRequest::default(),
+        )
+        .await;
+    process_model_client
+        .enable_process_model_by_id("Simple_Process")
+        .await
+        .unwrap();
+    let _ = client_factory
+        .create_process_instance_client()
+        .terminate_process_instance_by_id("Unknown_ProcessInstance")
+        .await;
+    let _ = client_factory
+        .create_user_task_client()
+        .finish_user_task("Unknown_FlowNodeInstance", UserTaskResult::new())
+        .await;
+
+    recorder.cassette()
+}
+
+// Error cases
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn rejected_requests_are_answered_like_engine() {
+    let live = record_rejected_requests(&live_engine_url()).await;
+    let engine = FakeEngine::start().await;
+    let fake = record_rejected_requests(&engine.url()).await;
+
+    assert_eq!(live.interactions.len(), fake.interactions.len());
+    for (live, fake) in live.interactions.iter().zip(&fake.interactions) {
+        let request = format!("{} {}", live.request.method, live.request.path);
+        assert_eq!(live.request.path, fake.request.path);
+        assert_eq!(live.response.status, fake.response.status, "{}", request);
+
+        let error_fields = |json: &Option<serde_json::Value>| {
+            json.as_ref()
+                .map(|json| (json["errorClassName"].clone(), json["code"].clone()))
+        };
+        assert_eq!(
+            error_fields(&live.response.json),
+            error_fields(&fake.response.json),
+            "{}",
+            request
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use processcube_engine_client::{
+    clients::{client_factory::ClientFactory, error::EngineError},
+    testing::fake_engine::{EventKind, FakeEngine, TriggeredEvent},
+};
+use serde_json::json;
+
+use crate::fixtures::DUMMY_TOKEN;
+
+// Happy cases
+
+#[tokio::test]
+async fn triggered_events_are_recorded() {
+    let engine = FakeEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+
+    client
+        .trigger_message("order_received", None, Some(json!({ "orderId": 42 })))
+        .await
+        .unwrap();
+    client
+        .trigger_signal("order_shipped", None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        engine.triggered_events(),
+        vec![
+            TriggeredEvent {
+                kind: EventKind::Message,
+                name: "order_received".to_string(),
+                process_instance_id: None,
+                payload: Some(json!({ "orderId": 42 })),
+            },
+            TriggeredEvent {
+                kind: EventKind::Signal,
+                name: "order_shipped".to_string(),
+                process_instance_id: None,
+                payload: None,
+            },
+        ]
+    );
+}
+
+// Error cases
+
+#[tokio::test]
+async fn trigger_message_for_unknown_process_instance() {
+    let engine = FakeEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_event_client();
+
+    let result = client
+        .trigger_message("order_received", Some("foo"), None)
+        .await;
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, EngineError::NotFound(_)));
+    assert_eq!(
+        err.details().unwrap().message,
+        "Process instance with ID `foo` not found."
+    );
+    assert!(engine.triggered_events().is_empty());
+}
@@ -0,0 +1,3 @@
+mod errors;
+mod events;
+mod process_instances;
@@ -0,0 +1,294 @@
+use processcube_engine_client::{
+    clients::{
+        api::transport::ReqwestTransport,
+        client_factory::ClientFactory,
+        error::EngineError,
+        flow_node_instance::flow_node_instance::FlowNodeInstancesQuery,
+        process_definition::process_definition::PersistProcessDefinitionPayload,
+        process_instance::process_instance::{ProcessInstanceState, ProcessInstancesQuery},
+        process_model::process_model::{ProcessStartRequest, ReturnOn, WaitOptions},
+        user_task::user_task::{UserTaskResult, UserTasksQuery},
+    },
+    testing::{cassette::RecordingTransport, fake_engine::FakeEngine},
+};
+use serde_json::{json, Value};
+
+use crate::fixtures::{live_engine_url, DUMMY_TOKEN};
+
+const SIMPLE_PROCESS_XML: &str = include_str!("../fixtures/bpmn/simple_process.bpmn");
+const APPROVAL_PROCESS_XML: &str = include_str!("../fixtures/bpmn/approval_process.bpmn");
+
+async fn start_engine(xml: &str) -> (FakeEngine, ClientFactory) {
+    let engine = FakeEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    client_factory
+        .create_process_definition_client()
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: xml.to_string(),
+            overwrite_existing: false,
+        })
+        .await
+        .unwrap();
+
+    (engine, client_factory)
+}
+
+/// Runs the simple process to its end on the engine at the given URL and returns
+/// the FlowNodeInstances documents it responds with, sorted by flow node ID.
+async fn record_flow_node_instances(engine_url: &str) -> Vec<Value> {
+    let recorder = RecordingTransport::new(ReqwestTransport::default());
+    let client_factory =
+        ClientFactory::new(engine_url, DUMMY_TOKEN).with_transport(recorder.clone());
+    client_factory
+        .create_process_definition_client()
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: SIMPLE_PROCESS_XML.to_string(),
+            overwrite_existing: true,
+        })
+        .await
+        .unwrap();
+    let request = ProcessStartRequest::builder()
+        .return_on(ReturnOn::OnProcessInstanceFinished)
+        .build()
+        .unwrap();
+    let response = client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id("Simple_Process", request)
+        .await
+        .unwrap();
+    let query = FlowNodeInstancesQuery {
+        process_instance_id: Some(response.process_instance_id),
+        ..Default::default()
+    };
+    client_factory
+        .create_flow_node_instance_client()
+        .get_flow_node_instances(None, None, Some(query))
+        .await
+        .unwrap();
+
+    let cassette = recorder.cassette();
+    let response = cassette.interactions.last().unwrap().response.json.clone();
+    let mut flow_node_instances = match response.unwrap()["flowNodeInstances"].take() {
+        Value::Array(flow_node_instances) => flow_node_instances,
+        other => panic!("Expected FlowNodeInstances, got {:?}", other),
+    };
+    flow_node_instances.sort_by_key(|instance| instance["flowNodeId"].to_string());
+    flow_node_instances
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn start_and_wait_returns_end_token() {
+    let (_engine, client_factory) = start_engine(SIMPLE_PROCESS_XML).await;
+    let request = ProcessStartRequest::builder()
+        .initial_token(&json!({ "orderId": 42 }))
+        .return_on(ReturnOn::OnProcessInstanceFinished)
+        .build()
+        .unwrap();
+
+    let result = client_factory
+        .create_process_model_client()
+        .start_and_wait::<Value>("Simple_Process", request, WaitOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(result.end_event_id, Some("Event_1lpuf0o".to_string()));
+    assert_eq!(result.token, json!({ "orderId": 42 }));
+
+    let correlation = client_factory
+        .create_correlation_client()
+        .get_correlation_by_id(&result.correlation_id)
+        .await
+        .unwrap();
+    let process_instances = correlation.process_instances.unwrap();
+    assert_eq!(process_instances.len(), 1);
+    assert_eq!(process_instances[0].state, ProcessInstanceState::Finished);
+}
+
+#[tokio::test]
+async fn process_instance_waits_at_user_task() {
+    let (_engine, client_factory) = start_engine(APPROVAL_PROCESS_XML).await;
+    let user_task_client = client_factory.create_user_task_client();
+    let process_instance_client = client_factory.create_process_instance_client();
+
+    let response = client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id(
+            "Approval_Process",
+            ProcessStartRequest::default(),
+        )
+        .await
+        .unwrap();
+
+    let query = UserTasksQuery {
+        process_instance_id: Some(response.process_instance_id.clone()),
+        state: Some("suspended".to_string()),
+        ..Default::default()
+    };
+    let user_tasks = user_task_client
+        .get_user_tasks(None, None, Some(query))
+        .await
+        .unwrap();
+    assert_eq!(user_tasks.total_count, 1);
+    let user_task = &user_tasks.user_tasks[0];
+    assert_eq!(user_task.flow_node_id, "Task_Approve");
+    assert_eq!(
+        user_task.process_model_name.as_deref(),
+        Some("Approval Process")
+    );
+
+    user_task_client
+        .reserve_user_task(&user_task.flow_node_instance_id, "clerk")
+        .await
+        .unwrap();
+    let reserved = user_task_client
+        .get_user_task_by_id(&user_task.flow_node_instance_id)
+        .await
+        .unwrap();
+    assert_eq!(reserved.actual_owner_id.as_deref(), Some("clerk"));
+
+    user_task_client
+        .finish_user_task(
+            &user_task.flow_node_instance_id,
+            UserTaskResult::new().with_field("approved", true),
+        )
+        .await
+        .unwrap();
+    let process_instance = process_instance_client
+        .get_process_instance_by_id(&response.process_instance_id)
+        .await
+        .unwrap();
+    assert_eq!(process_instance.state, ProcessInstanceState::Finished);
+    assert_eq!(
+        process_instance.end_token,
+        Some(json!({ "approved": true }))
+    );
+}
+
+#[tokio::test]
+async fn terminate_process_instance() {
+    let (_engine, client_factory) = start_engine(APPROVAL_PROCESS_XML).await;
+    let process_instance_client = client_factory.create_process_instance_client();
+
+    let response = client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id(
+            "Approval_Process",
+            ProcessStartRequest::default(),
+        )
+        .await
+        .unwrap();
+    process_instance_client
+        .terminate_process_instance_by_id(&response.process_instance_id)
+        .await
+        .unwrap();
+
+    let query = ProcessInstancesQuery {
+        state: Some("terminated".to_string()),
+        ..Default::default()
+    };
+    let process_instances = process_instance_client
+        .get_process_instances(None, None, Some(query))
+        .await
+        .unwrap();
+    assert_eq!(process_instances.total_count, 1);
+    assert_eq!(
+        process_instances.process_instances[0].terminated_by_user_id,
+        Some("ZHVtbXlfdG9rZW4=".to_string())
+    );
+
+    let user_tasks = client_factory
+        .create_user_task_client()
+        .get_user_tasks(None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(user_tasks.user_tasks[0].state.as_str(), "terminated");
+}
+
+// Golden cases against a live engine
+
+#[tokio::test]
+#[ignore = "needs a live ProcessCube® Engine in PROCESSCUBE_ENGINE_URL"]
+async fn flow_node_instances_are_documented_like_engine() {
+    let live = record_flow_node_instances(&live_engine_url()).await;
+    let engine = FakeEngine::start().await;
+    let fake = record_flow_node_instances(&engine.url()).await;
+
+    let keys = |instance: &Value| {
+        let mut keys: Vec<String> = instance.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        keys
+    };
+    assert_eq!(live.len(), fake.len());
+    for (live, fake) in live.iter().zip(&fake) {
+        assert_eq!(live["flowNodeId"], fake["flowNodeId"]);
+        assert_eq!(
+            live["flowNodeType"], fake["flowNodeType"],
+            "{}",
+            live["flowNodeId"]
+        );
+        assert_eq!(keys(live), keys(fake), "{}", live["flowNodeId"]);
+    }
+}
+
+// Error cases
+
+#[tokio::test]
+async fn start_disabled_process_model() {
+    let (_engine, client_factory) = start_engine(SIMPLE_PROCESS_XML).await;
+    let process_model_client = client_factory.create_process_model_client();
+
+    process_model_client
+        .disable_process_model_by_id("Simple_Process")
+        .await
+        .unwrap();
+    let result = process_model_client
+        .start_process_instance_by_process_model_id(
+            "Simple_Process",
+            ProcessStartRequest::default(),
+        )
+        .await;
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(
+        err.details().unwrap().message,
+        "Process model `Simple_Process` is not executable."
+    );
+}
+
+#[tokio::test]
+async fn upload_existing_process_definition() {
+    let (_engine, client_factory) = start_engine(SIMPLE_PROCESS_XML).await;
+
+    let result = client_factory
+        .create_process_definition_client()
+        .upload_process_definition(PersistProcessDefinitionPayload {
+            xml: SIMPLE_PROCESS_XML.to_string(),
+            overwrite_existing: false,
+        })
+        .await;
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, EngineError::Conflict(_)));
+    assert_eq!(err.details().unwrap().error_class_name, "ConflictError");
+}
+
+#[tokio::test]
+async fn unknown_route() {
+    let engine = FakeEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+
+    let result = client_factory
+        .create_external_task_client()
+        .extend_lock("foo", "bar", 1000)
+        .await;
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(404));
+    assert_eq!(
+        err.details().unwrap().error_class_name,
+        "UnexpectedResponseError"
+    );
+}
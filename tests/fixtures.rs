@@ -1,5 +1,87 @@
+use processcube_engine_client::{
+    clients::{
+        api::auth_provider::{AuthProvider, StaticTokenProvider},
+        client_factory::ClientFactory,
+    },
+    testing::fake_engine::FakeEngine,
+};
+use serde_json::json;
+use wiremock::{MockServer, ResponseTemplate};
+
 pub const DUMMY_TOKEN: &str = "Bearer ZHVtbXlfdG9rZW4=";
-pub const ENGINE_URL: &str = "http://localhost:10560";
+
+/// The environment variable with the URL of a live ProcessCube® Engine to run the client tests against.
+const ENGINE_URL_VARIABLE: &str = "PROCESSCUBE_ENGINE_URL";
+
+/// The engine the client tests run against.
+///
+/// This is a FakeEngine, unless `PROCESSCUBE_ENGINE_URL` is set to the URL of a live ProcessCube® Engine,
+/// e.g. `http://localhost:10560`. Running the tests against a live engine checks their expectations,
+/// and with them the FakeEngine, against the output of the real one.
+pub enum TestEngine {
+    Fake(FakeEngine),
+    Live(String),
+}
+
+impl TestEngine {
+    pub async fn start() -> TestEngine {
+        match std::env::var(ENGINE_URL_VARIABLE) {
+            Ok(engine_url) => TestEngine::Live(engine_url),
+            Err(_) => TestEngine::Fake(FakeEngine::start().await),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        match self {
+            TestEngine::Fake(engine) => engine.url(),
+            TestEngine::Live(engine_url) => engine_url.clone(),
+        }
+    }
+}
+
+/// Returns the URL of the live ProcessCube® Engine set in `PROCESSCUBE_ENGINE_URL`.
+///
+/// Tests that compare this crate against the real engine are ignored by default and need it to be set,
+/// e.g. `PROCESSCUBE_ENGINE_URL=http://localhost:10560 cargo test -- --ignored`.
+pub fn live_engine_url() -> String {
+    std::env::var(ENGINE_URL_VARIABLE).unwrap_or_else(|_| {
+        panic!(
+            "Set {} to the URL of a live ProcessCube® Engine to run this test",
+            ENGINE_URL_VARIABLE
+        )
+    })
+}
+
+/// Starts a MockServer standing in for the ProcessCube® Engine and returns it with a ClientFactory for it.
+///
+/// Use it instead of the FakeEngine for responses the engine gives rarely, e.g. failures or delays,
+/// and to check the requests the clients send.
+pub async fn start_mock_engine() -> (MockServer, ClientFactory) {
+    start_mock_engine_with_auth_provider(StaticTokenProvider::new(DUMMY_TOKEN)).await
+}
+
+/// Like `start_mock_engine`, with the clients asking the given AuthProvider for their tokens.
+pub async fn start_mock_engine_with_auth_provider<P>(
+    auth_provider: P,
+) -> (MockServer, ClientFactory)
+where
+    P: AuthProvider + 'static,
+{
+    let mock_server = MockServer::start().await;
+    let client_factory = ClientFactory::with_auth_provider(&mock_server.uri(), auth_provider);
+    (mock_server, client_factory)
+}
+
+/// Returns a response with an error document of the ProcessCube® Engine.
+pub fn engine_error_response(error_class_name: &str, message: &str, code: u16) -> ResponseTemplate {
+    ResponseTemplate::new(code).set_body_json(json!({
+        "errorClassName": error_class_name,
+        "message": message,
+        "callStack": "",
+        "code": code,
+        "fatal": false
+    }))
+}
 
 pub fn get_valid_process_definition_xml() -> String {
     let rand_id = uuid::Uuid::new_v4().to_string();
@@ -10,3 +92,20 @@ pub fn get_valid_process_definition_xml() -> String {
 
     xml
 }
+
+/// Creates an empty directory that is unique to the calling test.
+pub fn create_temp_directory() -> std::path::PathBuf {
+    let directory = std::env::temp_dir().join(format!(
+        "processcube_engine_client_{}",
+        uuid::Uuid::new_v4()
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/// Writes a file relative to the given directory, creating missing subdirectories.
+pub fn write_temp_file(directory: &std::path::Path, name: &str, content: &str) {
+    let path = directory.join(name);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
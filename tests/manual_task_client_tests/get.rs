@@ -0,0 +1,35 @@
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory, manual_task::manual_task::ManualTasksQuery,
+};
+
+use crate::fixtures::{TestEngine, DUMMY_TOKEN};
+
+// Happy cases
+
+#[tokio::test]
+async fn get_manual_tasks() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_manual_task_client();
+
+    let result = client.get_manual_tasks(None, None, None).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn get_manual_tasks_with_query() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_manual_task_client();
+
+    let query = ManualTasksQuery {
+        process_instance_id: Some("foo".to_string()),
+        ..Default::default()
+    };
+    let result = client.get_manual_tasks(None, Some(10), Some(query)).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+    assert_eq!(result.unwrap().total_count, 0);
+}
+
+// Error cases
+// None
@@ -0,0 +1,23 @@
+use processcube_engine_client::clients::client_factory::ClientFactory;
+
+use crate::fixtures::TestEngine;
+
+// Error cases
+
+#[tokio::test]
+async fn finish_manual_task_invalid_token() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
+    let client = client_factory.create_manual_task_client();
+
+    let result = client.finish_manual_task("foo").await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "Must provide a token by which to create an identity!"
+    )
+}
@@ -0,0 +1,121 @@
+use http::{Request, Response};
+use processcube_engine_client::clients::{
+    api::{
+        auth_provider::StaticTokenProvider,
+        middleware::{AuthLayer, Body, EngineErrorLayer, TowerTransport},
+    },
+    client_factory::ClientFactory,
+    error::EngineError,
+};
+use serde_json::json;
+use tower::{BoxError, ServiceBuilder, ServiceExt};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+fn not_found() -> Response<Body> {
+    Response::builder()
+        .status(404)
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({
+                "errorClassName": "NotFoundError",
+                "message": "Correlation with ID `unknown` not found.",
+                "code": 404
+            })
+            .to_string(),
+        ))
+        .unwrap()
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn auth_layer_sets_authorization_header() {
+    let service = ServiceBuilder::new()
+        .layer(AuthLayer::new(StaticTokenProvider::new(DUMMY_TOKEN)))
+        .service_fn(|request: Request<Body>| async move {
+            let authorization = request.headers()["Authorization"].clone();
+            Ok::<_, BoxError>(Response::new(Body::from(authorization.as_bytes().to_vec())))
+        });
+
+    let response = service.oneshot(Request::new(Body::new())).await.unwrap();
+
+    assert_eq!(response.into_body(), DUMMY_TOKEN.as_bytes());
+}
+
+#[tokio::test]
+async fn auth_layer_authenticates_requests_of_client_without_token() {
+    let service = ServiceBuilder::new()
+        .layer(AuthLayer::new(StaticTokenProvider::new(DUMMY_TOKEN)))
+        .service_fn(|request: Request<Body>| async move {
+            let authorization: Vec<_> = request.headers().get_all("Authorization").iter().collect();
+            assert_eq!(authorization, vec![DUMMY_TOKEN]);
+            let body = json!({ "correlations": [], "totalCount": 0 }).to_string();
+            Ok::<_, BoxError>(Response::new(Body::from(body)))
+        });
+    let client_factory = ClientFactory::builder(ENGINE_URL)
+        .transport(TowerTransport::new(service))
+        .build()
+        .unwrap();
+
+    let correlations = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap();
+
+    assert_eq!(correlations.total_count, 0);
+}
+
+#[tokio::test]
+async fn engine_error_layer_passes_successful_responses() {
+    let service = ServiceBuilder::new()
+        .layer(EngineErrorLayer::new())
+        .service_fn(|_: Request<Body>| async {
+            Ok::<_, BoxError>(Response::new(Body::from_static(b"{}")))
+        });
+
+    let response = service.oneshot(Request::new(Body::new())).await.unwrap();
+
+    assert_eq!(response.status(), 200);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn engine_error_layer_converts_error_responses() {
+    let service = ServiceBuilder::new()
+        .layer(EngineErrorLayer::new())
+        .service_fn(|_: Request<Body>| async { Ok::<_, BoxError>(not_found()) });
+
+    let err = service
+        .oneshot(Request::new(Body::new()))
+        .await
+        .unwrap_err();
+
+    match err.downcast::<EngineError>().map(|err| *err) {
+        Ok(EngineError::NotFound(details)) => {
+            assert_eq!(details.message, "Correlation with ID `unknown` not found.")
+        }
+        err => panic!("Unexpected error: {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn engine_errors_of_the_service_stack_are_returned_unchanged() {
+    let service = ServiceBuilder::new()
+        .layer(EngineErrorLayer::new())
+        .service_fn(|_: Request<Body>| async { Ok::<_, BoxError>(not_found()) });
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(TowerTransport::new(service));
+
+    let err = client_factory
+        .create_correlation_client()
+        .get_correlation_by_id("unknown")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, EngineError::NotFound(_)));
+}
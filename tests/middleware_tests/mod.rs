@@ -0,0 +1,2 @@
+mod layers;
+mod tower_transport;
@@ -0,0 +1,140 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use http::{HeaderValue, Request, Response};
+use processcube_engine_client::clients::{
+    api::middleware::{Body, TowerTransport},
+    client_factory::ClientFactory,
+    error::{EngineError, NetworkErrorKind},
+};
+use serde_json::json;
+use tower::{load_shed::error::Overloaded, BoxError, ServiceBuilder};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+fn empty_correlation_list() -> Response<Body> {
+    Response::builder()
+        .status(200)
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({ "correlations": [], "totalCount": 0 }).to_string(),
+        ))
+        .unwrap()
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn requests_are_sent_through_the_service_stack() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let service = ServiceBuilder::new()
+        .map_request(|mut request: Request<Body>| {
+            request
+                .headers_mut()
+                .insert("X-Tenant-Id", HeaderValue::from_static("sample_tenant"));
+            request
+        })
+        .service_fn({
+            let received = received.clone();
+            move |request: Request<Body>| {
+                received.lock().unwrap().push(request);
+                async { Ok::<_, BoxError>(empty_correlation_list()) }
+            }
+        });
+    let client_factory = ClientFactory::builder(ENGINE_URL)
+        .auth_token(DUMMY_TOKEN)
+        .transport(TowerTransport::new(service))
+        .build()
+        .unwrap();
+
+    let correlations = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap();
+
+    let received = received.lock().unwrap();
+    assert_eq!(correlations.total_count, 0);
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0].uri(),
+        "http://localhost:10560/atlas_engine/api/v1/correlations"
+    );
+    assert_eq!(received[0].headers()["Authorization"], DUMMY_TOKEN);
+    assert_eq!(received[0].headers()["X-Tenant-Id"], "sample_tenant");
+}
+
+// Error cases
+
+#[tokio::test]
+async fn timeouts_of_the_service_stack_are_returned_as_network_errors() {
+    let service = ServiceBuilder::new()
+        .timeout(Duration::from_millis(50))
+        .service_fn(|_: Request<Body>| async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok::<_, BoxError>(empty_correlation_list())
+        });
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(TowerTransport::new(service));
+
+    let err = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        EngineError::Network {
+            kind: NetworkErrorKind::Timeout,
+            ..
+        }
+    ));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn rejections_of_the_load_shedding_layer_are_returned_as_network_errors() {
+    let service = ServiceBuilder::new().service_fn(|_: Request<Body>| async {
+        Err::<Response<Body>, BoxError>(Box::new(Overloaded::new()))
+    });
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(TowerTransport::new(service));
+
+    let err = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        EngineError::Network {
+            kind: NetworkErrorKind::Connect,
+            ..
+        }
+    ));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn other_errors_of_the_service_stack_are_returned() {
+    let service = ServiceBuilder::new().service_fn(|_: Request<Body>| async {
+        Err::<Response<Body>, BoxError>("rate limit exceeded".into())
+    });
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(TowerTransport::new(service));
+
+    let err = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, EngineError::Service(_)));
+    assert!(!err.is_retryable());
+}
@@ -1,5 +1,23 @@
 mod application_info_client_tests;
+mod auth_provider_tests;
+mod bpmn_tests;
+mod cassette_tests;
 mod client_factory_tests;
 mod correlation_client_tests;
+mod deployment_tests;
+mod engine_error_tests;
+mod event_client_tests;
+mod external_task_worker_tests;
+mod fake_engine_tests;
 mod fixtures;
+mod manual_task_client_tests;
+mod middleware_tests;
+mod pagination_tests;
 mod process_definition_client_tests;
+mod process_instance_client_tests;
+mod process_model_client_tests;
+mod retry_policy_tests;
+mod tracing_tests;
+mod transport_tests;
+mod untyped_task_client_tests;
+mod user_task_client_tests;
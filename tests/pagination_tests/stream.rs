@@ -0,0 +1,188 @@
+use futures::{pin_mut, StreamExt};
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory, error::EngineError, pagination::collect_all,
+    untyped_task::untyped_task::UntypedTasksQuery,
+};
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const PROCESS_DEFINITIONS_PATH: &str = "/atlas_engine/api/v1/process_definitions";
+const UNTYPED_TASKS_PATH: &str = "/atlas_engine/api/v1/untyped_tasks";
+
+fn process_definition(id: &str) -> Value {
+    json!({
+        "processDefinitionId": id,
+        "xml": "",
+        "hash": "",
+        "processModels": [],
+        "deployedAt": "",
+        "deployedByUserId": "",
+    })
+}
+
+fn untyped_task(id: &str) -> Value {
+    json!({
+        "flowNodeInstanceId": id,
+        "flowNodeId": "Task_1",
+        "correlationId": "correlation_id",
+        "processDefinitionId": "sample_definition",
+        "processInstanceId": "instance_id",
+        "processModelId": "sample_process",
+        "ownerId": "owner_id",
+        "state": "suspended",
+    })
+}
+
+async fn mount_process_definition_page(engine: &MockServer, offset: u32, ids: &[&str]) {
+    Mock::given(method("GET"))
+        .and(path(PROCESS_DEFINITIONS_PATH))
+        .and(query_param("offset", offset.to_string()))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "processDefinitions": ids.iter().map(|id| process_definition(id)).collect::<Vec<_>>(),
+            "totalCount": 5,
+        })))
+        .expect(1)
+        .mount(engine)
+        .await;
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn stream_fetches_pages_until_total_count() {
+    let engine = MockServer::start().await;
+    mount_process_definition_page(&engine, 0, &["a", "b"]).await;
+    mount_process_definition_page(&engine, 2, &["c", "d"]).await;
+    mount_process_definition_page(&engine, 4, &["e"]).await;
+
+    let client = ClientFactory::new(&engine.uri(), DUMMY_TOKEN).create_process_definition_client();
+    let process_definitions = collect_all(client.get_process_definitions_stream(Some(2)))
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = process_definitions
+        .iter()
+        .map(|pd| pd.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["a", "b", "c", "d", "e"]);
+}
+
+#[tokio::test]
+async fn stream_fetches_pages_lazily() {
+    let engine = MockServer::start().await;
+    mount_process_definition_page(&engine, 0, &["a", "b"]).await;
+
+    let client = ClientFactory::new(&engine.uri(), DUMMY_TOKEN).create_process_definition_client();
+    let first_two: Vec<_> = client
+        .get_process_definitions_stream(Some(2))
+        .take(2)
+        .collect()
+        .await;
+
+    assert_eq!(first_two.len(), 2);
+    assert!(first_two.iter().all(|result| result.is_ok()));
+}
+
+#[tokio::test]
+async fn stream_keeps_query_for_every_page() {
+    let engine = MockServer::start().await;
+    for (offset, ids) in [(0, vec!["a", "b"]), (2, vec!["c"])] {
+        Mock::given(method("GET"))
+            .and(path(UNTYPED_TASKS_PATH))
+            .and(query_param("processModelId", "sample_process"))
+            .and(query_param("offset", offset.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "untypedTasks": ids.iter().map(|id| untyped_task(id)).collect::<Vec<_>>(),
+                "totalCount": 3,
+            })))
+            .expect(1)
+            .mount(&engine)
+            .await;
+    }
+
+    let client = ClientFactory::new(&engine.uri(), DUMMY_TOKEN).create_untyped_task_client();
+    let query = UntypedTasksQuery {
+        process_model_id: Some("sample_process".to_string()),
+        ..Default::default()
+    };
+    let untyped_tasks = collect_all(client.get_untyped_tasks_stream(Some(query), Some(2)))
+        .await
+        .unwrap();
+
+    assert_eq!(untyped_tasks.len(), 3);
+}
+
+#[tokio::test]
+async fn stream_stops_at_empty_page() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_DEFINITIONS_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "processDefinitions": [],
+            "totalCount": 10,
+        })))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client = ClientFactory::new(&engine.uri(), DUMMY_TOKEN).create_process_definition_client();
+    let process_definitions = collect_all(client.get_process_definitions_stream(None))
+        .await
+        .unwrap();
+
+    assert!(process_definitions.is_empty());
+}
+
+// Error cases
+
+#[tokio::test]
+async fn stream_ends_after_failed_page() {
+    let engine = MockServer::start().await;
+    mount_process_definition_page(&engine, 0, &["a", "b"]).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_DEFINITIONS_PATH))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client = ClientFactory::new(&engine.uri(), DUMMY_TOKEN).create_process_definition_client();
+    let stream = client.get_process_definitions_stream(Some(2));
+    pin_mut!(stream);
+
+    assert!(stream.next().await.unwrap().is_ok());
+    assert!(stream.next().await.unwrap().is_ok());
+    assert!(matches!(
+        stream.next().await.unwrap(),
+        Err(EngineError::Engine(_))
+    ));
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn stream_rejects_zero_page_size() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_DEFINITIONS_PATH))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&engine)
+        .await;
+
+    let client = ClientFactory::new(&engine.uri(), DUMMY_TOKEN).create_process_definition_client();
+    let stream = client.get_process_definitions_stream(Some(0));
+    pin_mut!(stream);
+
+    assert!(matches!(
+        stream.next().await.unwrap(),
+        Err(EngineError::Configuration(_))
+    ));
+    assert!(stream.next().await.is_none());
+}
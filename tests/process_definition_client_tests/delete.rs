@@ -3,13 +3,14 @@ use processcube_engine_client::clients::{
     process_definition::process_definition::PersistProcessDefinitionPayload,
 };
 
-use crate::fixtures::{get_valid_process_definition_xml, DUMMY_TOKEN, ENGINE_URL};
+use crate::fixtures::{get_valid_process_definition_xml, TestEngine, DUMMY_TOKEN};
 
 // Happy cases
 
 #[tokio::test]
 async fn delete_process_definition_by_id() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_process_definition_client();
 
     // Upload a process definition
@@ -42,7 +43,8 @@ async fn delete_process_definition_by_id() {
 
 #[tokio::test]
 async fn delete_process_definition_by_id_invalid_token() {
-    let client_factory = ClientFactory::new(ENGINE_URL, "foo");
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
     let client = client_factory.create_process_definition_client();
 
     // Delete a process definition by ID
@@ -52,10 +54,10 @@ async fn delete_process_definition_by_id_invalid_token() {
     assert!(result.is_err());
 
     let err = result.unwrap_err();
-    assert_eq!(err.code, 400);
-    assert_eq!(err.error_type, "BadRequestError");
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
     assert_eq!(
-        err.message,
+        err.details().unwrap().message,
         "Must provide a token by which to create an identity!"
     )
 }
@@ -1,15 +1,16 @@
 use processcube_engine_client::clients::{
-    client_factory::ClientFactory,
+    client_factory::ClientFactory, error::EngineError,
     process_definition::process_definition::PersistProcessDefinitionPayload,
 };
 
-use crate::fixtures::{get_valid_process_definition_xml, DUMMY_TOKEN, ENGINE_URL};
+use crate::fixtures::{get_valid_process_definition_xml, TestEngine, DUMMY_TOKEN};
 
 // Happy cases
 
 #[tokio::test]
 async fn get_process_definitions() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_process_definition_client();
 
     let result = client.get_process_definitions(None, None).await;
@@ -18,7 +19,8 @@ async fn get_process_definitions() {
 
 #[tokio::test]
 async fn get_process_definition_by_id() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_process_definition_client();
 
     // Upload a process definition
@@ -51,7 +53,8 @@ async fn get_process_definition_by_id() {
 
 #[tokio::test]
 async fn get_process_definition_by_id_invalid_token() {
-    let client_factory = ClientFactory::new(ENGINE_URL, "foo");
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
     let client = client_factory.create_process_definition_client();
 
     // Get a process definition by ID
@@ -59,17 +62,18 @@ async fn get_process_definition_by_id_invalid_token() {
     assert!(result.is_err());
 
     let err = result.unwrap_err();
-    assert_eq!(err.code, 400);
-    assert_eq!(err.error_type, "BadRequestError");
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
     assert_eq!(
-        err.message,
+        err.details().unwrap().message,
         "Must provide a token by which to create an identity!"
     )
 }
 
 #[tokio::test]
 async fn get_process_definition_by_id_not_found() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_process_definition_client();
 
     // Get a process definition by ID
@@ -77,7 +81,11 @@ async fn get_process_definition_by_id_not_found() {
     assert!(result.is_err());
 
     let err = result.unwrap_err();
-    assert_eq!(err.code, 404);
-    assert_eq!(err.error_type, "NotFoundError");
-    assert_eq!(err.message, "Process definition with name `foo` not found.");
+    assert!(matches!(err, EngineError::NotFound(_)));
+    assert_eq!(err.status(), Some(404));
+    assert_eq!(err.details().unwrap().error_class_name, "NotFoundError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "Process definition with name `foo` not found."
+    );
 }
@@ -5,10 +5,11 @@ use processcube_engine_client::clients::{
 
 // Happy cases
 
-use crate::fixtures::{get_valid_process_definition_xml, DUMMY_TOKEN, ENGINE_URL};
+use crate::fixtures::{get_valid_process_definition_xml, TestEngine, DUMMY_TOKEN};
 #[tokio::test]
 async fn upload_process_definition() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_process_definition_client();
 
     // Upload a process definition
@@ -23,14 +24,15 @@ async fn upload_process_definition() {
     // Assert that at least one process definition exists
     let result = client.get_process_definitions(None, None).await;
     assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
-    assert!(result.unwrap().process_definitions.len() > 0);
+    assert!(!result.unwrap().process_definitions.is_empty());
 }
 
 // Error cases
 
 #[tokio::test]
 async fn upload_process_definition_invalid_token() {
-    let client_factory = ClientFactory::new(ENGINE_URL, "foo");
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
     let client = client_factory.create_process_definition_client();
 
     // Upload a process definition
@@ -43,17 +45,18 @@ async fn upload_process_definition_invalid_token() {
     assert!(result.is_err());
 
     let err = result.unwrap_err();
-    assert_eq!(err.code, 400);
-    assert_eq!(err.error_type, "BadRequestError");
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
     assert_eq!(
-        err.message,
+        err.details().unwrap().message,
         "Must provide a token by which to create an identity!"
     )
 }
 
 #[tokio::test]
 async fn upload_process_definition_invalid_xml() {
-    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN);
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
     let client = client_factory.create_process_definition_client();
 
     // Upload a process definition
@@ -66,9 +69,11 @@ async fn upload_process_definition_invalid_xml() {
     assert!(result.is_err());
 
     let err = result.unwrap_err();
-    assert_eq!(err.code, 400);
-    assert_eq!(err.error_type, "BadRequestError");
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
     assert!(err
+        .details()
+        .unwrap()
         .message
         .starts_with("Error: Non-whitespace before first tag."));
 }
@@ -0,0 +1,73 @@
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory, error::EngineError,
+    process_instance::process_instance::ProcessInstancesQuery,
+};
+
+use crate::fixtures::{TestEngine, DUMMY_TOKEN};
+
+// Happy cases
+
+#[tokio::test]
+async fn get_process_instances() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_process_instance_client();
+
+    let result = client.get_process_instances(None, None, None).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn get_process_instances_with_query() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_process_instance_client();
+
+    let query = ProcessInstancesQuery {
+        state: Some("finished".to_string()),
+        ..Default::default()
+    };
+    let result = client
+        .get_process_instances(None, Some(10), Some(query))
+        .await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn get_process_instance_by_id_not_found() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_process_instance_client();
+
+    let result = client.get_process_instance_by_id("foo").await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, EngineError::NotFound(_)));
+    assert_eq!(err.status(), Some(404));
+    assert_eq!(err.details().unwrap().error_class_name, "NotFoundError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "Process instance with ID `foo` not found."
+    );
+}
+
+#[tokio::test]
+async fn terminate_process_instance_by_id_invalid_token() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
+    let client = client_factory.create_process_instance_client();
+
+    let result = client.terminate_process_instance_by_id("foo").await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "Must provide a token by which to create an identity!"
+    )
+}
@@ -0,0 +1,2 @@
+mod start_and_wait;
+mod start_request;
@@ -0,0 +1,446 @@
+use std::time::Duration;
+
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory,
+    error::EngineError,
+    process_instance::process_instance::ProcessInstanceState,
+    process_model::process_model::{
+        ProcessInstanceResult, ProcessStartRequest, ReturnOn, WaitOptions,
+    },
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_partial_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const START_PATH: &str = "/atlas_engine/api/v1/process_models/Order_Process/start";
+const PROCESS_INSTANCES_PATH: &str = "/atlas_engine/api/v1/process_instances/query";
+const FLOW_NODE_INSTANCES_PATH: &str = "/atlas_engine/api/v1/flow_node_instances";
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Order {
+    order_id: u32,
+    approved: bool,
+}
+
+fn start_request(return_on: ReturnOn, end_event_id: Option<&str>) -> ProcessStartRequest {
+    ProcessStartRequest {
+        start_event_id: Some("StartEvent_1".to_string()),
+        initial_token: Some(json!({ "orderId": 42 })),
+        return_on,
+        end_event_id: end_event_id.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn wait_options() -> WaitOptions {
+    WaitOptions {
+        poll_interval: Duration::from_millis(10),
+        timeout: Duration::from_secs(5),
+    }
+}
+
+fn process_instances(state: &str, end_token: Value) -> Value {
+    json!({
+        "processInstances": [{
+            "processInstanceId": "ProcessInstance_1",
+            "processDefinitionId": "Definitions_Order",
+            "processModelId": "Order_Process",
+            "correlationId": "Correlation_1",
+            "ownerId": "User_1",
+            "state": state,
+            "endToken": end_token,
+            "error": if state == "error" { json!({ "message": "Boom" }) } else { Value::Null },
+        }],
+        "totalCount": 1,
+    })
+}
+
+fn end_event_instances(end_token: Value) -> Value {
+    json!({
+        "flowNodeInstances": [{
+            "correlationId": "Correlation_1",
+            "flowNodeId": "EndEvent_Approved",
+            "flowNodeInstanceId": "FlowNodeInstance_1",
+            "flowNodeType": "EndEvent",
+            "ownerId": "User_1",
+            "processDefinitionId": "Definitions_Order",
+            "processInstanceId": "ProcessInstance_1",
+            "processModelId": "Order_Process",
+            "startToken": {},
+            "state": "finished",
+            "tokens": [],
+            "endToken": end_token,
+        }],
+        "totalCount": 1,
+    })
+}
+
+async fn start_engine(start_response: Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(START_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(start_response))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+fn started_response() -> Value {
+    json!({
+        "processInstanceId": "ProcessInstance_1",
+        "correlationId": "Correlation_1",
+    })
+}
+
+async fn start_and_wait<T: DeserializeOwned>(
+    mock_server: &MockServer,
+    request: ProcessStartRequest,
+    options: WaitOptions,
+) -> Result<ProcessInstanceResult<T>, EngineError> {
+    let client_factory = ClientFactory::new(&mock_server.uri(), DUMMY_TOKEN);
+    client_factory
+        .create_process_model_client()
+        .start_and_wait::<T>("Order_Process", request, options)
+        .await
+}
+
+// Happy cases
+#[tokio::test]
+async fn start_and_wait_with_immediate_result() {
+    let mock_server = start_engine(json!({
+        "processInstanceId": "ProcessInstance_1",
+        "correlationId": "Correlation_1",
+        "endEventId": "EndEvent_Approved",
+        "tokenPayload": { "orderId": 42, "approved": true },
+    }))
+    .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(ReturnOn::OnProcessInstanceFinished, None),
+        wait_options(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.process_instance_id, "ProcessInstance_1");
+    assert_eq!(result.end_event_id.as_deref(), Some("EndEvent_Approved"));
+    assert_eq!(
+        result.token,
+        Order {
+            order_id: 42,
+            approved: true
+        }
+    );
+}
+
+#[tokio::test]
+async fn start_and_wait_polls_process_instance() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("POST"))
+        .and(path(START_PATH))
+        .and(body_partial_json(
+            json!({ "returnOn": "onProcessInstanceStarted" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(started_response()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .and(query_param("processInstanceId", "ProcessInstance_1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(process_instances("running", Value::Null)),
+        )
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(process_instances(
+            "finished",
+            json!({ "orderId": 42, "approved": false }),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(ReturnOn::OnProcessInstanceStarted, None),
+        wait_options(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.correlation_id, "Correlation_1");
+    assert_eq!(result.end_event_id, None);
+    assert!(!result.token.approved);
+}
+
+#[tokio::test]
+async fn start_and_wait_polls_end_event() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(process_instances("running", Value::Null)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(FLOW_NODE_INSTANCES_PATH))
+        .and(query_param("flowNodeId", "EndEvent_Approved"))
+        .and(query_param("processInstanceId", "ProcessInstance_1"))
+        .and(query_param("state", "finished"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(end_event_instances(
+                json!({ "orderId": 42, "approved": true }),
+            )),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<Value>(
+        &mock_server,
+        start_request(
+            ReturnOn::OnProcessInstanceStarted,
+            Some("EndEvent_Approved"),
+        ),
+        wait_options(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.end_event_id.as_deref(), Some("EndEvent_Approved"));
+    assert_eq!(result.token, json!({ "orderId": 42, "approved": true }));
+}
+
+#[tokio::test]
+async fn start_and_wait_returns_same_token_without_end_token() {
+    for end_event_id in [None, Some("EndEvent_Approved")] {
+        let mock_server = start_engine(started_response()).await;
+        Mock::given(method("GET"))
+            .and(path(PROCESS_INSTANCES_PATH))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(process_instances("finished", Value::Null)),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(FLOW_NODE_INSTANCES_PATH))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(end_event_instances(Value::Null)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let result = start_and_wait::<Value>(
+            &mock_server,
+            start_request(ReturnOn::OnProcessInstanceStarted, end_event_id),
+            wait_options(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.token, Value::Null, "End event {:?}", end_event_id);
+    }
+}
+
+#[tokio::test]
+async fn start_and_wait_polls_end_event_with_prefixed_flow_node_type() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(process_instances("running", Value::Null)),
+        )
+        .mount(&mock_server)
+        .await;
+    let mut flow_node_instances = end_event_instances(json!({ "orderId": 42, "approved": true }));
+    flow_node_instances["flowNodeInstances"][0]["flowNodeType"] = json!("bpmn:EndEvent");
+    Mock::given(method("GET"))
+        .and(path(FLOW_NODE_INSTANCES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(flow_node_instances))
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(
+            ReturnOn::OnProcessInstanceStarted,
+            Some("EndEvent_Approved"),
+        ),
+        wait_options(),
+    )
+    .await
+    .unwrap();
+
+    assert!(result.token.approved);
+}
+
+#[tokio::test]
+async fn start_and_wait_polls_end_event_with_scalar_token() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(process_instances("running", Value::Null)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(FLOW_NODE_INSTANCES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(end_event_instances(json!(42))))
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<u32>(
+        &mock_server,
+        start_request(
+            ReturnOn::OnProcessInstanceStarted,
+            Some("EndEvent_Approved"),
+        ),
+        wait_options(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.token, 42);
+}
+
+// Error cases
+#[tokio::test]
+async fn start_and_wait_with_failed_process_instance() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(process_instances("error", Value::Null)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(ReturnOn::OnProcessInstanceStarted, None),
+        wait_options(),
+    )
+    .await;
+
+    match result {
+        Err(EngineError::ProcessInstanceFailed {
+            process_instance_id,
+            state,
+            error,
+        }) => {
+            assert_eq!(process_instance_id, "ProcessInstance_1");
+            assert_eq!(state, ProcessInstanceState::Error);
+            assert_eq!(error, Some(json!({ "message": "Boom" })));
+        }
+        other => panic!("Expected failed process instance, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn start_and_wait_with_other_end_event() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(process_instances(
+            "finished",
+            json!({ "orderId": 42, "approved": false }),
+        )))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(FLOW_NODE_INSTANCES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flowNodeInstances": [],
+            "totalCount": 0,
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(
+            ReturnOn::OnProcessInstanceStarted,
+            Some("EndEvent_Approved"),
+        ),
+        wait_options(),
+    )
+    .await;
+
+    match result {
+        Err(EngineError::EndEventNotReached {
+            process_instance_id,
+            end_event_id,
+        }) => {
+            assert_eq!(process_instance_id, "ProcessInstance_1");
+            assert_eq!(end_event_id, "EndEvent_Approved");
+        }
+        other => panic!("Expected end event not reached, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn start_and_wait_with_timeout() {
+    let mock_server = start_engine(started_response()).await;
+    Mock::given(method("GET"))
+        .and(path(PROCESS_INSTANCES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(process_instances("suspended", Value::Null)),
+        )
+        .mount(&mock_server)
+        .await;
+    let options = WaitOptions {
+        poll_interval: Duration::from_millis(10),
+        timeout: Duration::from_millis(50),
+    };
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(ReturnOn::OnProcessInstanceStarted, None),
+        options,
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(EngineError::WaitTimeout { timeout, .. }) if timeout == Duration::from_millis(50)
+    ));
+}
+
+#[tokio::test]
+async fn start_and_wait_with_unexpected_token() {
+    let mock_server = start_engine(json!({
+        "processInstanceId": "ProcessInstance_1",
+        "correlationId": "Correlation_1",
+        "endEventId": "EndEvent_Approved",
+        "tokenPayload": { "unexpected": true },
+    }))
+    .await;
+
+    let result = start_and_wait::<Order>(
+        &mock_server,
+        start_request(ReturnOn::OnProcessInstanceFinished, None),
+        wait_options(),
+    )
+    .await;
+
+    assert!(matches!(result, Err(EngineError::Deserialization(_))));
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory,
+    error::EngineError,
+    process_model::process_model::{ProcessStartRequest, ReturnOn},
+};
+use serde::Serialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Order {
+    order_id: u32,
+    items: Vec<String>,
+}
+
+// Happy cases
+#[test]
+fn build_with_defaults() {
+    let request = ProcessStartRequest::builder().build().unwrap();
+
+    assert_eq!(request, ProcessStartRequest::default());
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "returnOn": "onProcessInstanceStarted" })
+    );
+}
+
+#[test]
+fn build_with_all_fields() {
+    let request = ProcessStartRequest::builder()
+        .start_event_id("StartEvent_1")
+        .correlation_id("Correlation_1")
+        .initial_token(&Order {
+            order_id: 42,
+            items: vec!["Book".to_string()],
+        })
+        .return_on_end_event("EndEvent_1")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "startEventId": "StartEvent_1",
+            "correlationId": "Correlation_1",
+            "initialToken": { "orderId": 42, "items": ["Book"] },
+            "returnOn": "onEndEventReached",
+            "endEventId": "EndEvent_1",
+        })
+    );
+}
+
+#[tokio::test]
+async fn start_sends_only_given_fields() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/atlas_engine/api/v1/process_models/Order_Process/start",
+        ))
+        .and(body_json(json!({
+            "initialToken": 42,
+            "returnOn": "onProcessInstanceFinished",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "processInstanceId": "ProcessInstance_1",
+            "correlationId": "Correlation_1",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client_factory = ClientFactory::new(&mock_server.uri(), DUMMY_TOKEN);
+    let request = ProcessStartRequest::builder()
+        .initial_token(&42)
+        .return_on(ReturnOn::OnProcessInstanceFinished)
+        .build()
+        .unwrap();
+
+    let response = client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id("Order_Process", request)
+        .await
+        .unwrap();
+
+    assert_eq!(response.process_instance_id, "ProcessInstance_1");
+    assert_eq!(response.end_event_id, "");
+}
+
+// Error cases
+#[test]
+fn build_returning_on_end_event_without_end_event_id() {
+    let result = ProcessStartRequest::builder()
+        .return_on(ReturnOn::OnEndEventReached)
+        .build();
+
+    assert!(matches!(result, Err(EngineError::Configuration(_))));
+}
+
+#[test]
+fn build_with_unserializable_token() {
+    let token: HashMap<(u8, u8), u8> = HashMap::from([((1, 2), 3)]);
+
+    let result = ProcessStartRequest::builder().initial_token(&token).build();
+
+    assert!(matches!(result, Err(EngineError::Deserialization(_))));
+}
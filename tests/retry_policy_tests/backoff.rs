@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use processcube_engine_client::clients::api::retry_policy::RetryPolicy;
+
+// Happy cases
+
+#[test]
+fn backoff_grows_exponentially_up_to_the_maximum() {
+    let retry_policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(500),
+        backoff_multiplier: 2.0,
+        jitter: false,
+        ..Default::default()
+    };
+
+    assert_eq!(retry_policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(retry_policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(retry_policy.backoff(3), Duration::from_millis(400));
+    assert_eq!(retry_policy.backoff(4), Duration::from_millis(500));
+    assert_eq!(retry_policy.backoff(u32::MAX), Duration::from_millis(500));
+}
+
+#[test]
+fn jitter_keeps_backoff_between_half_and_full_length() {
+    let retry_policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(100),
+        jitter: true,
+        ..Default::default()
+    };
+
+    for _ in 0..100 {
+        let backoff = retry_policy.backoff(1);
+        assert!(backoff >= Duration::from_millis(50));
+        assert!(backoff <= Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn no_retries_allows_a_single_attempt() {
+    assert_eq!(RetryPolicy::no_retries().max_attempts, 1);
+}
+
+#[test]
+fn default_retries_on_statuses_worth_repeating() {
+    assert_eq!(
+        RetryPolicy::default().retryable_statuses,
+        vec![429, 502, 503, 504]
+    );
+}
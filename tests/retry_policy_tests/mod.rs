@@ -0,0 +1,2 @@
+mod backoff;
+mod requests;
@@ -0,0 +1,299 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use processcube_engine_client::clients::{
+    api::retry_policy::RetryPolicy, client_factory::ClientFactory,
+    process_model::process_model::ProcessStartRequest, user_task::user_task::UserTaskResult,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const CORRELATIONS_PATH: &str = "/atlas_engine/api/v1/correlations";
+const START_PATH: &str = "/atlas_engine/api/v1/process_models/sample_process/start";
+const FINISH_USER_TASK_PATH: &str = "/atlas_engine/api/v1/user_tasks/sample_user_task/finish";
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(10),
+        jitter: false,
+        ..Default::default()
+    }
+}
+
+fn service_unavailable() -> ResponseTemplate {
+    ResponseTemplate::new(503).set_body_json(json!({
+        "errorClassName": "ServiceUnavailableError",
+        "message": "Service unavailable",
+        "callStack": "",
+        "code": 503,
+        "fatal": false,
+    }))
+}
+
+fn too_many_requests(retry_after: &str) -> ResponseTemplate {
+    ResponseTemplate::new(429)
+        .insert_header("Retry-After", retry_after)
+        .set_body_json(json!({
+            "errorClassName": "TooManyRequestsError",
+            "message": "Too many requests",
+            "callStack": "",
+            "code": 429,
+            "fatal": false,
+        }))
+}
+
+async fn mount_recovering_engine(first_response: ResponseTemplate) -> MockServer {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(first_response)
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&engine)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "correlations": [], "totalCount": 0 })),
+        )
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    engine
+}
+
+fn start_request() -> ProcessStartRequest {
+    ProcessStartRequest::builder()
+        .start_event_id("StartEvent_1")
+        .initial_token(&json!({}))
+        .build()
+        .unwrap()
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn get_is_retried_until_the_engine_recovers() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(service_unavailable())
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&engine)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "correlations": [], "totalCount": 0 })),
+        )
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_correlation_client();
+
+    assert!(client.get_correlations().await.is_ok());
+}
+
+#[tokio::test]
+async fn get_is_retried_after_the_seconds_of_retry_after() {
+    let engine = mount_recovering_engine(too_many_requests("1")).await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_correlation_client();
+
+    let started = Instant::now();
+    assert!(client.get_correlations().await.is_ok());
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn get_is_retried_after_the_date_of_retry_after() {
+    let started = Instant::now();
+    let retry_after = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(2));
+    let engine = mount_recovering_engine(
+        service_unavailable().insert_header("Retry-After", retry_after.as_str()),
+    )
+    .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_correlation_client();
+
+    assert!(client.get_correlations().await.is_ok());
+    // The date has a precision of seconds, so the delay may be up to a second shorter.
+    assert!(started.elapsed() >= Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn post_is_retried_if_allowed_for_non_idempotent_requests() {
+    let engine = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(START_PATH))
+        .respond_with(service_unavailable())
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&engine)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(START_PATH))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "processInstanceId": "instance_id",
+            "correlationId": "correlation_id",
+            "endEventId": "",
+            "tokenPayload": {},
+        })))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(RetryPolicy {
+            retry_non_idempotent: true,
+            ..retry_policy()
+        });
+    let client = client_factory.create_process_model_client();
+
+    let response = client
+        .start_process_instance_by_process_model_id("sample_process", start_request())
+        .await
+        .unwrap();
+    assert_eq!(response.process_instance_id, "instance_id");
+}
+
+// Error cases
+
+#[tokio::test]
+async fn get_gives_up_after_max_attempts() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(service_unavailable())
+        .expect(3)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_correlation_client();
+
+    let error = client.get_correlations().await.unwrap_err();
+    assert_eq!(error.status(), Some(503));
+}
+
+#[tokio::test]
+async fn get_is_not_retried_if_retry_after_exceeds_max_backoff() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(too_many_requests("120"))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_correlation_client();
+
+    let started = Instant::now();
+    let error = client.get_correlations().await.unwrap_err();
+    assert_eq!(error.status(), Some(429));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn get_is_not_retried_on_non_retryable_status() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "errorClassName": "InternalServerError",
+            "message": "Something went wrong",
+            "callStack": "",
+            "code": 500,
+            "fatal": false,
+        })))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_correlation_client();
+
+    let error = client.get_correlations().await.unwrap_err();
+    assert_eq!(error.status(), Some(500));
+}
+
+#[tokio::test]
+async fn post_start_request_is_not_duplicated() {
+    let engine = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(START_PATH))
+        .respond_with(service_unavailable())
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_process_model_client();
+
+    let error = client
+        .start_process_instance_by_process_model_id("sample_process", start_request())
+        .await
+        .unwrap_err();
+    assert_eq!(error.status(), Some(503));
+}
+
+#[tokio::test]
+async fn put_finish_request_is_not_duplicated() {
+    let engine = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(FINISH_USER_TASK_PATH))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory =
+        ClientFactory::new(&engine.uri(), DUMMY_TOKEN).with_retry_policy(retry_policy());
+    let client = client_factory.create_user_task_client();
+
+    let error = client
+        .finish_user_task(
+            "sample_user_task",
+            UserTaskResult::new().with_field("approved", true),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error.status(), Some(502));
+}
+
+#[tokio::test]
+async fn requests_are_sent_once_by_default() {
+    let engine = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(CORRELATIONS_PATH))
+        .respond_with(service_unavailable())
+        .expect(1)
+        .mount(&engine)
+        .await;
+
+    let client_factory = ClientFactory::new(&engine.uri(), DUMMY_TOKEN);
+    let client = client_factory.create_correlation_client();
+
+    assert!(client.get_correlations().await.is_err());
+}
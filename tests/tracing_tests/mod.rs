@@ -0,0 +1,2 @@
+mod spans;
+mod trace_context;
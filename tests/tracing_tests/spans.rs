@@ -0,0 +1,179 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use processcube_engine_client::clients::{
+    api::{
+        api_client::ApiClient,
+        transport::{InMemoryTransport, TransportResponse},
+    },
+    client_factory::ClientFactory,
+    process_model::process_model::ProcessStartRequest,
+};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+type Fields = BTreeMap<String, String>;
+
+/// Records the fields of all spans named `engine_request`.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<(Id, Fields)>>>,
+}
+
+impl SpanRecorder {
+    fn spans(&self) -> Vec<Fields> {
+        let spans = self.spans.lock().unwrap();
+        spans.iter().map(|(_, fields)| fields.clone()).collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl<S> Layer<S> for SpanRecorder
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, _: Context<'_, S>) {
+        if attributes.metadata().name() != "engine_request" {
+            return;
+        }
+        let mut fields = Fields::new();
+        attributes.record(&mut FieldVisitor(&mut fields));
+        self.spans.lock().unwrap().push((id.clone(), fields));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some((_, fields)) = spans.iter_mut().find(|(span_id, _)| span_id == id) {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn span_records_process_model_and_correlation() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing_subscriber::registry()
+        .with(recorder.clone())
+        .set_default();
+    let transport = InMemoryTransport::new(|_| {
+        TransportResponse::json(
+            StatusCode::OK,
+            &json!({ "processInstanceId": "ProcessInstance_1", "correlationId": "Correlation_1" }),
+        )
+    });
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport);
+
+    client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id(
+            "Order_Process",
+            ProcessStartRequest::builder()
+                .correlation_id("Correlation_1")
+                .build()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let spans = recorder.spans();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(
+        spans[0]["otel.name"],
+        "POST /process_models/{process_model_id}/start"
+    );
+    assert_eq!(spans[0]["http.request.method"], "POST");
+    assert_eq!(
+        spans[0]["url.template"],
+        "/process_models/{process_model_id}/start"
+    );
+    assert_eq!(spans[0]["process_model_id"], "Order_Process");
+    assert_eq!(spans[0]["correlation_id"], "Correlation_1");
+    assert_eq!(spans[0]["http.response.status_code"], "200");
+    assert!(spans[0].contains_key("latency_ms"));
+    assert!(!spans[0].contains_key("otel.status_code"));
+}
+
+#[tokio::test]
+async fn span_of_unknown_endpoint_has_fixed_name() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing_subscriber::registry()
+        .with(recorder.clone())
+        .set_default();
+    let transport = InMemoryTransport::new(|_| TransportResponse::json(StatusCode::OK, &json!({})));
+    let api_client = ApiClient::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport);
+
+    api_client
+        .get::<Value>(&format!(
+            "{}/atlas_engine/api/v1/data_objects/DataObject_1",
+            ENGINE_URL
+        ))
+        .await
+        .unwrap();
+
+    let spans = recorder.spans();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0]["otel.name"], "GET unknown");
+    assert_eq!(spans[0]["url.template"], "unknown");
+    assert_eq!(spans[0]["url.path"], "/data_objects/DataObject_1");
+}
+
+// Error cases
+
+#[tokio::test]
+async fn span_records_error_responses() {
+    let recorder = SpanRecorder::default();
+    let _guard = tracing_subscriber::registry()
+        .with(recorder.clone())
+        .set_default();
+    let transport = InMemoryTransport::new(|_| {
+        TransportResponse::json(
+            StatusCode::NOT_FOUND,
+            &json!({
+                "errorClassName": "NotFoundError",
+                "message": "Correlation with ID `Correlation_1` not found.",
+                "code": 404
+            }),
+        )
+    });
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport);
+
+    client_factory
+        .create_correlation_client()
+        .get_correlation_by_id("Correlation_1")
+        .await
+        .unwrap_err();
+
+    let spans = recorder.spans();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0]["otel.name"], "GET /correlations/{correlation_id}");
+    assert_eq!(spans[0]["correlation_id"], "Correlation_1");
+    assert_eq!(spans[0]["http.response.status_code"], "404");
+    assert_eq!(spans[0]["otel.status_code"], "ERROR");
+}
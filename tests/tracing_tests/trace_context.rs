@@ -0,0 +1,74 @@
+use opentelemetry::trace::{TraceContextExt, TracerProvider};
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use processcube_engine_client::clients::{
+    api::transport::{InMemoryTransport, TransportResponse},
+    client_factory::ClientFactory,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::prelude::*;
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+fn correlation_transport() -> InMemoryTransport {
+    InMemoryTransport::new(|_| {
+        TransportResponse::json(
+            StatusCode::OK,
+            &json!({ "correlations": [], "totalCount": 0 }),
+        )
+    })
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn traceparent_continues_the_current_trace() {
+    let tracer_provider = SdkTracerProvider::builder().build();
+    let _guard = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("tests")))
+        .set_default();
+    let transport = correlation_transport();
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport.clone());
+    let parent = tracing::info_span!("handle_order");
+
+    client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .instrument(parent.clone())
+        .await
+        .unwrap();
+
+    let trace_id = parent.context().span().span_context().trace_id();
+    let parent_span_id = parent.context().span().span_context().span_id();
+    let traceparent = transport.requests()[0].headers["traceparent"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let parts: Vec<&str> = traceparent.split('-').collect();
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0], "00");
+    assert_eq!(parts[1], trace_id.to_string());
+    assert_eq!(parts[2].len(), 16);
+    assert_ne!(parts[2], parent_span_id.to_string());
+    assert_eq!(parts[3], "01");
+}
+
+#[tokio::test]
+async fn no_traceparent_without_opentelemetry() {
+    let transport = correlation_transport();
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport.clone());
+
+    client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap();
+
+    assert!(!transport.requests()[0].headers.contains_key("traceparent"));
+}
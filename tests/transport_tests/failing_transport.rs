@@ -0,0 +1,116 @@
+use std::{
+    io,
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use processcube_engine_client::clients::{
+    api::{
+        retry_policy::RetryPolicy,
+        transport::{Transport, TransportRequest, TransportResponse},
+    },
+    client_factory::ClientFactory,
+    error::{EngineError, NetworkErrorKind},
+    process_model::process_model::ProcessStartRequest,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+/// A Transport failing the first requests with a network error and answering the others.
+struct FailingTransport {
+    kind: NetworkErrorKind,
+    failures: u32,
+    attempts: AtomicU32,
+}
+
+impl FailingTransport {
+    fn new(kind: NetworkErrorKind, failures: u32) -> FailingTransport {
+        FailingTransport {
+            kind,
+            failures,
+            attempts: AtomicU32::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for FailingTransport {
+    async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, EngineError> {
+        if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+            let source = io::Error::new(io::ErrorKind::ConnectionReset, "connection lost");
+            return Err(EngineError::network(self.kind, source));
+        }
+
+        Ok(TransportResponse::json(
+            StatusCode::CREATED,
+            &json!({
+                "processInstanceId": "ProcessInstance_1",
+                "correlationId": "Correlation_1"
+            }),
+        ))
+    }
+}
+
+fn create_client_factory(transport: FailingTransport) -> ClientFactory {
+    ClientFactory::builder(ENGINE_URL)
+        .auth_token(DUMMY_TOKEN)
+        .transport(transport)
+        .retry_policy(RetryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(10),
+            jitter: false,
+            ..Default::default()
+        })
+        .build()
+        .unwrap()
+}
+
+fn start_request() -> ProcessStartRequest {
+    ProcessStartRequest::builder()
+        .start_event_id("StartEvent_1")
+        .build()
+        .unwrap()
+}
+
+// Happy cases
+
+#[tokio::test]
+async fn failed_connection_is_retried() {
+    let client_factory = create_client_factory(FailingTransport::new(NetworkErrorKind::Connect, 1));
+
+    let result = client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id("sample_process", start_request())
+        .await;
+
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn request_lost_after_sending_is_not_retried() {
+    let client_factory = create_client_factory(FailingTransport::new(NetworkErrorKind::Other, 1));
+
+    let result = client_factory
+        .create_process_model_client()
+        .start_process_instance_by_process_model_id("sample_process", start_request())
+        .await;
+
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        EngineError::Network {
+            kind: NetworkErrorKind::Other,
+            ..
+        }
+    ));
+    assert!(err.is_retryable());
+    assert_eq!(err.status(), None);
+    assert_eq!(err.to_string(), "Error sending request: connection lost");
+}
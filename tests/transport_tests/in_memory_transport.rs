@@ -0,0 +1,108 @@
+use processcube_engine_client::clients::{
+    api::transport::{InMemoryTransport, TransportResponse},
+    client_factory::ClientFactory,
+    error::EngineError,
+};
+use reqwest::{Method, StatusCode};
+use serde_json::json;
+
+use crate::fixtures::DUMMY_TOKEN;
+
+const ENGINE_URL: &str = "http://localhost:10560";
+
+// Happy cases
+
+#[tokio::test]
+async fn requests_are_passed_to_the_transport() {
+    let transport = InMemoryTransport::new(|_| TransportResponse::empty(StatusCode::NO_CONTENT));
+    let client_factory =
+        ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport.clone());
+
+    client_factory
+        .create_event_client()
+        .trigger_message("order_received", None, Some(json!({ "orderId": 42 })))
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(
+        requests[0].url,
+        "http://localhost:10560/atlas_engine/api/v1/messages/order_received/trigger"
+    );
+    assert_eq!(requests[0].headers["Authorization"], DUMMY_TOKEN);
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(requests[0].body.as_ref().unwrap()).unwrap(),
+        json!({ "payload": { "orderId": 42 } })
+    );
+}
+
+#[tokio::test]
+async fn responses_of_the_transport_are_deserialized() {
+    let transport = InMemoryTransport::new(|_| {
+        TransportResponse::json(
+            StatusCode::OK,
+            &json!({ "correlations": [], "totalCount": 0 }),
+        )
+    });
+    let client_factory = ClientFactory::builder(ENGINE_URL)
+        .auth_token(DUMMY_TOKEN)
+        .transport(transport)
+        .build()
+        .unwrap();
+
+    let correlations = client_factory
+        .create_correlation_client()
+        .get_correlations()
+        .await
+        .unwrap();
+
+    assert_eq!(correlations.total_count, 0);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn error_responses_of_the_transport_are_converted() {
+    let transport = InMemoryTransport::new(|_| {
+        TransportResponse::json(
+            StatusCode::NOT_FOUND,
+            &json!({
+                "errorClassName": "NotFoundError",
+                "message": "Correlation with ID `unknown` not found.",
+                "code": 404
+            }),
+        )
+    });
+    let client_factory = ClientFactory::new(ENGINE_URL, DUMMY_TOKEN).with_transport(transport);
+
+    let err = client_factory
+        .create_correlation_client()
+        .get_correlation_by_id("unknown")
+        .await
+        .unwrap_err();
+
+    match err {
+        EngineError::NotFound(details) => {
+            assert_eq!(details.message, "Correlation with ID `unknown` not found.")
+        }
+        error => panic!("Unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn builder_rejects_http_settings_with_custom_transport() {
+    let result = ClientFactory::builder(ENGINE_URL)
+        .auth_token(DUMMY_TOKEN)
+        .transport(InMemoryTransport::new(|_| {
+            TransportResponse::empty(StatusCode::NO_CONTENT)
+        }))
+        .user_agent("sample_application/1.0")
+        .build();
+
+    assert!(matches!(
+        result.err().unwrap(),
+        EngineError::Configuration(_)
+    ));
+}
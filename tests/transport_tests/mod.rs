@@ -0,0 +1,2 @@
+mod failing_transport;
+mod in_memory_transport;
@@ -0,0 +1,35 @@
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory, untyped_task::untyped_task::UntypedTasksQuery,
+};
+
+use crate::fixtures::{TestEngine, DUMMY_TOKEN};
+
+// Happy cases
+
+#[tokio::test]
+async fn get_untyped_tasks() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_untyped_task_client();
+
+    let result = client.get_untyped_tasks(None, None, None).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn get_untyped_tasks_with_query() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_untyped_task_client();
+
+    let query = UntypedTasksQuery {
+        process_instance_id: Some("foo".to_string()),
+        ..Default::default()
+    };
+    let result = client.get_untyped_tasks(None, Some(10), Some(query)).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+    assert_eq!(result.unwrap().total_count, 0);
+}
+
+// Error cases
+// None
@@ -0,0 +1,52 @@
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory, error::EngineError, user_task::user_task::UserTasksQuery,
+};
+
+use crate::fixtures::{TestEngine, DUMMY_TOKEN};
+
+// Happy cases
+
+#[tokio::test]
+async fn get_user_tasks() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_user_task_client();
+
+    let result = client.get_user_tasks(None, None, None).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn get_user_tasks_with_query() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_user_task_client();
+
+    let query = UserTasksQuery {
+        state: Some("suspended".to_string()),
+        ..Default::default()
+    };
+    let result = client.get_user_tasks(None, Some(10), Some(query)).await;
+    assert!(result.is_ok(), "Expected Ok result, but got {:?}", result);
+}
+
+// Error cases
+
+#[tokio::test]
+async fn get_user_task_by_id_not_found() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), DUMMY_TOKEN);
+    let client = client_factory.create_user_task_client();
+
+    let result = client.get_user_task_by_id("foo").await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, EngineError::NotFound(_)));
+    assert_eq!(err.status(), Some(404));
+    assert_eq!(err.details().unwrap().error_class_name, "NotFoundError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "User task with ID `foo` not found."
+    );
+}
@@ -0,0 +1,45 @@
+use processcube_engine_client::clients::{
+    client_factory::ClientFactory, user_task::user_task::UserTaskResult,
+};
+
+use crate::fixtures::TestEngine;
+
+// Error cases
+
+#[tokio::test]
+async fn reserve_user_task_invalid_token() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
+    let client = client_factory.create_user_task_client();
+
+    let result = client.reserve_user_task("foo", "bar").await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "Must provide a token by which to create an identity!"
+    )
+}
+
+#[tokio::test]
+async fn finish_user_task_invalid_token() {
+    let engine = TestEngine::start().await;
+    let client_factory = ClientFactory::new(&engine.url(), "foo");
+    let client = client_factory.create_user_task_client();
+
+    let result = client
+        .finish_user_task("foo", UserTaskResult::new().with_field("approved", true))
+        .await;
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert_eq!(err.details().unwrap().error_class_name, "BadRequestError");
+    assert_eq!(
+        err.details().unwrap().message,
+        "Must provide a token by which to create an identity!"
+    )
+}